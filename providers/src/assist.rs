@@ -0,0 +1,150 @@
+//! Wordplay helpers for cryptic clues.
+//!
+//! These look for hidden words and anagram fodder in the clue text itself, so
+//! they need no word list: they point at candidates, and the solver still has to
+//! find the definition and decide which one (if any) is the answer.
+
+use crate::entries::{letters_match, uppercase_letter};
+
+/// Uppercase letters of `text`, ignoring spaces, punctuation and digits.
+fn letters(text: &str) -> Vec<char> {
+    text.chars()
+        .filter(|c| c.is_alphabetic())
+        .map(uppercase_letter)
+        .collect()
+}
+
+/// Whether `word` is hidden in `phrase`, reading across spaces and punctuation
+/// ("hidden in *pARTISan*", "*tHE ARTh*").
+pub fn contains_hidden(word: &str, phrase: &str) -> bool {
+    let (word, phrase) = (letters(word), letters(phrase));
+    !word.is_empty() && phrase.windows(word.len()).any(|window| window == word)
+}
+
+/// Whether `a` and `b` are made of exactly the same letters.
+pub fn is_anagram(a: &str, b: &str) -> bool {
+    let (mut a, mut b) = (letters(a), letters(b));
+    a.sort_unstable();
+    b.sort_unstable();
+    a == b
+}
+
+/// Whether `word` fits `pattern`, where `None` is an unknown letter.
+///
+/// Known letters without an accent fit accented letters of the word, since
+/// clues often drop accents the grid has.
+pub fn matches_pattern(word: &str, pattern: &[Option<char>]) -> bool {
+    let word = letters(word);
+    word.len() == pattern.len()
+        && word
+            .iter()
+            .zip(pattern)
+            .all(|(letter, known)| known.is_none_or(|known| letters_match(known, *letter, true)))
+}
+
+/// Letter sequences hidden in `phrase` that fit `pattern`, in reading order.
+///
+/// Whole words of the phrase are left out, since a clue never hides an answer
+/// in plain sight.
+pub fn hidden_words(phrase: &str, pattern: &[Option<char>]) -> Vec<String> {
+    let words: Vec<String> = phrase
+        .split_whitespace()
+        .map(|word| letters(word).into_iter().collect())
+        .collect();
+    let letters = letters(phrase);
+
+    let mut found: Vec<String> = Vec::new();
+    if pattern.is_empty() {
+        return found;
+    }
+    for window in letters.windows(pattern.len()) {
+        let candidate: String = window.iter().collect();
+        if matches_pattern(&candidate, pattern)
+            && !words.contains(&candidate)
+            && !found.contains(&candidate)
+        {
+            found.push(candidate);
+        }
+    }
+    found
+}
+
+/// Runs of consecutive words in `phrase` with as many letters as `pattern`,
+/// containing its known letters, as possible anagram fodder.
+pub fn anagram_fodder(phrase: &str, pattern: &[Option<char>]) -> Vec<String> {
+    let words: Vec<&str> = phrase.split_whitespace().collect();
+    let known: Vec<char> = pattern
+        .iter()
+        .flatten()
+        .map(|&c| uppercase_letter(c))
+        .collect();
+
+    let mut fodder = Vec::new();
+    for start in 0..words.len() {
+        let mut count = 0;
+        for (end, word) in words.iter().enumerate().skip(start) {
+            count += letters(word).len();
+            if count > pattern.len() {
+                break;
+            }
+            if count < pattern.len() {
+                continue;
+            }
+
+            let run = words[start..=end].join(" ");
+            let mut available = letters(&run);
+            let has_known = known.iter().all(|c| {
+                available
+                    .iter()
+                    .position(|a| a == c)
+                    .map(|i| available.swap_remove(i))
+                    .is_some()
+            });
+            // trailing enumerations and punctuation add no letters
+            let run = run.trim_matches(|c: char| !c.is_alphabetic()).to_string();
+            if has_known && !fodder.contains(&run) {
+                fodder.push(run);
+            }
+        }
+    }
+    fodder
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hidden_words() {
+        assert!(contains_hidden("artis", "Some partisan"));
+        assert!(contains_hidden("hear", "the art"));
+        assert!(!contains_hidden("", "the art"));
+
+        let clue = "Some partisan artist (5)";
+        let hidden = hidden_words(clue, &[None; 5]);
+        assert!(hidden.contains(&"ARTIS".to_string()));
+        assert!(hidden.contains(&"EPART".to_string()));
+        // whole words are not offered
+        let hidden = hidden_words(clue, &[None; 6]);
+        assert!(hidden.contains(&"ARTISA".to_string()));
+        assert!(!hidden.contains(&"ARTIST".to_string()));
+
+        let pattern = [None, Some('r'), None, None, Some('S')];
+        assert_eq!(hidden_words(clue, &pattern), vec!["ARTIS".to_string()]);
+    }
+
+    #[test]
+    fn test_anagram_fodder() {
+        assert!(is_anagram("Listen", "SILENT!"));
+        assert!(!is_anagram("listen", "silents"));
+
+        let clue = "Strange ideas about a great sin (9)";
+        assert_eq!(anagram_fodder(clue, &[None; 9]), vec!["a great sin"]);
+
+        // known letters that the fodder lacks rule it out
+        let mut pattern = [None; 9];
+        pattern[0] = Some('S');
+        pattern[8] = Some('S');
+        assert!(anagram_fodder(clue, &pattern).is_empty());
+    }
+}
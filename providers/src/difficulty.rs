@@ -0,0 +1,22 @@
+//! Puzzle difficulty classes.
+
+/// Kind of puzzle a provider publishes, from the easiest to the hardest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Difficulty {
+    /// Short, straightforward definitions on a small grid.
+    Quick,
+    /// American-style puzzle with definitions and wordplay of varying difficulty.
+    Standard,
+    /// Cryptic clues, each with a definition and wordplay.
+    Cryptic,
+}
+
+impl Difficulty {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Difficulty::Quick => "Quick",
+            Difficulty::Standard => "Standard",
+            Difficulty::Cryptic => "Cryptic",
+        }
+    }
+}
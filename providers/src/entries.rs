@@ -0,0 +1,576 @@
+//! Numbered answer entries of a puzzle.
+//!
+//! Derives the across and down entries from a puzzle's solution grid using
+//! standard crossword numbering, pairing each answer with its clue.
+
+use crate::ProviderError;
+use puz_parse::Puzzle;
+use serde::{Deserialize, Serialize};
+
+/// Direction of an entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum EntryDirection {
+    Across,
+    Down,
+}
+
+impl EntryDirection {
+    pub fn name(&self) -> &'static str {
+        match self {
+            EntryDirection::Across => "Across",
+            EntryDirection::Down => "Down",
+        }
+    }
+}
+
+/// A single numbered entry (answer slot) in a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Entry {
+    pub direction: EntryDirection,
+    /// Clue number.
+    pub number: u16,
+    /// Row of the first cell.
+    pub row: usize,
+    /// Column of the first cell.
+    pub col: usize,
+    /// Answer letters, as given in the solution grid.
+    pub answer: String,
+    /// Clue text, empty if the puzzle has no clue for this entry.
+    pub clue: String,
+}
+
+impl Entry {
+    /// Grid positions covered by this entry, in reading order.
+    pub fn cells(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..self.answer.chars().count()).map(move |i| match self.direction {
+            EntryDirection::Across => (self.row, self.col + i),
+            EntryDirection::Down => (self.row + i, self.col),
+        })
+    }
+
+    /// Enumeration of the answer, e.g. `(5)`.
+    ///
+    /// Solution grids have no word breaks, so this is always the total length.
+    pub fn enumeration(&self) -> String {
+        format!("({})", self.answer.chars().count())
+    }
+
+    /// Answer with the word breaks of the clue's enumeration, e.g. "OUT OF SORTS"
+    /// for "(3,2,5)" or "WELL-MET" for "(4-3)".
+    ///
+    /// Returns the plain answer if the clue has no enumeration or it doesn't
+    /// match the answer's length.
+    pub fn display_answer(&self) -> String {
+        let Some(inner) = self
+            .clue
+            .trim_end()
+            .strip_suffix(')')
+            .and_then(|clue| clue.rsplit_once('('))
+            .map(|(_, inner)| inner)
+        else {
+            return self.answer.clone();
+        };
+        if enumeration_lengths(&self.clue)
+            .is_none_or(|lengths| lengths.iter().sum::<usize>() != self.answer.chars().count())
+        {
+            return self.answer.clone();
+        }
+
+        // Walk the enumeration, copying letters and inserting its separators
+        let mut letters = self.answer.chars();
+        let mut display = String::new();
+        let mut length = String::new();
+        for c in inner.chars().chain(std::iter::once(',')) {
+            if c.is_ascii_digit() {
+                length.push(c);
+                continue;
+            }
+            let count: usize = length.parse().unwrap_or(0);
+            length.clear();
+            display.extend(letters.by_ref().take(count));
+            match c {
+                '-' => display.push('-'),
+                ',' | ' ' if count > 0 => display.push(' '),
+                _ => {}
+            }
+        }
+        display.trim_end().to_string()
+    }
+}
+
+/// Grid letters of an answer as providers give it: uppercase, without the
+/// spaces, hyphens, apostrophes and other punctuation of multi-word answers
+/// ("Out-of-sorts" becomes "OUTOFSORTS").
+pub fn grid_letters(answer: &str) -> String {
+    answer
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_uppercase)
+        .collect()
+}
+
+/// Accented capitals of Spanish, French, German and Portuguese puzzles, with
+/// their base letter and combining accent.
+const ACCENTED: [(char, char, char); 27] = [
+    ('À', 'A', '\u{300}'),
+    ('Á', 'A', '\u{301}'),
+    ('Â', 'A', '\u{302}'),
+    ('Ã', 'A', '\u{303}'),
+    ('Ä', 'A', '\u{308}'),
+    ('Å', 'A', '\u{30a}'),
+    ('Ç', 'C', '\u{327}'),
+    ('È', 'E', '\u{300}'),
+    ('É', 'E', '\u{301}'),
+    ('Ê', 'E', '\u{302}'),
+    ('Ë', 'E', '\u{308}'),
+    ('Ì', 'I', '\u{300}'),
+    ('Í', 'I', '\u{301}'),
+    ('Î', 'I', '\u{302}'),
+    ('Ï', 'I', '\u{308}'),
+    ('Ñ', 'N', '\u{303}'),
+    ('Ò', 'O', '\u{300}'),
+    ('Ó', 'O', '\u{301}'),
+    ('Ô', 'O', '\u{302}'),
+    ('Õ', 'O', '\u{303}'),
+    ('Ö', 'O', '\u{308}'),
+    ('Ù', 'U', '\u{300}'),
+    ('Ú', 'U', '\u{301}'),
+    ('Û', 'U', '\u{302}'),
+    ('Ü', 'U', '\u{308}'),
+    ('Ý', 'Y', '\u{301}'),
+    ('Ÿ', 'Y', '\u{308}'),
+];
+
+/// Uppercase form of a letter. Letters whose uppercase is more than one
+/// character (`ß`) are kept as they are.
+pub fn uppercase_letter(c: char) -> char {
+    let mut upper = c.to_uppercase();
+    match (upper.next(), upper.next()) {
+        (Some(upper), None) => upper,
+        _ => c,
+    }
+}
+
+/// Uppercase letter without its accent (`é` is `E`).
+pub fn base_letter(c: char) -> char {
+    let c = uppercase_letter(c);
+    ACCENTED
+        .iter()
+        .find(|(accented, ..)| *accented == c)
+        .map_or(c, |(_, base, _)| *base)
+}
+
+/// Whether a typed `letter` is right for an `answer` letter, ignoring case and,
+/// with `accept_unaccented`, the answer's accent (`E` for `É`).
+pub fn letters_match(letter: char, answer: char, accept_unaccented: bool) -> bool {
+    let (letter, answer) = (uppercase_letter(letter), uppercase_letter(answer));
+    letter == answer
+        || (accept_unaccented && letter == base_letter(letter) && letter == base_letter(answer))
+}
+
+/// Compose letters followed by a combining accent (`E\u{301}`) into a single
+/// character (`É`), so that each grid cell holds one character.
+pub fn compose_accents(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        let upper = uppercase_letter(c);
+        let composed = chars.peek().and_then(|&mark| {
+            ACCENTED
+                .iter()
+                .find(|(_, base, accent)| *base == upper && *accent == mark)
+        });
+        match composed {
+            Some(&(accented, ..)) => {
+                chars.next();
+                // keep the case of the base letter
+                result.extend(if c == upper {
+                    accented.to_uppercase().collect::<Vec<_>>()
+                } else {
+                    accented.to_lowercase().collect()
+                });
+            }
+            None => result.push(c),
+        }
+    }
+    result
+}
+
+/// Normalize the solution grid of a puzzle: accents are composed and letters
+/// uppercased, so answers compare letter by letter.
+pub fn normalize_solution(puzzle: &mut Puzzle) {
+    for row in &mut puzzle.grid.solution {
+        *row = compose_accents(row).chars().map(uppercase_letter).collect();
+    }
+}
+
+/// Solution letter of a cell whose answer isn't known, as in prize puzzles that
+/// are published before their solution.
+pub const UNKNOWN_LETTER: char = '-';
+
+/// Whether the puzzle comes with its solution, i.e. every letter cell has a known
+/// answer. Without one the fill can't be checked, only completed.
+pub fn has_solution(puzzle: &Puzzle) -> bool {
+    !puzzle
+        .grid
+        .solution
+        .iter()
+        .any(|row| row.contains(UNKNOWN_LETTER))
+}
+
+/// Fill in the unknown letters of `puzzle` from `solved`, a copy of the same
+/// puzzle with its solution, returning how many letters were filled in.
+///
+/// Fails, leaving `puzzle` as it was, if the grids differ in size or black
+/// squares, or if `solved` has unknown letters where `puzzle` does.
+pub fn merge_solution(puzzle: &mut Puzzle, solved: &Puzzle) -> Result<usize, ProviderError> {
+    let mismatch = || ProviderError::InvalidPuzzleData("the grids don't match".to_string());
+    if puzzle.grid.solution.len() != solved.grid.solution.len() {
+        return Err(mismatch());
+    }
+
+    let mut filled = 0;
+    let mut rows = Vec::with_capacity(puzzle.grid.solution.len());
+    for (row, solved_row) in puzzle.grid.solution.iter().zip(&solved.grid.solution) {
+        if row.chars().count() != solved_row.chars().count() {
+            return Err(mismatch());
+        }
+        let mut merged = String::with_capacity(row.len());
+        for (ch, solved_ch) in row.chars().zip(solved_row.chars()) {
+            match (ch, solved_ch) {
+                (UNKNOWN_LETTER, UNKNOWN_LETTER) => {
+                    return Err(ProviderError::InvalidPuzzleData(
+                        "the solution is incomplete".to_string(),
+                    ));
+                }
+                ('.', _) | (_, '.') if ch != solved_ch => {
+                    return Err(mismatch());
+                }
+                (UNKNOWN_LETTER, letter) => {
+                    merged.push(letter);
+                    filled += 1;
+                }
+                _ => merged.push(ch),
+            }
+        }
+        rows.push(merged);
+    }
+    puzzle.grid.solution = rows;
+    Ok(filled)
+}
+
+/// Word lengths given by a clue's trailing enumeration, e.g. `[3, 4]` for "(3,4)".
+///
+/// Returns `None` if the clue does not end with an enumeration.
+fn enumeration_lengths(clue: &str) -> Option<Vec<usize>> {
+    let (_, inner) = clue.trim_end().strip_suffix(')')?.rsplit_once('(')?;
+    let lengths = inner
+        .split([',', '-', ' '])
+        .filter(|part| !part.is_empty())
+        .map(|part| part.parse().ok())
+        .collect::<Option<Vec<usize>>>()?;
+    (!lengths.is_empty()).then_some(lengths)
+}
+
+/// Whether clue text already ends with an enumeration such as `(5)`, `(3,4)` or `(2-4)`.
+pub fn has_enumeration(clue: &str) -> bool {
+    enumeration_lengths(clue).is_some()
+}
+
+/// Check clue enumerations against the grid.
+///
+/// Returns a warning for each entry whose enumeration does not add up to the
+/// length of its slot, which usually means the provider's data is broken and the
+/// entry can't be solved as clued. Entries with rebus squares are not checked.
+pub fn check_enumerations(puzzle: &Puzzle) -> Vec<String> {
+    let is_rebus = |(row, col): (usize, usize)| {
+        puzzle.extensions.rebus.as_ref().is_some_and(|rebus| {
+            rebus
+                .grid
+                .get(row)
+                .and_then(|r| r.get(col))
+                .is_some_and(|&key| key != 0)
+        })
+    };
+
+    entries(puzzle)
+        .into_iter()
+        .filter(|entry| !entry.cells().any(is_rebus))
+        .filter_map(|entry| {
+            let total: usize = enumeration_lengths(&entry.clue)?.iter().sum();
+            let slot = entry.answer.chars().count();
+            (total != slot).then(|| {
+                format!(
+                    "{} {}: enumeration has {} letters, but the grid has {}",
+                    entry.number,
+                    entry.direction.name(),
+                    total,
+                    slot
+                )
+            })
+        })
+        .collect()
+}
+
+/// Append the enumeration to every clue that does not end with one already,
+/// as UK-style outlets expect (e.g. "Feline (3)").
+pub fn append_enumerations(puzzle: &mut Puzzle) {
+    for entry in entries(puzzle) {
+        let clues = match entry.direction {
+            EntryDirection::Across => &mut puzzle.clues.across,
+            EntryDirection::Down => &mut puzzle.clues.down,
+        };
+        match clues.get_mut(&entry.number) {
+            Some(clue) if !clue.trim().is_empty() && !has_enumeration(clue) => {
+                clue.push_str(&format!(" {}", entry.enumeration()));
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Extract all entries from a puzzle: across entries by number, then down entries by number.
+///
+/// Black squares are `'.'` in the solution grid. A word starts where the previous
+/// cell is black (or the edge) and the next cell is a letter.
+pub fn entries(puzzle: &Puzzle) -> Vec<Entry> {
+    let grid: Vec<Vec<char>> = puzzle
+        .grid
+        .solution
+        .iter()
+        .map(|row| row.chars().collect())
+        .collect();
+    let is_letter = |r: usize, c: usize| {
+        grid.get(r)
+            .and_then(|row| row.get(c))
+            .is_some_and(|&ch| ch != '.')
+    };
+
+    let mut across = Vec::new();
+    let mut down = Vec::new();
+    let mut number: u16 = 1;
+
+    for (row, cells) in grid.iter().enumerate() {
+        for col in 0..cells.len() {
+            if !is_letter(row, col) {
+                continue;
+            }
+
+            let starts_across = (col == 0 || !is_letter(row, col - 1)) && is_letter(row, col + 1);
+            let starts_down = (row == 0 || !is_letter(row - 1, col)) && is_letter(row + 1, col);
+
+            if starts_across {
+                let answer: String = (col..)
+                    .take_while(|&c| is_letter(row, c))
+                    .map(|c| grid[row][c])
+                    .collect();
+                across.push(Entry {
+                    direction: EntryDirection::Across,
+                    number,
+                    row,
+                    col,
+                    answer,
+                    clue: puzzle
+                        .clues
+                        .across
+                        .get(&number)
+                        .cloned()
+                        .unwrap_or_default(),
+                });
+            }
+            if starts_down {
+                let answer: String = (row..)
+                    .take_while(|&r| is_letter(r, col))
+                    .map(|r| grid[r][col])
+                    .collect();
+                down.push(Entry {
+                    direction: EntryDirection::Down,
+                    number,
+                    row,
+                    col,
+                    answer,
+                    clue: puzzle.clues.down.get(&number).cloned().unwrap_or_default(),
+                });
+            }
+            if starts_across || starts_down {
+                number += 1;
+            }
+        }
+    }
+
+    across.extend(down);
+    across
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn puzzle(solution: &[&str], across: &[(u16, &str)], down: &[(u16, &str)]) -> Puzzle {
+        let to_map = |clues: &[(u16, &str)]| -> HashMap<u16, String> {
+            clues.iter().map(|(n, c)| (*n, c.to_string())).collect()
+        };
+        Puzzle {
+            info: puz_parse::PuzzleInfo {
+                title: String::new(),
+                height: solution.len() as u8,
+                width: solution[0].len() as u8,
+                author: String::new(),
+                copyright: String::new(),
+                notes: String::new(),
+                version: "1.4".to_string(),
+                is_scrambled: false,
+            },
+            grid: puz_parse::Grid {
+                blank: solution
+                    .iter()
+                    .map(|r| r.replace(|c| c != '.', "-"))
+                    .collect(),
+                solution: solution.iter().map(|r| r.to_string()).collect(),
+            },
+            clues: puz_parse::Clues {
+                across: to_map(across),
+                down: to_map(down),
+            },
+            extensions: puz_parse::Extensions {
+                rebus: None,
+                circles: None,
+                given: None,
+            },
+        }
+    }
+
+    #[test]
+    fn test_answer_forms() {
+        assert_eq!(grid_letters("Out-of sorts"), "OUTOFSORTS");
+        assert_eq!(grid_letters("o'er"), "OER");
+
+        let entry = |answer: &str, clue: &str| Entry {
+            direction: EntryDirection::Across,
+            number: 1,
+            row: 0,
+            col: 0,
+            answer: answer.to_string(),
+            clue: clue.to_string(),
+        };
+        assert_eq!(
+            entry("OUTOFSORTS", "Grumpy (3,2,5)").display_answer(),
+            "OUT OF SORTS"
+        );
+        assert_eq!(entry("WELLMET", "Hello (4-3)").display_answer(), "WELL-MET");
+        assert_eq!(entry("CAT", "Feline (3)").display_answer(), "CAT");
+        // no enumeration, or one that doesn't fit
+        assert_eq!(entry("CAT", "Feline").display_answer(), "CAT");
+        assert_eq!(entry("CAT", "Feline (2,2)").display_answer(), "CAT");
+    }
+
+    #[test]
+    fn test_has_solution() {
+        assert!(has_solution(&puzzle(&["AB", "C."], &[], &[])));
+        assert!(!has_solution(&puzzle(&["A-", "C."], &[], &[])));
+    }
+
+    #[test]
+    fn test_merge_solution() {
+        let mut prize = puzzle(&["A-", "-."], &[], &[]);
+        assert_eq!(
+            merge_solution(&mut prize, &puzzle(&["AB", "C."], &[], &[])).unwrap(),
+            2
+        );
+        assert_eq!(prize.grid.solution, ["AB", "C."]);
+        assert!(has_solution(&prize));
+
+        let mut prize = puzzle(&["A-", "-."], &[], &[]);
+        assert!(merge_solution(&mut prize, &puzzle(&["AB", "CD"], &[], &[])).is_err());
+        assert!(merge_solution(&mut prize, &puzzle(&["AB", "-."], &[], &[])).is_err());
+    }
+
+    #[test]
+    fn test_accented_letters() {
+        assert_eq!(uppercase_letter('é'), 'É');
+        assert_eq!(uppercase_letter('ß'), 'ß');
+        assert_eq!(base_letter('ñ'), 'N');
+        assert_eq!(compose_accents("Cafe\u{301} N\u{303}"), "Café Ñ");
+
+        for accept_unaccented in [true, false] {
+            assert!(letters_match('é', 'É', accept_unaccented));
+            assert!(letters_match('E', 'e', accept_unaccented));
+            assert!(!letters_match('É', 'E', accept_unaccented));
+            assert!(!letters_match('a', 'É', accept_unaccented));
+        }
+        assert!(letters_match('e', 'É', true));
+        assert!(!letters_match('e', 'É', false));
+
+        let mut puzzle = puzzle(&["CAFE\u{301}", "n\u{303}..."], &[], &[]);
+        normalize_solution(&mut puzzle);
+        assert_eq!(puzzle.grid.solution, ["CAFÉ", "Ñ..."]);
+    }
+
+    #[test]
+    fn test_entries_numbering() {
+        let puzzle = puzzle(
+            &["CAT", "A.O", "BOW"],
+            &[(1, "Feline"), (3, "Archer's weapon")],
+            &[(1, "Taxi"), (2, "Pull")],
+        );
+        let entries = entries(&puzzle);
+
+        let summary: Vec<(EntryDirection, u16, &str, &str)> = entries
+            .iter()
+            .map(|e| (e.direction, e.number, e.answer.as_str(), e.clue.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (EntryDirection::Across, 1, "CAT", "Feline"),
+                (EntryDirection::Across, 3, "BOW", "Archer's weapon"),
+                (EntryDirection::Down, 1, "CAB", "Taxi"),
+                (EntryDirection::Down, 2, "TOW", "Pull"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_entry_cells() {
+        let puzzle = puzzle(&["CAT", "A.O", "BOW"], &[], &[]);
+        let entries = entries(&puzzle);
+        let down = entries
+            .iter()
+            .find(|e| e.direction == EntryDirection::Down && e.number == 2)
+            .unwrap();
+        assert_eq!(
+            down.cells().collect::<Vec<_>>(),
+            vec![(0, 2), (1, 2), (2, 2)]
+        );
+        assert!(down.clue.is_empty());
+    }
+
+    #[test]
+    fn test_append_enumerations() {
+        let mut puzzle = puzzle(
+            &["CAT", "A.O", "BOW"],
+            &[(1, "Feline"), (3, "Archer's weapon (3)")],
+            &[(1, "Taxi (2-1)"), (2, "")],
+        );
+        append_enumerations(&mut puzzle);
+
+        assert_eq!(puzzle.clues.across[&1], "Feline (3)");
+        assert_eq!(puzzle.clues.across[&3], "Archer's weapon (3)");
+        assert_eq!(puzzle.clues.down[&1], "Taxi (2-1)");
+        assert_eq!(puzzle.clues.down[&2], "");
+    }
+
+    #[test]
+    fn test_check_enumerations() {
+        let puzzle = puzzle(
+            &["CAT", "A.O", "BOW"],
+            &[(1, "Feline (3)"), (3, "Archer's weapon (2,2)")],
+            &[(1, "Taxi"), (2, "Pull (1-2)")],
+        );
+        assert_eq!(
+            check_enumerations(&puzzle),
+            vec!["3 Across: enumeration has 4 letters, but the grid has 3".to_string()]
+        );
+    }
+}
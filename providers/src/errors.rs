@@ -4,6 +4,8 @@ pub enum ProviderError {
     SerdeParseError(serde_json::Error),
     PuzParseError(puz_parse::PuzError),
     InvalidPuzzleData(String),
+    /// The provider was turned off by the user.
+    Disabled(String),
     Other(String),
 }
 
@@ -25,6 +27,12 @@ impl From<puz_parse::PuzError> for ProviderError {
     }
 }
 
+impl From<std::io::Error> for ProviderError {
+    fn from(err: std::io::Error) -> Self {
+        ProviderError::Other(format!("IO error: {}", err))
+    }
+}
+
 impl From<std::num::ParseIntError> for ProviderError {
     fn from(err: std::num::ParseIntError) -> Self {
         ProviderError::Other(format!("Integer parse error: {}", err.to_string()))
@@ -38,6 +46,9 @@ impl std::fmt::Display for ProviderError {
             ProviderError::SerdeParseError(e) => write!(f, "Serde parse error: {}", e),
             ProviderError::PuzParseError(e) => write!(f, "PUZ parse error: {}", e),
             ProviderError::InvalidPuzzleData(e) => write!(f, "Invalid puzzle data: {}", e),
+            ProviderError::Disabled(name) => {
+                write!(f, "{} is disabled in the provider settings", name)
+            }
             ProviderError::Other(e) => write!(f, "Other error: {}", e),
         }
     }
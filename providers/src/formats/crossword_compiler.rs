@@ -4,6 +4,8 @@
 //! providers like Simply Daily Puzzles and Daily Pop.
 
 use crate::ProviderError;
+use crate::entries::{UNKNOWN_LETTER, uppercase_letter};
+use crate::util::clean_clue_text;
 use puz_parse::Puzzle;
 use quick_xml::de::from_str;
 use serde::Deserialize;
@@ -54,10 +56,10 @@ pub fn parse(xml_content: &str) -> Result<Puzzle, ProviderError> {
                     sol_row.push('.');
                 } else if let Some(sol) = &cell.solution {
                     blank_row.push('-');
-                    sol_row.push(sol.chars().next().unwrap_or('-'));
+                    sol_row.push(sol.chars().next().map_or(UNKNOWN_LETTER, uppercase_letter));
                 } else {
                     blank_row.push('-');
-                    sol_row.push('-');
+                    sol_row.push(UNKNOWN_LETTER);
                 }
             } else {
                 blank_row.push('.');
@@ -76,7 +78,7 @@ pub fn parse(xml_content: &str) -> Result<Puzzle, ProviderError> {
         let is_across = idx == 0; // First list is typically across
         for clue in &clue_list.clues {
             let clue_no: u16 = clue.number.parse().unwrap_or(0);
-            let mut clue_text = clue.text.clone().unwrap_or_default();
+            let mut clue_text = clean_clue_text(clue.text.as_deref().unwrap_or_default());
 
             // Append format/enumeration if present
             if let Some(fmt) = &clue.format {
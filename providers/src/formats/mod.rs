@@ -4,3 +4,219 @@
 //! into the common `puz_parse::Puzzle` structure.
 
 pub mod crossword_compiler;
+pub mod scramble;
+pub mod svg;
+
+use crate::ProviderError;
+use puz_parse::Puzzle;
+use std::path::Path;
+
+/// Magic string of Across Lite `.puz` files, found after the 2-byte checksum.
+const PUZ_MAGIC: &[u8] = b"ACROSS&DOWN\0";
+
+/// A puzzle file format recognised by [`load_file`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Across Lite binary `.puz`.
+    Puz,
+    /// CrosswordCompiler XML (`.xml`, and unzipped `.jpz`).
+    CrosswordCompiler,
+    /// ipuz JSON (`.ipuz`), recognised but not supported yet.
+    Ipuz,
+    /// The `puz_parse::Puzzle` structure serialized as JSON (`.json`).
+    Json,
+}
+
+impl Format {
+    /// Display name of the format.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Format::Puz => "Across Lite (.puz)",
+            Format::CrosswordCompiler => "CrosswordCompiler XML (.xml/.jpz)",
+            Format::Ipuz => "ipuz (.ipuz)",
+            Format::Json => "Puzzle JSON (.json)",
+        }
+    }
+
+    /// Guess the format from a file extension.
+    pub fn from_extension(path: &Path) -> Option<Format> {
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "puz" => Some(Format::Puz),
+            "xml" | "jpz" => Some(Format::CrosswordCompiler),
+            "ipuz" => Some(Format::Ipuz),
+            "json" => Some(Format::Json),
+            _ => None,
+        }
+    }
+
+    /// Detect the format from the file contents: the `.puz` magic string, the XML
+    /// root element, or the ipuz version URL in JSON (other JSON is taken as
+    /// puzzle JSON).
+    pub fn sniff(data: &[u8]) -> Option<Format> {
+        if data.get(2..2 + PUZ_MAGIC.len()) == Some(PUZ_MAGIC) {
+            return Some(Format::Puz);
+        }
+
+        let text = String::from_utf8_lossy(&data[..data.len().min(4096)]);
+        let text = text.trim_start_matches('\u{feff}').trim_start();
+        if text.starts_with('<') {
+            return xml_root(text)
+                .filter(|root| root.starts_with("crossword-compiler"))
+                .map(|_| Format::CrosswordCompiler);
+        }
+        if text.starts_with('{') {
+            return Some(if text.contains("ipuz.org") {
+                Format::Ipuz
+            } else {
+                Format::Json
+            });
+        }
+        None
+    }
+
+    /// Parse `data` as this format.
+    fn parse(&self, data: &[u8]) -> Result<Puzzle, ProviderError> {
+        match self {
+            Format::Puz => Ok(puz_parse::parse_bytes(data)?),
+            Format::CrosswordCompiler => crossword_compiler::parse(&String::from_utf8_lossy(data)),
+            Format::Ipuz => Err(ProviderError::Other(
+                "ipuz files are not supported yet".to_string(),
+            )),
+            Format::Json => Ok(serde_json::from_slice(data)?),
+        }
+    }
+}
+
+/// Name of the root element of an XML document, skipping the declaration,
+/// comments and doctype.
+fn xml_root(text: &str) -> Option<&str> {
+    let mut rest = text;
+    loop {
+        rest = rest.trim_start();
+        if let Some(after) = rest.strip_prefix("<?") {
+            rest = &after[after.find("?>")? + 2..];
+        } else if let Some(after) = rest.strip_prefix("<!--") {
+            rest = &after[after.find("-->")? + 3..];
+        } else if let Some(after) = rest.strip_prefix("<!") {
+            rest = &after[after.find('>')? + 1..];
+        } else {
+            let tag = rest.strip_prefix('<')?;
+            let end = tag
+                .find(|c: char| c.is_whitespace() || c == '>' || c == '/')
+                .unwrap_or(tag.len());
+            return Some(&tag[..end]);
+        }
+    }
+}
+
+/// Load a puzzle from a local file.
+///
+/// The format is detected from the contents first and the file extension second,
+/// so mislabeled and extension-less files still load. If no format can parse the
+/// file, the error lists each format that was tried. The solution is normalized
+/// with [`normalize_solution`](crate::entries::normalize_solution).
+pub fn load_file(path: &Path) -> Result<Puzzle, ProviderError> {
+    let data = std::fs::read(path)?;
+
+    let mut formats = Vec::new();
+    for format in [Format::sniff(&data), Format::from_extension(path)]
+        .into_iter()
+        .flatten()
+    {
+        if !formats.contains(&format) {
+            formats.push(format);
+        }
+    }
+    if formats.is_empty() {
+        formats = vec![Format::Puz, Format::CrosswordCompiler];
+    }
+
+    let mut errors = Vec::new();
+    for format in formats {
+        match format.parse(&data) {
+            Ok(mut puzzle) => {
+                crate::entries::normalize_solution(&mut puzzle);
+                return Ok(puzzle);
+            }
+            Err(e) => errors.push(format!("{}: {}", format.name(), e)),
+        }
+    }
+    Err(ProviderError::InvalidPuzzleData(format!(
+        "could not read {} (tried {})",
+        path.display(),
+        errors.join("; ")
+    )))
+}
+
+/// Write a puzzle to a local file, in the format given by its extension.
+///
+/// Only puzzle JSON can be written for now. Attribution is kept in the puzzle
+/// notes, see [`Source::annotate`](crate::Source::annotate).
+pub fn save_file(path: &Path, puzzle: &Puzzle) -> Result<(), ProviderError> {
+    match Format::from_extension(path) {
+        Some(Format::Json) => {
+            std::fs::write(path, serde_json::to_string_pretty(puzzle)?)?;
+            Ok(())
+        }
+        Some(format) => Err(ProviderError::Other(format!(
+            "writing {} files is not supported, use .json",
+            format.name()
+        ))),
+        None => Err(ProviderError::Other(format!(
+            "unknown output format for {}, use .json",
+            path.display()
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sniff() {
+        let mut puz = vec![0u8, 0];
+        puz.extend_from_slice(PUZ_MAGIC);
+        assert_eq!(Format::sniff(&puz), Some(Format::Puz));
+
+        let xml = "\u{feff}<?xml version=\"1.0\"?>\n<!-- jpz -->\n<crossword-compiler-applet xmlns=\"x\">";
+        assert_eq!(
+            Format::sniff(xml.as_bytes()),
+            Some(Format::CrosswordCompiler)
+        );
+        assert_eq!(Format::sniff(b"<html><body>"), None);
+
+        let ipuz = br#"{"version": "http://ipuz.org/v2", "kind": ["http://ipuz.org/crossword#1"]}"#;
+        assert_eq!(Format::sniff(ipuz), Some(Format::Ipuz));
+        assert_eq!(Format::sniff(b"{}"), Some(Format::Json));
+    }
+
+    #[test]
+    fn test_load_file_sniffs_mislabeled_files() {
+        let path = std::env::temp_dir().join("cruciverbal-sniff-test.puz");
+        std::fs::write(&path, include_str!("../../samples/warm-up.xml")).unwrap();
+        let puzzle = load_file(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert!(puzzle.is_ok());
+    }
+
+    #[test]
+    fn test_save_file_keeps_source() {
+        let mut puzzle =
+            crossword_compiler::parse(include_str!("../../samples/warm-up.xml")).unwrap();
+        let source = crate::Source::fetched(crate::PuzzleProvider::GuardianQuick, &puzzle);
+        source.annotate(&mut puzzle);
+
+        let path = std::env::temp_dir().join(format!(
+            "cruciverbal-export-test-{}.json",
+            std::process::id()
+        ));
+        save_file(&path, &puzzle).unwrap();
+        let exported = load_file(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert!(exported.unwrap().info.notes.contains(&source.attribution()));
+        let svg = svg::render(&puzzle, false);
+        assert!(svg.contains(&format!("<desc>Source: {}", source.provider)));
+    }
+}
@@ -0,0 +1,215 @@
+//! Locked (scrambled) solutions of Across Lite `.puz` files.
+//!
+//! Across Lite can scramble a puzzle's solution with a 4-digit key, so it can be
+//! played without giving the answers away. The file keeps a checksum of the real
+//! solution, which tells whether a key unlocks it; with only 10,000 keys they can
+//! also be tried one by one.
+
+use crate::ProviderError;
+use puz_parse::Puzzle;
+use std::path::Path;
+
+/// Offset of the checksum of the unscrambled solution in the `.puz` header.
+const SCRAMBLED_CHECKSUM_OFFSET: usize = 0x1E;
+/// Offset of the scrambled tag in the `.puz` header.
+const SCRAMBLED_TAG_OFFSET: usize = 0x32;
+/// Value of the scrambled tag when the solution is locked.
+const SCRAMBLED_TAG: u16 = 0x0004;
+
+/// Largest unlock key.
+pub const MAX_KEY: u16 = 9999;
+
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    let bytes = data.get(offset..offset + 2)?;
+    Some(u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+/// Checksum of the unscrambled solution, from the header of a `.puz` file with a
+/// locked solution.
+pub fn scrambled_checksum(data: &[u8]) -> Option<u16> {
+    (read_u16(data, SCRAMBLED_TAG_OFFSET)? == SCRAMBLED_TAG)
+        .then(|| read_u16(data, SCRAMBLED_CHECKSUM_OFFSET))
+        .flatten()
+}
+
+/// The `.puz` checksum of a byte region.
+fn checksum(data: &[u8]) -> u16 {
+    data.iter().fold(0u16, |sum, &byte| {
+        sum.rotate_right(1).wrapping_add(byte as u16)
+    })
+}
+
+/// The solution's letters column by column, skipping blocks.
+fn solution_letters(puzzle: &Puzzle) -> Vec<u8> {
+    let rows: Vec<&[u8]> = puzzle.grid.solution.iter().map(|r| r.as_bytes()).collect();
+    let width = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+    (0..width)
+        .flat_map(|col| rows.iter().filter_map(move |row| row.get(col).copied()))
+        .filter(|&c| c != b'.')
+        .collect()
+}
+
+/// `puzzle` with its solution letters replaced, column by column, by `letters`.
+fn with_solution_letters(puzzle: &Puzzle, letters: &[u8]) -> Puzzle {
+    let mut rows: Vec<Vec<u8>> = puzzle
+        .grid
+        .solution
+        .iter()
+        .map(|r| r.as_bytes().to_vec())
+        .collect();
+    let width = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+    let mut letters = letters.iter();
+    for col in 0..width {
+        for row in rows.iter_mut() {
+            if let Some(c) = row.get_mut(col).filter(|c| **c != b'.') {
+                *c = *letters.next().unwrap_or(c);
+            }
+        }
+    }
+
+    let mut puzzle = puzzle.clone();
+    puzzle.grid.solution = rows
+        .into_iter()
+        .map(|r| String::from_utf8_lossy(&r).into_owned())
+        .collect();
+    puzzle
+}
+
+fn key_digits(key: u16) -> [u8; 4] {
+    let key = key.min(MAX_KEY);
+    [
+        (key / 1000) as u8,
+        (key / 100 % 10) as u8,
+        (key / 10 % 10) as u8,
+        (key % 10) as u8,
+    ]
+}
+
+/// Shift each letter by the key digit for its position, backwards if `back`.
+fn shift(letters: &mut [u8], digits: [u8; 4], back: bool) {
+    for (i, c) in letters.iter_mut().enumerate() {
+        let by = if back {
+            26 - digits[i % 4]
+        } else {
+            digits[i % 4]
+        };
+        *c = b'A' + (*c - b'A' + by) % 26;
+    }
+}
+
+fn unscramble_letters(mut letters: Vec<u8>, digits: [u8; 4]) -> Vec<u8> {
+    let len = letters.len();
+    for &digit in digits.iter().rev() {
+        // undo the interleaving of the two halves
+        letters = letters
+            .iter()
+            .skip(1)
+            .step_by(2)
+            .chain(letters.iter().step_by(2))
+            .copied()
+            .collect();
+        letters.rotate_right(digit as usize % len);
+        shift(&mut letters, digits, true);
+    }
+    letters
+}
+
+#[cfg(test)]
+fn scramble_letters(mut letters: Vec<u8>, digits: [u8; 4]) -> Vec<u8> {
+    let len = letters.len();
+    for &digit in digits.iter() {
+        shift(&mut letters, digits, false);
+        letters.rotate_left(digit as usize % len);
+        let (first, second) = letters.split_at(len / 2);
+        let mut shuffled: Vec<u8> = second
+            .iter()
+            .zip(first)
+            .flat_map(|(&a, &b)| [a, b])
+            .collect();
+        if len % 2 == 1 {
+            shuffled.push(letters[len - 1]);
+        }
+        letters = shuffled;
+    }
+    letters
+}
+
+/// Unlock the solution of `puzzle` with `key`, if the result matches the
+/// `expected` checksum.
+///
+/// Only solutions of plain letters can be scrambled, so any other character
+/// (a lowercase letter or a digit) fails the unlock.
+pub fn unlock(puzzle: &Puzzle, key: u16, expected: u16) -> Option<Puzzle> {
+    let letters = solution_letters(puzzle);
+    if letters.is_empty() || !letters.iter().all(u8::is_ascii_uppercase) {
+        return None;
+    }
+    let letters = unscramble_letters(letters, key_digits(key));
+    if checksum(&letters) != expected {
+        return None;
+    }
+
+    let mut puzzle = with_solution_letters(puzzle, &letters);
+    puzzle.info.is_scrambled = false;
+    Some(puzzle)
+}
+
+/// Find the key that unlocks `puzzle`, trying all of them.
+pub fn find_key(puzzle: &Puzzle, checksum: u16) -> Option<u16> {
+    (0..=MAX_KEY).find(|&key| unlock(puzzle, key, checksum).is_some())
+}
+
+/// Unlock the solution of a `.puz` file loaded from `path`, with `key` or by
+/// trying every key when it's `None`. Returns the unlocked puzzle and its key.
+pub fn unlock_file(
+    path: &Path,
+    puzzle: &Puzzle,
+    key: Option<u16>,
+) -> Result<(Puzzle, u16), ProviderError> {
+    let data = std::fs::read(path)?;
+    let Some(checksum) = scrambled_checksum(&data).filter(|_| puzzle.info.is_scrambled) else {
+        return Err(ProviderError::InvalidPuzzleData(
+            "the solution is not locked".to_string(),
+        ));
+    };
+    let key = match key {
+        Some(key) => key,
+        None => find_key(puzzle, checksum).ok_or_else(|| {
+            ProviderError::InvalidPuzzleData("no key unlocks the solution".to_string())
+        })?,
+    };
+    unlock(puzzle, key, checksum)
+        .map(|puzzle| (puzzle, key))
+        .ok_or_else(|| ProviderError::InvalidPuzzleData(format!("{:04} is not the key", key)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unlock() {
+        let mut puzzle =
+            super::super::crossword_compiler::parse(include_str!("../../samples/warm-up.xml"))
+                .unwrap();
+        let letters = solution_letters(&puzzle);
+        let expected = checksum(&letters);
+        let solution = puzzle.grid.solution.clone();
+
+        let scrambled = scramble_letters(letters.clone(), key_digits(1234));
+        assert_ne!(scrambled, letters);
+        assert_eq!(
+            unscramble_letters(scrambled.clone(), key_digits(1234)),
+            letters
+        );
+
+        puzzle = with_solution_letters(&puzzle, &scrambled);
+        puzzle.info.is_scrambled = true;
+        assert!(unlock(&puzzle, 4321, expected).is_none());
+
+        let unlocked = unlock(&puzzle, 1234, expected).unwrap();
+        assert_eq!(unlocked.grid.solution, solution);
+        assert!(!unlocked.info.is_scrambled);
+        assert!(find_key(&puzzle, expected).is_some());
+    }
+}
@@ -0,0 +1,168 @@
+//! SVG rendering of a puzzle grid, for sharing puzzles as images.
+//!
+//! The grid is drawn with clue numbers and circled cells under a title block,
+//! either empty (ready to solve) or filled in with the solution. The puzzle
+//! notes, with its source attribution, are kept in the SVG description.
+
+use crate::entries::entries;
+use puz_parse::Puzzle;
+use std::collections::HashMap;
+use std::fmt::Write;
+
+const CELL_SIZE: usize = 32;
+const MARGIN: usize = 16;
+
+/// Escape text for use in SVG content and attribute values.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Render a puzzle as an SVG document.
+///
+/// With `show_solution`, each cell shows its solution letter.
+pub fn render(puzzle: &Puzzle, show_solution: bool) -> String {
+    let grid: Vec<Vec<char>> = puzzle
+        .grid
+        .solution
+        .iter()
+        .map(|row| row.chars().collect())
+        .collect();
+    let rows = grid.len();
+    let cols = grid.iter().map(Vec::len).max().unwrap_or(0);
+
+    let numbers: HashMap<(usize, usize), u16> = entries(puzzle)
+        .into_iter()
+        .map(|entry| ((entry.row, entry.col), entry.number))
+        .collect();
+
+    // Title block: title, then author and copyright
+    let title = puzzle.info.title.trim();
+    let byline = [puzzle.info.author.trim(), puzzle.info.copyright.trim()]
+        .into_iter()
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join(" · ");
+    let title_height = match (title.is_empty(), byline.is_empty()) {
+        (true, true) => 0,
+        (false, false) => 44,
+        _ => 26,
+    };
+
+    let width = cols * CELL_SIZE + 2 * MARGIN;
+    let height = rows * CELL_SIZE + 2 * MARGIN + title_height;
+
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}" font-family="Helvetica, Arial, sans-serif">"#
+    );
+    // Notes carry the source attribution, see `Source::annotate`
+    let notes = puzzle.info.notes.trim();
+    if !notes.is_empty() {
+        let _ = writeln!(svg, "<desc>{}</desc>", escape(notes));
+    }
+    let _ = writeln!(
+        svg,
+        r#"<rect width="{width}" height="{height}" fill="white"/>"#
+    );
+
+    let mut y = MARGIN;
+    if !title.is_empty() {
+        y += 18;
+        let _ = writeln!(
+            svg,
+            r#"<text x="{MARGIN}" y="{y}" font-size="18" font-weight="bold">{}</text>"#,
+            escape(title)
+        );
+    }
+    if !byline.is_empty() {
+        y += if title.is_empty() { 12 } else { 18 };
+        let _ = writeln!(
+            svg,
+            r##"<text x="{MARGIN}" y="{y}" font-size="12" fill="#555">{}</text>"##,
+            escape(&byline)
+        );
+    }
+    let top = MARGIN + title_height;
+
+    let circles = puzzle.extensions.circles.as_ref();
+    for (row, cells) in grid.iter().enumerate() {
+        for (col, &letter) in cells.iter().enumerate() {
+            let x = MARGIN + col * CELL_SIZE;
+            let y = top + row * CELL_SIZE;
+            let fill = if letter == '.' { "black" } else { "white" };
+            let _ = writeln!(
+                svg,
+                r#"<rect x="{x}" y="{y}" width="{CELL_SIZE}" height="{CELL_SIZE}" fill="{fill}" stroke="black"/>"#
+            );
+            if letter == '.' {
+                continue;
+            }
+
+            let circled = circles
+                .and_then(|c| c.get(row))
+                .and_then(|r| r.get(col))
+                .copied()
+                .unwrap_or(false);
+            if circled {
+                let _ = writeln!(
+                    svg,
+                    r#"<circle cx="{}" cy="{}" r="{}" fill="none" stroke="black" stroke-width="0.75"/>"#,
+                    x + CELL_SIZE / 2,
+                    y + CELL_SIZE / 2,
+                    CELL_SIZE / 2 - 1
+                );
+            }
+            if let Some(no) = numbers.get(&(row, col)) {
+                let _ = writeln!(
+                    svg,
+                    r#"<text x="{}" y="{}" font-size="9">{}</text>"#,
+                    x + 2,
+                    y + 10,
+                    no
+                );
+            }
+            if show_solution {
+                let _ = writeln!(
+                    svg,
+                    r#"<text x="{}" y="{}" font-size="18" text-anchor="middle">{}</text>"#,
+                    x + CELL_SIZE / 2,
+                    y + CELL_SIZE - 7,
+                    escape(&letter.to_string())
+                );
+            }
+        }
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::formats::crossword_compiler;
+
+    #[test]
+    fn test_render_numbers_and_solution() {
+        let puzzle = crossword_compiler::parse(include_str!("../../samples/warm-up.xml")).unwrap();
+
+        let empty = render(&puzzle, false);
+        assert!(empty.starts_with("<svg"));
+        assert!(empty.contains(r#"font-size="9">1</text>"#));
+        assert!(!empty.contains(r#"text-anchor="middle""#));
+
+        let solved = render(&puzzle, true);
+        let letters = puzzle
+            .grid
+            .solution
+            .iter()
+            .flat_map(|row| row.chars())
+            .filter(|&c| c != '.')
+            .count();
+        assert_eq!(solved.matches(r#"text-anchor="middle""#).count(), letters);
+    }
+}
@@ -1,14 +1,50 @@
+pub mod assist;
+pub mod entries;
 pub mod formats;
+pub mod lint;
 pub mod providers;
+pub mod samples;
 pub mod util;
 
+mod difficulty;
+pub use difficulty::Difficulty;
+
 mod errors;
 pub use errors::ProviderError;
 
+mod language;
+pub use language::Language;
+
+mod schedule;
+pub use schedule::{Archive, DateZone, Schedule};
+
+mod source;
+pub use source::{FILE_PROVIDER, Source};
+
+/// How many publication dates are tried for the latest puzzle, in case the most
+/// recent one isn't up yet.
+const LATEST_ATTEMPTS: usize = 3;
+
 // Re-export provider modules for convenience
 pub use providers::guardian::{self, GuardianVariant};
 pub use providers::simply_daily::{self, SimplyDailyVariant};
 
+/// Result of [`PuzzleProvider::download_if_modified`].
+#[derive(Debug)]
+pub enum Fetched {
+    /// The puzzle is new or changed.
+    Modified {
+        puzzle: Box<puz_parse::Puzzle>,
+        /// Validators of the response, to check for changes later.
+        validators: Option<util::Validators>,
+        /// Publication date of the puzzle, if known (see
+        /// [`PuzzleProvider::download_dated`]).
+        date: Option<chrono::NaiveDate>,
+    },
+    /// The puzzle hasn't changed since the earlier download.
+    NotModified,
+}
+
 /// Available puzzle providers.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum PuzzleProvider {
@@ -75,6 +111,367 @@ impl PuzzleProvider {
         }
     }
 
+    /// Publisher page for this provider, used for attribution.
+    pub fn url(&self) -> &'static str {
+        match self {
+            PuzzleProvider::LovattsCryptic => "https://www.lovatts.com.au",
+            PuzzleProvider::GuardianCryptic => {
+                "https://www.theguardian.com/crosswords/series/cryptic"
+            }
+            PuzzleProvider::GuardianEveryman => {
+                "https://www.theguardian.com/crosswords/series/everyman"
+            }
+            PuzzleProvider::GuardianSpeedy => {
+                "https://www.theguardian.com/crosswords/series/speedy"
+            }
+            PuzzleProvider::GuardianQuick => "https://www.theguardian.com/crosswords/series/quick",
+            PuzzleProvider::GuardianPrize => "https://www.theguardian.com/crosswords/series/prize",
+            PuzzleProvider::GuardianWeekend => {
+                "https://www.theguardian.com/crosswords/series/weekend-crossword"
+            }
+            PuzzleProvider::GuardianQuiptic => {
+                "https://www.theguardian.com/crosswords/series/quiptic"
+            }
+            PuzzleProvider::WashingtonPost => "https://www.washingtonpost.com/crossword-puzzles/",
+            PuzzleProvider::UsaToday => "https://games.usatoday.com/category/crossword",
+            PuzzleProvider::SimplyDaily => "https://simplydailypuzzles.com/daily-crossword/",
+            PuzzleProvider::SimplyDailyCryptic => "https://simplydailypuzzles.com/daily-cryptic/",
+            PuzzleProvider::SimplyDailyQuick => {
+                "https://simplydailypuzzles.com/daily-quick-crossword/"
+            }
+            PuzzleProvider::Universal => "https://www.universaluclick.com",
+            PuzzleProvider::DailyPop => "https://dailypopcrosswordsweb.puzzlenation.com",
+        }
+    }
+
+    /// Default licensing note, used when a puzzle carries no copyright line.
+    pub fn license_note(&self) -> &'static str {
+        match self {
+            PuzzleProvider::LovattsCryptic => "© Lovatts. Personal use only.",
+            PuzzleProvider::GuardianCryptic
+            | PuzzleProvider::GuardianEveryman
+            | PuzzleProvider::GuardianSpeedy
+            | PuzzleProvider::GuardianQuick
+            | PuzzleProvider::GuardianPrize
+            | PuzzleProvider::GuardianWeekend
+            | PuzzleProvider::GuardianQuiptic => "© Guardian News & Media. Personal use only.",
+            PuzzleProvider::WashingtonPost => "© The Washington Post. Personal use only.",
+            PuzzleProvider::UsaToday => "© USA Today. Personal use only.",
+            PuzzleProvider::SimplyDaily
+            | PuzzleProvider::SimplyDailyCryptic
+            | PuzzleProvider::SimplyDailyQuick => "© Simply Daily Puzzles. Personal use only.",
+            PuzzleProvider::Universal => "© Andrews McMeel Universal. Personal use only.",
+            PuzzleProvider::DailyPop => "© PuzzleNation. Personal use only.",
+        }
+    }
+
+    /// Language of the provider's puzzles.
+    ///
+    /// All current providers publish in English; non-English puzzles are
+    /// available among the [`samples`].
+    pub fn language(&self) -> Language {
+        Language::English
+    }
+
+    /// Days on which this provider publishes a new puzzle.
+    pub fn schedule(&self) -> Schedule {
+        match self {
+            PuzzleProvider::GuardianCryptic => Schedule::Weekdays,
+            PuzzleProvider::GuardianQuick => Schedule::MondayToSaturday,
+            PuzzleProvider::GuardianEveryman | PuzzleProvider::GuardianSpeedy => {
+                Schedule::Weekly(chrono::Weekday::Sun)
+            }
+            PuzzleProvider::GuardianPrize | PuzzleProvider::GuardianWeekend => {
+                Schedule::Weekly(chrono::Weekday::Sat)
+            }
+            PuzzleProvider::GuardianQuiptic => Schedule::Weekly(chrono::Weekday::Mon),
+            PuzzleProvider::WashingtonPost => Schedule::Weekly(chrono::Weekday::Sun),
+            PuzzleProvider::LovattsCryptic
+            | PuzzleProvider::UsaToday
+            | PuzzleProvider::SimplyDaily
+            | PuzzleProvider::SimplyDailyCryptic
+            | PuzzleProvider::SimplyDailyQuick
+            | PuzzleProvider::Universal
+            | PuzzleProvider::DailyPop => Schedule::Daily,
+        }
+    }
+
+    /// How far back this provider's puzzles can be fetched.
+    pub fn archive(&self) -> Archive {
+        match self {
+            // the Guardian client only knows how to fetch the current puzzle
+            _ if self.guardian_variant().is_some() => Archive::LatestOnly,
+            // the WaPo API drops older puzzles
+            PuzzleProvider::WashingtonPost => Archive::Recent,
+            _ => Archive::Dated,
+        }
+    }
+
+    /// Usual grid size (width, height) of this provider's puzzles.
+    pub fn typical_size(&self) -> (u8, u8) {
+        match self {
+            PuzzleProvider::WashingtonPost => (21, 21),
+            PuzzleProvider::GuardianSpeedy
+            | PuzzleProvider::GuardianQuick
+            | PuzzleProvider::GuardianWeekend
+            | PuzzleProvider::LovattsCryptic
+            | PuzzleProvider::SimplyDaily
+            | PuzzleProvider::SimplyDailyCryptic => (13, 13),
+            PuzzleProvider::SimplyDailyQuick => (11, 11),
+            PuzzleProvider::GuardianCryptic
+            | PuzzleProvider::GuardianEveryman
+            | PuzzleProvider::GuardianPrize
+            | PuzzleProvider::GuardianQuiptic
+            | PuzzleProvider::UsaToday
+            | PuzzleProvider::Universal
+            | PuzzleProvider::DailyPop => (15, 15),
+        }
+    }
+
+    /// Kind of puzzle this provider publishes.
+    pub fn difficulty(&self) -> Difficulty {
+        match self {
+            PuzzleProvider::LovattsCryptic
+            | PuzzleProvider::GuardianCryptic
+            | PuzzleProvider::GuardianEveryman
+            | PuzzleProvider::GuardianPrize
+            | PuzzleProvider::GuardianQuiptic
+            | PuzzleProvider::SimplyDailyCryptic => Difficulty::Cryptic,
+            PuzzleProvider::GuardianSpeedy
+            | PuzzleProvider::GuardianQuick
+            | PuzzleProvider::SimplyDailyQuick => Difficulty::Quick,
+            PuzzleProvider::GuardianWeekend
+            | PuzzleProvider::WashingtonPost
+            | PuzzleProvider::UsaToday
+            | PuzzleProvider::SimplyDaily
+            | PuzzleProvider::Universal
+            | PuzzleProvider::DailyPop => Difficulty::Standard,
+        }
+    }
+
+    /// Whether puzzles come with their solution, so they can be checked.
+    ///
+    /// Prize puzzles are published without one; it can be fetched later with
+    /// [`guardian::download_solution`].
+    pub fn includes_solutions(&self) -> bool {
+        *self != PuzzleProvider::GuardianPrize
+    }
+
+    /// One-line summary of the schedule, grid size and difficulty, e.g.
+    /// "Weekly (Sunday) · 21x21 · Standard".
+    pub fn summary(&self) -> String {
+        let (width, height) = self.typical_size();
+        let mut summary = format!(
+            "{} · {}x{} · {}",
+            self.schedule().name(),
+            width,
+            height,
+            self.difficulty().name()
+        );
+        if !self.includes_solutions() {
+            summary.push_str(" · No solutions");
+        }
+        summary
+    }
+
+    /// Whether puzzles can be requested by date; otherwise only the latest one is available.
+    pub fn supports_dates(&self) -> bool {
+        self.archive() != Archive::LatestOnly
+    }
+
+    /// Whether fetching puzzles needs an account or subscription.
+    ///
+    /// All current providers serve their puzzles without signing in.
+    pub fn requires_auth(&self) -> bool {
+        false
+    }
+
+    /// Whether this provider may be used, i.e. it wasn't turned off with
+    /// [`util::set_disabled_providers`].
+    pub fn is_enabled(&self) -> bool {
+        !util::is_provider_disabled(self.name(), &self.slug())
+    }
+
+    /// Providers that may be used, in the order of [`PuzzleProvider::ALL`].
+    pub fn enabled() -> impl Iterator<Item = PuzzleProvider> {
+        PuzzleProvider::ALL.into_iter().filter(|p| p.is_enabled())
+    }
+
+    /// Whether a puzzle for `date` should be downloadable as of `today`.
+    pub fn is_available(&self, date: chrono::NaiveDate, today: chrono::NaiveDate) -> bool {
+        self.supports_dates() && date <= today && self.schedule().publishes_on(date)
+    }
+
+    /// Time zone of the publisher, at whose midnight a new puzzle comes out.
+    pub fn timezone(&self) -> chrono_tz::Tz {
+        use chrono_tz::{America, Australia, Europe};
+
+        match self {
+            PuzzleProvider::LovattsCryptic => Australia::Sydney,
+            PuzzleProvider::GuardianCryptic
+            | PuzzleProvider::GuardianEveryman
+            | PuzzleProvider::GuardianSpeedy
+            | PuzzleProvider::GuardianQuick
+            | PuzzleProvider::GuardianPrize
+            | PuzzleProvider::GuardianWeekend
+            | PuzzleProvider::GuardianQuiptic
+            | PuzzleProvider::SimplyDaily
+            | PuzzleProvider::SimplyDailyCryptic
+            | PuzzleProvider::SimplyDailyQuick => Europe::London,
+            // Andrews McMeel is based in Kansas City
+            PuzzleProvider::Universal => America::Chicago,
+            PuzzleProvider::WashingtonPost
+            | PuzzleProvider::UsaToday
+            | PuzzleProvider::DailyPop => America::New_York,
+        }
+    }
+
+    /// Today's date for this provider: in its [time zone](Self::timezone), unless
+    /// another one was chosen with [`util::set_date_zone`].
+    pub fn today(&self) -> chrono::NaiveDate {
+        util::date_zone().date(chrono::Utc::now(), self.timezone())
+    }
+
+    /// Date of the most recent puzzle as of `today`, following the schedule, or
+    /// `None` if only the latest puzzle can be fetched.
+    pub fn latest_available_date(&self, today: chrono::NaiveDate) -> Option<chrono::NaiveDate> {
+        self.supports_dates()
+            .then(|| self.schedule().latest_on_or_before(today))
+    }
+
+    /// Short command-line name, e.g. "guardian-cryptic".
+    pub fn slug(&self) -> String {
+        self.name().to_lowercase().replace(' ', "-")
+    }
+
+    /// Find a provider by its [`slug`](Self::slug).
+    pub fn from_slug(slug: &str) -> Option<PuzzleProvider> {
+        PuzzleProvider::ALL
+            .into_iter()
+            .find(|provider| provider.slug() == slug)
+    }
+
+    /// Download the puzzle for `date` (YYYY-MM-DD), or the latest one if `date` is `None`.
+    ///
+    /// Providers without an archive ignore the date and return their latest puzzle.
+    /// For the others, the latest puzzle is the one of the
+    /// [latest available date](Self::latest_available_date), or of an earlier
+    /// publication date if that one isn't up yet.
+    pub async fn download(&self, date: Option<&str>) -> Result<puz_parse::Puzzle, ProviderError> {
+        self.download_dated(date).await.map(|(puzzle, _)| puzzle)
+    }
+
+    /// Download a puzzle like [`download`](Self::download), along with its
+    /// publication date: `date`, or the date the latest puzzle was found on.
+    /// The date is `None` for providers without an archive, whose latest puzzle
+    /// has no known date.
+    pub async fn download_dated(
+        &self,
+        date: Option<&str>,
+    ) -> Result<(puz_parse::Puzzle, Option<chrono::NaiveDate>), ProviderError> {
+        if !self.is_enabled() {
+            return Err(ProviderError::Disabled(self.name().to_string()));
+        }
+
+        if let Some(variant) = self.guardian_variant() {
+            // Guardian doesn't support date-based download, use latest
+            let puzzle = providers::guardian::download_latest(variant).await?;
+            return Ok((puzzle, None));
+        }
+
+        match date {
+            Some(date) => {
+                let puzzle = self.download_date(date).await?;
+                Ok((
+                    puzzle,
+                    chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").ok(),
+                ))
+            }
+            None => {
+                let (puzzle, date) = self.download_latest().await?;
+                Ok((puzzle, Some(date)))
+            }
+        }
+    }
+
+    /// Download the puzzle for `date` like [`download`](Self::download), unless it
+    /// hasn't changed since an earlier download that returned `previous`.
+    ///
+    /// The request for the puzzle is made conditional with the ETag and
+    /// Last-Modified headers of the earlier response, so an unchanged puzzle isn't
+    /// downloaded again.
+    pub async fn download_if_modified(
+        &self,
+        date: Option<&str>,
+        previous: Option<util::Validators>,
+    ) -> Result<Fetched, ProviderError> {
+        let (result, validators, not_modified) =
+            util::with_validators(previous, self.download_dated(date)).await;
+        if not_modified {
+            return Ok(Fetched::NotModified);
+        }
+        result.map(|(puzzle, date)| Fetched::Modified {
+            puzzle: Box::new(puzzle),
+            validators,
+            date,
+        })
+    }
+
+    /// Download the latest puzzle of a provider with dated puzzles, walking back
+    /// through its publication dates. Returns the date the puzzle was found on.
+    async fn download_latest(
+        &self,
+    ) -> Result<(puz_parse::Puzzle, chrono::NaiveDate), ProviderError> {
+        let Some(mut date) = self.latest_available_date(self.today()) else {
+            return Err(ProviderError::Other(format!(
+                "{} has no dated puzzles",
+                self.name()
+            )));
+        };
+
+        let mut first_error = None;
+        for _ in 0..LATEST_ATTEMPTS {
+            let error = match self
+                .download_date(&date.format("%Y-%m-%d").to_string())
+                .await
+            {
+                Ok(puzzle) => return Ok((puzzle, date)),
+                Err(error) => error,
+            };
+            // no point trying earlier dates without a connection
+            let offline =
+                matches!(&error, ProviderError::FetchError(e) if e.is_connect() || e.is_timeout());
+            first_error.get_or_insert(error);
+            // an unchanged puzzle is still the latest
+            if offline || util::not_modified() {
+                break;
+            }
+            date = self
+                .schedule()
+                .latest_on_or_before(date - chrono::Days::new(1));
+        }
+        Err(first_error.expect("at least one date is tried"))
+    }
+
+    /// Download the puzzle for `date` (YYYY-MM-DD) from a provider with dated puzzles.
+    async fn download_date(&self, date: &str) -> Result<puz_parse::Puzzle, ProviderError> {
+        use providers::*;
+
+        if let Some(variant) = self.simply_daily_variant() {
+            return simply_daily::download(variant, date).await;
+        }
+
+        match self {
+            PuzzleProvider::LovattsCryptic => lovatts_cryptic::download(date).await,
+            // WaPo expects date in YYYY/MM/DD format
+            PuzzleProvider::WashingtonPost => wapo::download(&date.replace('-', "/")).await,
+            PuzzleProvider::UsaToday => usa_today::download(date).await,
+            PuzzleProvider::Universal => universal::download(date).await,
+            PuzzleProvider::DailyPop => daily_pop::download(date).await,
+            _ => unreachable!("Guardian and Simply Daily variants are handled above"),
+        }
+    }
+
     /// Get the Guardian variant if this is a Guardian provider
     pub fn guardian_variant(&self) -> Option<GuardianVariant> {
         match self {
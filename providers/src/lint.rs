@@ -0,0 +1,397 @@
+//! Quality checks for finished puzzles.
+//!
+//! [`lint`] runs structural checks on the grid, verifies that every entry has a
+//! clue with a matching enumeration, and flags fill problems such as repeated
+//! answers, so constructors can catch them before publishing. [`check_blocklist`]
+//! additionally flags entries containing words from a list of banned words.
+
+use crate::entries::{EntryDirection, check_enumerations, entries};
+use puz_parse::Puzzle;
+use std::collections::{HashMap, HashSet};
+
+/// How serious an issue is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// Worth knowing, but common in published puzzles (e.g. unchecked letters).
+    Note,
+    /// Likely a mistake, but the puzzle is still solvable.
+    Warning,
+    /// The puzzle is broken or can't be solved as clued.
+    Error,
+}
+
+impl Severity {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Severity::Note => "note",
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        }
+    }
+}
+
+/// A single problem found by [`lint`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Issue {
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl Issue {
+    fn new(severity: Severity, message: impl Into<String>) -> Self {
+        Self {
+            severity,
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for Issue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.severity.name(), self.message)
+    }
+}
+
+/// Check a puzzle, returning its issues from most to least severe.
+pub fn lint(puzzle: &Puzzle) -> Vec<Issue> {
+    let grid: Vec<Vec<char>> = puzzle
+        .grid
+        .solution
+        .iter()
+        .map(|row| row.chars().collect())
+        .collect();
+
+    let mut issues = check_shape(puzzle, &grid);
+    if issues.is_empty() {
+        issues.extend(check_connected(&grid));
+        issues.extend(check_symmetry(&grid));
+    }
+
+    let entries = entries(puzzle);
+
+    // Clues
+    for entry in &entries {
+        if entry.clue.trim().is_empty() {
+            issues.push(Issue::new(
+                Severity::Error,
+                format!("{} {}: no clue", entry.number, entry.direction.name()),
+            ));
+        }
+    }
+    for (direction, clues) in [
+        (EntryDirection::Across, &puzzle.clues.across),
+        (EntryDirection::Down, &puzzle.clues.down),
+    ] {
+        let mut numbers: Vec<&u16> = clues.keys().collect();
+        numbers.sort();
+        for number in numbers {
+            let has_entry = entries
+                .iter()
+                .any(|e| e.direction == direction && e.number == *number);
+            if !has_entry {
+                issues.push(Issue::new(
+                    Severity::Warning,
+                    format!(
+                        "{} {}: clue has no matching entry in the grid",
+                        number,
+                        direction.name()
+                    ),
+                ));
+            }
+        }
+    }
+    issues.extend(
+        check_enumerations(puzzle)
+            .into_iter()
+            .map(|message| Issue::new(Severity::Error, message)),
+    );
+
+    // Fill
+    let mut seen: HashMap<&str, (u16, EntryDirection)> = HashMap::new();
+    for entry in &entries {
+        let label = format!("{} {}", entry.number, entry.direction.name());
+        match seen.get(entry.answer.as_str()) {
+            Some((number, direction)) => issues.push(Issue::new(
+                Severity::Warning,
+                format!(
+                    "{}: {} is also the answer to {} {}",
+                    label,
+                    entry.answer,
+                    number,
+                    direction.name()
+                ),
+            )),
+            None => {
+                seen.insert(&entry.answer, (entry.number, entry.direction));
+            }
+        }
+        if entry.answer.chars().count() < 3 {
+            issues.push(Issue::new(
+                Severity::Warning,
+                format!("{}: {} is shorter than 3 letters", label, entry.answer),
+            ));
+        }
+    }
+
+    let checked: HashSet<(usize, usize)> = {
+        let mut counts: HashMap<(usize, usize), usize> = HashMap::new();
+        for cell in entries.iter().flat_map(|e| e.cells()) {
+            *counts.entry(cell).or_default() += 1;
+        }
+        counts
+            .into_iter()
+            .filter(|(_, count)| *count > 1)
+            .map(|(cell, _)| cell)
+            .collect()
+    };
+    let unchecked = letter_cells(&grid)
+        .filter(|cell| !checked.contains(cell))
+        .count();
+    if unchecked > 0 {
+        issues.push(Issue::new(
+            Severity::Note,
+            format!("{} letter(s) are unchecked (in only one entry)", unchecked),
+        ));
+    }
+
+    issues.sort_by_key(|issue| std::cmp::Reverse(issue.severity));
+    issues
+}
+
+/// Words that must not appear in a grid.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Blocklist {
+    words: HashSet<String>,
+}
+
+impl Blocklist {
+    /// Parse a blocklist with one entry per line.
+    ///
+    /// Entries are compared by their letters only, ignoring case, spaces and
+    /// punctuation. Empty lines and lines starting with `#` are skipped.
+    pub fn parse(text: &str) -> Self {
+        let words = text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.starts_with('#'))
+            .map(normalize)
+            .filter(|word| !word.is_empty())
+            .collect();
+        Self { words }
+    }
+
+    /// Load a blocklist file (see [`Blocklist::parse`]).
+    pub fn load(path: &std::path::Path) -> std::io::Result<Self> {
+        Ok(Self::parse(&std::fs::read_to_string(path)?))
+    }
+
+    /// Whether `word` is on the list.
+    pub fn contains(&self, word: &str) -> bool {
+        self.words.contains(&normalize(word))
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+}
+
+/// Uppercase letters of `text`.
+fn normalize(text: &str) -> String {
+    text.chars()
+        .filter(|c| c.is_alphabetic())
+        .flat_map(char::to_uppercase)
+        .collect()
+}
+
+/// Check a puzzle's entries against a blocklist.
+///
+/// An answer on the list is an error. A listed word of three or more letters
+/// inside a longer answer is a warning, since crossings can spell words that
+/// nobody chose (they are often innocent, as in SCUNTHORPE).
+pub fn check_blocklist(puzzle: &Puzzle, blocklist: &Blocklist) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    for entry in entries(puzzle) {
+        let label = format!("{} {}", entry.number, entry.direction.name());
+        let answer = normalize(&entry.answer);
+        if blocklist.words.contains(&answer) {
+            issues.push(Issue::new(
+                Severity::Error,
+                format!("{}: {} is on the blocklist", label, answer),
+            ));
+            continue;
+        }
+
+        let mut found: Vec<&str> = blocklist
+            .words
+            .iter()
+            .filter(|word| word.chars().count() >= 3 && answer.contains(word.as_str()))
+            .map(String::as_str)
+            .collect();
+        found.sort_unstable();
+        for word in found {
+            issues.push(Issue::new(
+                Severity::Warning,
+                format!("{}: {} contains blocklisted {}", label, answer, word),
+            ));
+        }
+    }
+    issues.sort_by_key(|issue| std::cmp::Reverse(issue.severity));
+    issues
+}
+
+/// Positions of all letter (non-black) cells.
+fn letter_cells(grid: &[Vec<char>]) -> impl Iterator<Item = (usize, usize)> + '_ {
+    grid.iter().enumerate().flat_map(|(row, cells)| {
+        cells
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| **c != '.')
+            .map(move |(col, _)| (row, col))
+    })
+}
+
+/// The grid must be rectangular and match the size in the puzzle info.
+fn check_shape(puzzle: &Puzzle, grid: &[Vec<char>]) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    if grid.is_empty() {
+        issues.push(Issue::new(Severity::Error, "the grid is empty"));
+        return issues;
+    }
+    if grid.len() != puzzle.info.height as usize {
+        issues.push(Issue::new(
+            Severity::Error,
+            format!(
+                "the grid has {} rows, but the puzzle height is {}",
+                grid.len(),
+                puzzle.info.height
+            ),
+        ));
+    }
+    for (row, cells) in grid.iter().enumerate() {
+        if cells.len() != puzzle.info.width as usize {
+            issues.push(Issue::new(
+                Severity::Error,
+                format!(
+                    "row {} has {} cells, but the puzzle width is {}",
+                    row + 1,
+                    cells.len(),
+                    puzzle.info.width
+                ),
+            ));
+        }
+    }
+    issues
+}
+
+/// All letter cells must be reachable from each other.
+fn check_connected(grid: &[Vec<char>]) -> Option<Issue> {
+    let letters: HashSet<(usize, usize)> = letter_cells(grid).collect();
+    let start = *letters.iter().min()?;
+
+    let mut reached = HashSet::from([start]);
+    let mut stack = vec![start];
+    while let Some((row, col)) = stack.pop() {
+        let neighbours = [
+            (row.wrapping_sub(1), col),
+            (row + 1, col),
+            (row, col.wrapping_sub(1)),
+            (row, col + 1),
+        ];
+        for cell in neighbours {
+            if letters.contains(&cell) && reached.insert(cell) {
+                stack.push(cell);
+            }
+        }
+    }
+
+    (reached.len() < letters.len()).then(|| {
+        Issue::new(
+            Severity::Error,
+            format!(
+                "the grid is split into separate parts ({} of {} letters can't be reached from the first one)",
+                letters.len() - reached.len(),
+                letters.len()
+            ),
+        )
+    })
+}
+
+/// Most published grids have 180° rotational symmetry.
+fn check_symmetry(grid: &[Vec<char>]) -> Option<Issue> {
+    let (height, width) = (grid.len(), grid[0].len());
+    let symmetric = (0..height).all(|row| {
+        (0..width)
+            .all(|col| (grid[row][col] == '.') == (grid[height - 1 - row][width - 1 - col] == '.'))
+    });
+    (!symmetric).then(|| {
+        Issue::new(
+            Severity::Note,
+            "black squares are not rotationally symmetric",
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::formats::crossword_compiler;
+
+    fn lattice() -> Puzzle {
+        crossword_compiler::parse(include_str!("../samples/lattice.xml")).unwrap()
+    }
+
+    #[test]
+    fn test_lint_sample_has_no_errors() {
+        let issues = lint(&lattice());
+        assert!(issues.iter().all(|issue| issue.severity == Severity::Note));
+        assert!(!issues.is_empty(), "lattice grids have unchecked letters");
+    }
+
+    #[test]
+    fn test_lint_reports_missing_clues_and_split_grids() {
+        let mut puzzle = lattice();
+        puzzle.clues.across.remove(&4);
+        puzzle.clues.down.insert(9, "Nowhere".to_string());
+        // wall off the right column
+        puzzle.grid.solution = vec![
+            "STE.M".to_string(),
+            "H.X.E".to_string(),
+            "ADA.T".to_string(),
+            "D.C.E".to_string(),
+            "EAT.R".to_string(),
+        ];
+
+        let issues = lint(&puzzle);
+        let messages: Vec<&str> = issues.iter().map(|issue| issue.message.as_str()).collect();
+        assert!(messages.contains(&"4 Across: no clue"));
+        assert!(messages.contains(&"9 Down: clue has no matching entry in the grid"));
+        assert!(messages.iter().any(|m| m.starts_with("the grid is split")));
+        // most severe first
+        assert_eq!(issues[0].severity, Severity::Error);
+    }
+
+    #[test]
+    fn test_check_blocklist() {
+        let puzzle = lattice();
+        let blocklist = Blocklist::parse("# banned\nsteam\n\n  ada \nxx\n");
+        assert_eq!(blocklist.len(), 3);
+        assert!(blocklist.contains("S-t-e-a-m"));
+
+        let messages: Vec<String> = check_blocklist(&puzzle, &blocklist)
+            .iter()
+            .map(|issue| issue.to_string())
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "error: 1 Across: STEAM is on the blocklist",
+                "warning: 4 Across: ADAPT contains blocklisted ADA",
+            ]
+        );
+        assert!(check_blocklist(&puzzle, &Blocklist::default()).is_empty());
+    }
+}
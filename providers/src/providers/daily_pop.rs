@@ -1,13 +1,17 @@
-use crate::formats::crossword_compiler;
-use crate::util::http_client;
 use crate::ProviderError;
+use crate::formats::crossword_compiler;
+use crate::util::{HttpClient, http_client, low_bandwidth};
 use puz_parse::Puzzle;
+use std::sync::Mutex;
+
+/// API key from the last setup script fetch, reused in low-bandwidth mode.
+static API_KEY: Mutex<Option<String>> = Mutex::new(None);
 
 /// Fetch the API key from the Daily Pop setup script.
-async fn get_api_key(client: &reqwest::Client) -> Result<String, ProviderError> {
+async fn get_api_key(client: &HttpClient) -> Result<String, ProviderError> {
     let setup_url = "http://dailypopcrosswordsweb.puzzlenation.com/crosswordSetup.js";
 
-    let res = client.get(setup_url).send().await?;
+    let res = client.get(setup_url).await?;
 
     if !res.status().is_success() {
         return Err(ProviderError::Other(
@@ -56,8 +60,22 @@ pub async fn download(date: &str) -> Result<Puzzle, ProviderError> {
 
     let client = http_client();
 
-    // Get API key
-    let api_key = get_api_key(&client).await?;
+    // Get API key, fetching the setup script once per session in low-bandwidth mode
+    let cached_key = API_KEY
+        .lock()
+        .ok()
+        .and_then(|key| key.clone())
+        .filter(|_| low_bandwidth());
+    let api_key = match cached_key {
+        Some(key) => key,
+        None => {
+            let key = get_api_key(&client).await?;
+            if let Ok(mut cached) = API_KEY.lock() {
+                *cached = Some(key.clone());
+            }
+            key
+        }
+    };
 
     let url = format!(
         "https://api.puzzlenation.com/dailyPopCrosswords/puzzles/daily/{}",
@@ -65,9 +83,7 @@ pub async fn download(date: &str) -> Result<Puzzle, ProviderError> {
     );
 
     let res = client
-        .get(&url)
-        .header("x-api-key", &api_key)
-        .send()
+        .get_with_headers(&url, &[("x-api-key", &api_key)])
         .await?;
 
     if !res.status().is_success() {
@@ -84,11 +100,7 @@ pub async fn download(date: &str) -> Result<Puzzle, ProviderError> {
 
 /// Download the latest Daily Pop crossword.
 pub async fn download_latest() -> Result<Puzzle, ProviderError> {
-    use chrono::Local;
-
-    let today = Local::now().date_naive();
-    let date_str = today.format("%Y-%m-%d").to_string();
-    download(&date_str).await
+    crate::PuzzleProvider::DailyPop.download(None).await
 }
 
 #[cfg(test)]
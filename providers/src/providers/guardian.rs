@@ -1,5 +1,6 @@
 use crate::ProviderError;
-use crate::util::http_client;
+use crate::entries::UNKNOWN_LETTER;
+use crate::util::{clean_clue_text, http_client, low_bandwidth};
 use puz_parse::Puzzle;
 use serde::Deserialize;
 use std::collections::HashMap;
@@ -29,6 +30,28 @@ impl GuardianVariant {
         }
     }
 
+    /// Path segment of the variant's puzzle pages (`/crosswords/<type>/<number>`).
+    fn type_path(&self) -> &'static str {
+        match self {
+            GuardianVariant::Cryptic => "cryptic",
+            GuardianVariant::Everyman => "everyman",
+            GuardianVariant::Speedy => "speedy",
+            GuardianVariant::Quick => "quick",
+            GuardianVariant::Prize => "prize",
+            GuardianVariant::Weekend => "weekend",
+            GuardianVariant::Quiptic => "quiptic",
+        }
+    }
+
+    /// Page of the puzzle with the given number.
+    pub fn puzzle_url(&self, number: u32) -> String {
+        format!(
+            "https://www.theguardian.com/crosswords/{}/{}",
+            self.type_path(),
+            number
+        )
+    }
+
     pub fn name(&self) -> &'static str {
         match self {
             GuardianVariant::Cryptic => "Guardian Cryptic",
@@ -50,7 +73,7 @@ pub async fn download_latest(variant: GuardianVariant) -> Result<Puzzle, Provide
     );
 
     let client = http_client();
-    let res = client.get(&landing_url).send().await?;
+    let res = client.get(&landing_url).await?;
 
     if !res.status().is_success() {
         return Err(ProviderError::Other(format!(
@@ -59,18 +82,73 @@ pub async fn download_latest(variant: GuardianVariant) -> Result<Puzzle, Provide
         )));
     }
 
-    let html = res.text().await?;
-
     // Find the latest puzzle link
-    let puzzle_url = extract_latest_puzzle_url(&html)?;
+    let puzzle_url = match read_page(res, find_puzzle_link).await? {
+        Page::Found(url) => url,
+        Page::Complete(html) => extract_latest_puzzle_url(&html)?,
+    };
 
     download_from_url(&puzzle_url).await
 }
 
+/// Download the solution of a puzzle, e.g. a prize puzzle's once the Guardian
+/// publishes it (usually a week later).
+///
+/// Returns the whole puzzle; merge its solution into a saved copy with
+/// [`entries::merge_solution`](crate::entries::merge_solution).
+pub async fn download_solution(
+    variant: GuardianVariant,
+    number: u32,
+) -> Result<Puzzle, ProviderError> {
+    let puzzle = download_from_url(&variant.puzzle_url(number)).await?;
+    if !crate::entries::has_solution(&puzzle) {
+        return Err(ProviderError::Other(format!(
+            "the solution of {} No {} isn't published yet",
+            variant.name(),
+            number
+        )));
+    }
+    Ok(puzzle)
+}
+
+/// Puzzle number in a Guardian title, e.g. 29733 for "Prize crossword No 29,733".
+pub fn puzzle_number(title: &str) -> Option<u32> {
+    let (_, number) = title.rsplit_once("No")?;
+    number.trim().replace(',', "").parse().ok()
+}
+
+/// A page read by [`read_page`].
+enum Page<T> {
+    /// The value was found before the end of the page.
+    Found(T),
+    /// The whole page.
+    Complete(String),
+}
+
+/// Read a page. In low-bandwidth mode, stop as soon as `find` succeeds on the
+/// part received so far, without downloading the rest.
+async fn read_page<T>(
+    mut res: reqwest::Response,
+    find: impl Fn(&str) -> Option<T>,
+) -> Result<Page<T>, ProviderError> {
+    if !low_bandwidth() {
+        return Ok(Page::Complete(res.text().await?));
+    }
+
+    let mut bytes = Vec::new();
+    while let Some(chunk) = res.chunk().await? {
+        bytes.extend_from_slice(&chunk);
+        if let Some(value) = find(&String::from_utf8_lossy(&bytes)) {
+            return Ok(Page::Found(value));
+        }
+    }
+    Ok(Page::Complete(String::from_utf8_lossy(&bytes).to_string()))
+}
+
 /// Download a Guardian crossword from a specific URL.
 pub async fn download_from_url(url: &str) -> Result<Puzzle, ProviderError> {
     let client = http_client();
-    let res = client.get(url).send().await?;
+    let res = client.get(url).await?;
 
     if !res.status().is_success() {
         return Err(ProviderError::Other(format!(
@@ -79,13 +157,15 @@ pub async fn download_from_url(url: &str) -> Result<Puzzle, ProviderError> {
         )));
     }
 
-    let html = res.text().await?;
-    let json_data = extract_crossword_json(&html)?;
+    let json_data = match read_page(res, |html| extract_crossword_json(html).ok()).await? {
+        Page::Found(data) => data,
+        Page::Complete(html) => extract_crossword_json(&html)?,
+    };
     parse(json_data)
 }
 
-fn extract_latest_puzzle_url(html: &str) -> Result<String, ProviderError> {
-    // Look for links matching /crosswords/<type>/<number>
+/// First `href` to a puzzle page (`/crosswords/<type>/<number>`), if any.
+fn find_puzzle_link(html: &str) -> Option<String> {
     for line in html.lines() {
         if let Some(start) = line.find("href=\"/crosswords/") {
             let rest = &line[start + 6..]; // skip 'href="'
@@ -98,11 +178,19 @@ fn extract_latest_puzzle_url(html: &str) -> Result<String, ProviderError> {
                         .next()
                         .is_some_and(|s| s.chars().all(|c| c.is_ascii_digit()))
                 {
-                    return Ok(format!("https://www.theguardian.com{}", path));
+                    return Some(format!("https://www.theguardian.com{}", path));
                 }
             }
         }
     }
+    None
+}
+
+fn extract_latest_puzzle_url(html: &str) -> Result<String, ProviderError> {
+    // Look for links matching /crosswords/<type>/<number>
+    if let Some(url) = find_puzzle_link(html) {
+        return Ok(url);
+    }
 
     // Fallback: use regex-like manual search
     let search_start = "/crosswords/";
@@ -229,7 +317,13 @@ fn parse(data: GuardianData) -> Result<Puzzle, ProviderError> {
     let mut grid: Vec<Vec<char>> = vec![vec!['.'; width as usize]; height as usize];
 
     for entry in &data.entries {
-        let solution = entry.solution.as_deref().unwrap_or("");
+        // multi-word answers may come with their spaces and hyphens; prize
+        // puzzles have no solutions until the week after, but still need their
+        // letter cells
+        let solution = match &entry.solution {
+            Some(solution) => crate::entries::grid_letters(solution),
+            None => UNKNOWN_LETTER.to_string().repeat(entry.length),
+        };
         let mut x = entry.position.x;
         let mut y = entry.position.y;
 
@@ -237,7 +331,11 @@ fn parse(data: GuardianData) -> Result<Puzzle, ProviderError> {
             if i >= entry.length {
                 break;
             }
-            if y < height as usize && x < width as usize {
+            // a crossing entry may already have the letter
+            if y < height as usize
+                && x < width as usize
+                && (grid[y][x] == '.' || ch != UNKNOWN_LETTER)
+            {
                 grid[y][x] = ch;
             }
             if entry.direction == "across" {
@@ -281,9 +379,9 @@ fn parse(data: GuardianData) -> Result<Puzzle, ProviderError> {
 
     for entry in entries_sorted {
         if entry.direction == "across" {
-            across_clues.insert(entry.number, entry.clue.clone());
+            across_clues.insert(entry.number, clean_clue_text(&entry.clue));
         } else {
-            down_clues.insert(entry.number, entry.clue.clone());
+            down_clues.insert(entry.number, clean_clue_text(&entry.clue));
         }
     }
 
@@ -354,4 +452,35 @@ mod tests {
             Err(e) => panic!("Download failed: {}", e),
         }
     }
+
+    #[test]
+    fn test_puzzle_number() {
+        assert_eq!(puzzle_number("Prize crossword No 29,733"), Some(29733));
+        assert_eq!(puzzle_number("Quick crossword No 17,000"), Some(17000));
+        assert_eq!(puzzle_number("Prize crossword"), None);
+        assert_eq!(
+            GuardianVariant::Prize.puzzle_url(29733),
+            "https://www.theguardian.com/crosswords/prize/29733"
+        );
+    }
+
+    #[test]
+    fn test_parse_without_solution() {
+        // a prize puzzle before its solution is out: 1 across crossing 1 down
+        let data: GuardianData = serde_json::from_str(
+            r#"{
+                "dimensions": {"rows": 2, "cols": 2},
+                "date": 0,
+                "entries": [
+                    {"direction": "across", "position": {"x": 0, "y": 0}, "length": 2, "clue": "A (2)", "number": 1},
+                    {"direction": "down", "position": {"x": 0, "y": 0}, "length": 2, "clue": "D (2)", "number": 1}
+                ]
+            }"#,
+        )
+        .unwrap();
+        let puzzle = parse(data).unwrap();
+        assert_eq!(puzzle.grid.solution, ["--", "-."]);
+        assert_eq!(puzzle.grid.blank, ["--", "-."]);
+        assert!(!crate::entries::has_solution(&puzzle));
+    }
 }
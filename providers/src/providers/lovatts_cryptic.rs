@@ -1,5 +1,5 @@
 use crate::ProviderError;
-use crate::util::http_client;
+use crate::util::{clean_clue_text, http_client};
 use puz_parse::Puzzle;
 use std::collections::HashMap;
 
@@ -16,7 +16,7 @@ pub async fn download(date: &str) -> Result<puz_parse::Puzzle, ProviderError> {
     // https://data.puzzlexperts.com/puzzleapp-v3/data.php?date=2025-15-12&psid=100000160
 
     let client = http_client();
-    let res = client.get(&url).send().await?;
+    let res = client.get(&url).await?;
     if !res.status().is_success() {
         return Err(ProviderError::Other(format!(
             "Failed to fetch {} HTTP {}",
@@ -167,7 +167,7 @@ fn parse(data: &str) -> Result<puz_parse::Puzzle, ProviderError> {
     let mut solution_grid = blank_grid.clone();
     let mut clue_positions: Vec<usize> = Vec::with_capacity(cluewords.len());
     for clueword in cluewords.values() {
-        let word = &clueword.word;
+        let word = &crate::entries::grid_letters(&clueword.word);
         let row = clueword.row;
         let col = clueword.col;
         clue_positions.push(row * (height as usize) + col);
@@ -204,8 +204,8 @@ fn parse(data: &str) -> Result<puz_parse::Puzzle, ProviderError> {
             .get(&(clueword.row * (height as usize) + clueword.col))
             .unwrap();
         match clueword.direction {
-            Direction::Across => across_clues.insert(clue_no, clueword.clue),
-            Direction::Down => down_clues.insert(clue_no, clueword.clue),
+            Direction::Across => across_clues.insert(clue_no, clean_clue_text(&clueword.clue)),
+            Direction::Down => down_clues.insert(clue_no, clean_clue_text(&clueword.clue)),
         };
     }
 
@@ -1,6 +1,6 @@
+use crate::ProviderError;
 use crate::formats::crossword_compiler;
 use crate::util::http_client;
-use crate::ProviderError;
 use puz_parse::Puzzle;
 
 /// Simply Daily Puzzles variants
@@ -65,7 +65,7 @@ pub async fn download(variant: SimplyDailyVariant, date: &str) -> Result<Puzzle,
     );
 
     let client = http_client();
-    let res = client.get(&url).send().await?;
+    let res = client.get(&url).await?;
 
     if !res.status().is_success() {
         return Err(ProviderError::Other(format!(
@@ -82,11 +82,12 @@ pub async fn download(variant: SimplyDailyVariant, date: &str) -> Result<Puzzle,
 
 /// Download the latest Simply Daily puzzle.
 pub async fn download_latest(variant: SimplyDailyVariant) -> Result<Puzzle, ProviderError> {
-    use chrono::Local;
-
-    let today = Local::now().date_naive();
-    let date_str = today.format("%Y-%m-%d").to_string();
-    download(variant, &date_str).await
+    let provider = match variant {
+        SimplyDailyVariant::Regular => crate::PuzzleProvider::SimplyDaily,
+        SimplyDailyVariant::Cryptic => crate::PuzzleProvider::SimplyDailyCryptic,
+        SimplyDailyVariant::Quick => crate::PuzzleProvider::SimplyDailyQuick,
+    };
+    provider.download(None).await
 }
 
 #[cfg(test)]
@@ -1,5 +1,6 @@
-use crate::util::{http_client, url_decode};
 use crate::ProviderError;
+use crate::entries::uppercase_letter;
+use crate::util::{clean_clue_text, http_client, url_decode};
 use puz_parse::Puzzle;
 use serde::Deserialize;
 use std::collections::HashMap;
@@ -22,7 +23,7 @@ pub async fn download(date: &str) -> Result<Puzzle, ProviderError> {
     // Retry logic - sometimes the API is flaky
     let mut attempts = 3;
     let data: AMUniversalData = loop {
-        let res = client.get(&url).send().await?;
+        let res = client.get(&url).await?;
 
         if !res.status().is_success() {
             return Err(ProviderError::Other(format!(
@@ -52,11 +53,7 @@ pub async fn download(date: &str) -> Result<Puzzle, ProviderError> {
 
 /// Download the latest Universal crossword.
 pub async fn download_latest() -> Result<Puzzle, ProviderError> {
-    use chrono::Local;
-
-    let today = Local::now().date_naive();
-    let date_str = today.format("%Y-%m-%d").to_string();
-    download(&date_str).await
+    crate::PuzzleProvider::Universal.download(None).await
 }
 
 #[derive(Debug, Deserialize)]
@@ -87,7 +84,7 @@ fn parse(data: AMUniversalData) -> Result<Puzzle, ProviderError> {
     let solution_chars: Vec<char> = data
         .all_answer
         .chars()
-        .map(|c| if c == '-' { '.' } else { c })
+        .map(|c| if c == '-' { '.' } else { uppercase_letter(c) })
         .collect();
 
     // Build grids
@@ -117,7 +114,7 @@ fn parse(data: AMUniversalData) -> Result<Puzzle, ProviderError> {
     for line in across_lines {
         if let Some((num_str, clue)) = line.split_once('|') {
             if let Ok(num) = num_str.parse::<u16>() {
-                clues_list.push((num, true, url_decode(clue)));
+                clues_list.push((num, true, clean_clue_text(&url_decode(clue))));
             }
         }
     }
@@ -129,7 +126,7 @@ fn parse(data: AMUniversalData) -> Result<Puzzle, ProviderError> {
         }
         if let Some((num_str, clue)) = line.split_once('|') {
             if let Ok(num) = num_str.parse::<u16>() {
-                clues_list.push((num, false, url_decode(clue)));
+                clues_list.push((num, false, clean_clue_text(&url_decode(clue))));
             }
         }
     }
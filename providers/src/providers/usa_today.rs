@@ -1,5 +1,6 @@
-use crate::util::{http_client, url_decode};
 use crate::ProviderError;
+use crate::entries::uppercase_letter;
+use crate::util::{clean_clue_text, http_client, url_decode};
 use puz_parse::Puzzle;
 use quick_xml::de::from_str;
 use serde::Deserialize;
@@ -30,7 +31,7 @@ pub async fn download(date: &str) -> Result<Puzzle, ProviderError> {
     );
 
     let client = http_client();
-    let res = client.get(&url).send().await?;
+    let res = client.get(&url).await?;
 
     if !res.status().is_success() {
         return Err(ProviderError::Other(format!(
@@ -44,27 +45,9 @@ pub async fn download(date: &str) -> Result<Puzzle, ProviderError> {
     parse(&xml_content)
 }
 
-/// Download the latest USA Today crossword (tries today, then previous days).
+/// Download the latest USA Today crossword (see [`PuzzleProvider::download`](crate::PuzzleProvider::download)).
 pub async fn download_latest() -> Result<Puzzle, ProviderError> {
-    use chrono::Local;
-
-    let mut check_date = Local::now().date_naive();
-    let mut days_to_check = 3;
-
-    while days_to_check > 0 {
-        let date_str = check_date.format("%Y-%m-%d").to_string();
-        match download(&date_str).await {
-            Ok(puzzle) => return Ok(puzzle),
-            Err(_) => {
-                days_to_check -= 1;
-                check_date -= chrono::Duration::days(1);
-            }
-        }
-    }
-
-    Err(ProviderError::Other(
-        "Unable to find latest puzzle".to_string(),
-    ))
+    crate::PuzzleProvider::UsaToday.download(None).await
 }
 
 #[derive(Debug, Deserialize)]
@@ -106,9 +89,8 @@ struct Clue {
 }
 
 fn parse(xml_content: &str) -> Result<Puzzle, ProviderError> {
-    let crossword: Crossword = from_str(xml_content).map_err(|e| {
-        ProviderError::Other(format!("Failed to parse XML: {}", e))
-    })?;
+    let crossword: Crossword = from_str(xml_content)
+        .map_err(|e| ProviderError::Other(format!("Failed to parse XML: {}", e)))?;
 
     let width: u8 = crossword.width.v.parse()?;
     let height: u8 = crossword.height.v.parse()?;
@@ -123,7 +105,7 @@ fn parse(xml_content: &str) -> Result<Puzzle, ProviderError> {
     let solution_raw = &crossword.all_answer.v;
     let solution_chars: Vec<char> = solution_raw
         .chars()
-        .map(|c| if c == '-' { '.' } else { c })
+        .map(|c| if c == '-' { '.' } else { uppercase_letter(c) })
         .collect();
 
     // Build grids
@@ -150,18 +132,24 @@ fn parse(xml_content: &str) -> Result<Puzzle, ProviderError> {
 
     for clue in &crossword.across.clues {
         let clue_no: u16 = clue.cn.parse().unwrap_or(0);
-        let clue_text = url_decode(clue.c.as_deref().unwrap_or(""));
+        let clue_text = clean_clue_text(&url_decode(clue.c.as_deref().unwrap_or("")));
         across_clues.insert(clue_no, clue_text);
     }
 
     for clue in &crossword.down.clues {
         let clue_no: u16 = clue.cn.parse().unwrap_or(0);
-        let clue_text = url_decode(clue.c.as_deref().unwrap_or(""));
+        let clue_text = clean_clue_text(&url_decode(clue.c.as_deref().unwrap_or("")));
         down_clues.insert(clue_no, clue_text);
     }
 
     let title = url_decode(crossword.title.as_ref().map(|t| t.v.as_str()).unwrap_or(""));
-    let author = url_decode(crossword.author.as_ref().map(|a| a.v.as_str()).unwrap_or(""));
+    let author = url_decode(
+        crossword
+            .author
+            .as_ref()
+            .map(|a| a.v.as_str())
+            .unwrap_or(""),
+    );
     let copyright = url_decode(
         crossword
             .copyright
@@ -1,5 +1,6 @@
 use crate::ProviderError;
-use crate::util::http_client;
+use crate::entries::{UNKNOWN_LETTER, uppercase_letter};
+use crate::util::{clean_clue_text, http_client};
 use puz_parse::Puzzle;
 use serde::Deserialize;
 use std::collections::HashMap;
@@ -18,7 +19,7 @@ pub async fn download(date: &str) -> Result<Puzzle, ProviderError> {
     );
 
     let client = http_client();
-    let res = client.get(&url).send().await?;
+    let res = client.get(&url).await?;
 
     if !res.status().is_success() {
         return Err(ProviderError::Other(format!(
@@ -43,16 +44,7 @@ pub async fn download(date: &str) -> Result<Puzzle, ProviderError> {
 
 /// Download the latest (most recent Sunday) Washington Post crossword.
 pub async fn download_latest() -> Result<Puzzle, ProviderError> {
-    use chrono::{Datelike, Local};
-
-    let today = Local::now().date_naive();
-
-    // Find the most recent Sunday
-    let days_since_sunday = today.weekday().num_days_from_sunday();
-    let most_recent_sunday = today - chrono::Duration::days(days_since_sunday as i64);
-
-    let date_str = most_recent_sunday.format("%Y/%m/%d").to_string();
-    download(&date_str).await
+    crate::PuzzleProvider::WashingtonPost.download(None).await
 }
 
 #[derive(Debug, Deserialize)]
@@ -101,7 +93,7 @@ fn parse(data: WaPoData) -> Result<Puzzle, ProviderError> {
 
     for cell in &data.cells {
         if let Some(ans) = &cell.answer {
-            solution_chars.push(ans.chars().next().unwrap_or('-'));
+            solution_chars.push(ans.chars().next().map_or(UNKNOWN_LETTER, uppercase_letter));
             blank_chars.push('-');
         } else {
             solution_chars.push('.');
@@ -152,9 +144,9 @@ fn parse(data: WaPoData) -> Result<Puzzle, ProviderError> {
         let clue_no = *clue_number_map.get(&first_idx).unwrap_or(&1);
 
         if word.direction == "across" {
-            across_clues.insert(clue_no, word.clue.trim().to_string());
+            across_clues.insert(clue_no, clean_clue_text(&word.clue));
         } else {
-            down_clues.insert(clue_no, word.clue.trim().to_string());
+            down_clues.insert(clue_no, clean_clue_text(&word.clue));
         }
     }
 
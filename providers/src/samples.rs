@@ -0,0 +1,110 @@
+//! Sample puzzles bundled into the binary.
+//!
+//! A handful of small original puzzles, licensed like the rest of the project,
+//! so the app is fully usable offline on first run. They are stored as
+//! CrosswordCompiler XML and parsed with the same format parser as downloads.
+
+use crate::{Language, ProviderError, Source, formats::crossword_compiler};
+use puz_parse::Puzzle;
+
+/// Provider name recorded for sample puzzles in saves and stats.
+pub const SAMPLES_PROVIDER: &str = "Samples";
+
+/// A puzzle embedded in the binary.
+#[derive(Debug, Clone, Copy)]
+pub struct Sample {
+    /// Short identifier, used in place of a puzzle date (e.g., "warm-up").
+    pub id: &'static str,
+    /// Display title.
+    pub title: &'static str,
+    /// Language of the clues and answers.
+    pub language: Language,
+    xml: &'static str,
+}
+
+/// All bundled samples, smallest first.
+pub const SAMPLES: [Sample; 4] = [
+    Sample {
+        id: "warm-up",
+        title: "Warm-up (3x3)",
+        language: Language::English,
+        xml: include_str!("../samples/warm-up.xml"),
+    },
+    Sample {
+        id: "calentamiento",
+        title: "Calentamiento (3x3)",
+        language: Language::Spanish,
+        xml: include_str!("../samples/calentamiento.xml"),
+    },
+    Sample {
+        id: "lattice",
+        title: "Lattice (5x5)",
+        language: Language::English,
+        xml: include_str!("../samples/lattice.xml"),
+    },
+    Sample {
+        id: "hearth",
+        title: "Hearth (5x5)",
+        language: Language::English,
+        xml: include_str!("../samples/hearth.xml"),
+    },
+];
+
+impl Sample {
+    /// Parse the embedded puzzle.
+    pub fn puzzle(&self) -> Result<Puzzle, ProviderError> {
+        crossword_compiler::parse(self.xml)
+    }
+
+    /// Attribution for the sample.
+    pub fn source(&self) -> Source {
+        Source {
+            provider: SAMPLES_PROVIDER.to_string(),
+            url: "https://github.com/erhant/cruciverbal".to_string(),
+            fetched_at: String::new(),
+            license_note: "Bundled sample puzzle, MIT License".to_string(),
+            language: Some(self.language),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entries;
+
+    #[test]
+    fn test_samples_parse() {
+        for sample in SAMPLES {
+            let puzzle = sample.puzzle().unwrap();
+            let entries = entries::entries(&puzzle);
+            assert!(!entries.is_empty(), "{} has no entries", sample.id);
+            assert_eq!(
+                entries.len(),
+                puzzle.clues.across.len() + puzzle.clues.down.len(),
+                "{} has clues without entries",
+                sample.id
+            );
+            for entry in entries {
+                assert!(
+                    !entry.clue.is_empty(),
+                    "{} {}{} has no clue",
+                    sample.id,
+                    entry.number,
+                    entry.direction.name()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_spanish_sample() {
+        let sample = SAMPLES.iter().find(|s| s.id == "calentamiento").unwrap();
+        let answers: Vec<String> = entries::entries(&sample.puzzle().unwrap())
+            .into_iter()
+            .map(|entry| entry.answer)
+            .collect();
+        assert_eq!(answers, ["MAR", "OSO", "MÍO", "RÍO"]);
+        assert!(entries::letters_match('i', 'Í', true));
+    }
+}
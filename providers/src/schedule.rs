@@ -0,0 +1,184 @@
+//! Provider publication schedules and archive depth.
+//!
+//! A [`Schedule`] describes on which days a provider publishes a new puzzle, and
+//! an [`Archive`] how far back puzzles can be fetched, so the UI can tell which
+//! dates are worth requesting before hitting a 404. New puzzles come out at
+//! midnight in the publisher's time zone, so a [`DateZone`] decides which day
+//! counts as today.
+
+use chrono::{DateTime, Datelike, Days, NaiveDate, Utc, Weekday};
+use chrono_tz::Tz;
+
+/// Days of the week on which a provider publishes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Schedule {
+    /// Every day.
+    Daily,
+    /// Monday through Friday.
+    Weekdays,
+    /// Monday through Saturday.
+    MondayToSaturday,
+    /// Once a week, on the given day.
+    Weekly(Weekday),
+}
+
+impl Schedule {
+    /// Whether a puzzle is published on `date`.
+    pub fn publishes_on(&self, date: NaiveDate) -> bool {
+        let weekday = date.weekday();
+        match self {
+            Schedule::Daily => true,
+            Schedule::Weekdays => !matches!(weekday, Weekday::Sat | Weekday::Sun),
+            Schedule::MondayToSaturday => weekday != Weekday::Sun,
+            Schedule::Weekly(day) => weekday == *day,
+        }
+    }
+
+    /// The most recent publication date on or before `date`.
+    pub fn latest_on_or_before(&self, date: NaiveDate) -> NaiveDate {
+        // every schedule publishes at least once a week
+        (0..7)
+            .map(|n| date - Days::new(n))
+            .find(|date| self.publishes_on(*date))
+            .unwrap_or(date)
+    }
+
+    pub fn name(&self) -> String {
+        match self {
+            Schedule::Daily => "Daily".to_string(),
+            Schedule::Weekdays => "Weekdays".to_string(),
+            Schedule::MondayToSaturday => "Monday to Saturday".to_string(),
+            Schedule::Weekly(day) => format!("Weekly ({})", weekday_name(*day)),
+        }
+    }
+}
+
+/// How far back a provider's puzzles can be fetched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Archive {
+    /// Only the current puzzle can be fetched.
+    LatestOnly,
+    /// Past puzzles are only kept online for a limited time.
+    Recent,
+    /// Any published date can be requested.
+    Dated,
+}
+
+impl Archive {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Archive::LatestOnly => "Latest puzzle only",
+            Archive::Recent => "Recent puzzles only",
+            Archive::Dated => "Past dates on request",
+        }
+    }
+}
+
+/// Time zone whose calendar decides which day's puzzle is today.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DateZone {
+    /// Each publisher's own time zone.
+    #[default]
+    Publisher,
+    /// The system's local time zone.
+    Local,
+    /// A fixed time zone.
+    Named(Tz),
+}
+
+impl DateZone {
+    /// Parse "publisher", "local" or an IANA time zone name such as
+    /// "America/Chicago".
+    pub fn parse(value: &str) -> Option<DateZone> {
+        match value.trim() {
+            value if value.eq_ignore_ascii_case("publisher") => Some(DateZone::Publisher),
+            value if value.eq_ignore_ascii_case("local") => Some(DateZone::Local),
+            value => value.parse().ok().map(DateZone::Named),
+        }
+    }
+
+    /// The date at `now` for a publisher in `publisher` time zone.
+    pub fn date(&self, now: DateTime<Utc>, publisher: Tz) -> NaiveDate {
+        match self {
+            DateZone::Publisher => now.with_timezone(&publisher).date_naive(),
+            DateZone::Local => now.with_timezone(&chrono::Local).date_naive(),
+            DateZone::Named(tz) => now.with_timezone(tz).date_naive(),
+        }
+    }
+}
+
+fn weekday_name(day: Weekday) -> &'static str {
+    match day {
+        Weekday::Mon => "Monday",
+        Weekday::Tue => "Tuesday",
+        Weekday::Wed => "Wednesday",
+        Weekday::Thu => "Thursday",
+        Weekday::Fri => "Friday",
+        Weekday::Sat => "Saturday",
+        Weekday::Sun => "Sunday",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_publishes_on() {
+        // 2025-01-04 is a Saturday, 2025-01-05 a Sunday
+        let saturday = NaiveDate::from_ymd_opt(2025, 1, 4).unwrap();
+        let sunday = NaiveDate::from_ymd_opt(2025, 1, 5).unwrap();
+
+        assert!(Schedule::Daily.publishes_on(sunday));
+        assert!(!Schedule::Weekdays.publishes_on(saturday));
+        assert!(Schedule::MondayToSaturday.publishes_on(saturday));
+        assert!(!Schedule::MondayToSaturday.publishes_on(sunday));
+        assert!(Schedule::Weekly(Weekday::Sun).publishes_on(sunday));
+        assert!(!Schedule::Weekly(Weekday::Sun).publishes_on(saturday));
+    }
+
+    #[test]
+    fn test_date_zone() {
+        // 2025-01-08 02:00 UTC is still the 7th in New York, and the 8th in Sydney
+        let now = DateTime::parse_from_rfc3339("2025-01-08T02:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let date = |day| NaiveDate::from_ymd_opt(2025, 1, day).unwrap();
+
+        assert_eq!(
+            DateZone::Publisher.date(now, Tz::America__New_York),
+            date(7)
+        );
+        assert_eq!(
+            DateZone::Publisher.date(now, Tz::Australia__Sydney),
+            date(8)
+        );
+        let london = DateZone::parse("Europe/London").unwrap();
+        assert_eq!(london.date(now, Tz::America__New_York), date(8));
+
+        assert_eq!(DateZone::parse(" Local "), Some(DateZone::Local));
+        assert_eq!(DateZone::parse("publisher"), Some(DateZone::Publisher));
+        assert_eq!(DateZone::parse("Mars/Olympus_Mons"), None);
+    }
+
+    #[test]
+    fn test_latest_on_or_before() {
+        // 2025-01-08 is a Wednesday
+        let wednesday = NaiveDate::from_ymd_opt(2025, 1, 8).unwrap();
+        let date = |day| NaiveDate::from_ymd_opt(2025, 1, day).unwrap();
+
+        assert_eq!(Schedule::Daily.latest_on_or_before(wednesday), wednesday);
+        assert_eq!(
+            Schedule::Weekly(Weekday::Sun).latest_on_or_before(wednesday),
+            date(5)
+        );
+        assert_eq!(
+            Schedule::Weekdays.latest_on_or_before(date(5)),
+            NaiveDate::from_ymd_opt(2025, 1, 3).unwrap()
+        );
+        assert_eq!(
+            Schedule::MondayToSaturday.latest_on_or_before(date(5)),
+            date(4)
+        );
+    }
+}
@@ -0,0 +1,170 @@
+//! Puzzle source attribution.
+//!
+//! A [`Source`] records where a puzzle came from. It travels with the puzzle
+//! through saves and exports so redistributed files keep their attribution.
+
+use crate::{Language, PuzzleProvider};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Provider name recorded for puzzles opened from a local file.
+pub const FILE_PROVIDER: &str = "Local file";
+
+/// Start of the attribution line that [`Source::annotate`] adds to puzzle notes.
+const ATTRIBUTION_PREFIX: &str = "Source: ";
+
+/// Attribution and licensing metadata for a downloaded puzzle.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Source {
+    /// Provider display name (e.g., "Guardian Cryptic").
+    pub provider: String,
+    /// Publisher page the puzzle was fetched from.
+    pub url: String,
+    /// When the puzzle was fetched, in RFC 3339 format.
+    pub fetched_at: String,
+    /// Copyright / licensing note to retain when redistributing.
+    pub license_note: String,
+    /// Language of the puzzle, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<Language>,
+}
+
+impl Source {
+    /// Build a [`Source`] for a puzzle that was just fetched from `provider`.
+    ///
+    /// The license note prefers the copyright line embedded in the puzzle and
+    /// falls back to the provider's default note.
+    pub fn fetched(provider: PuzzleProvider, puzzle: &puz_parse::Puzzle) -> Self {
+        let copyright = puzzle.info.copyright.trim();
+        let license_note = if copyright.is_empty() {
+            provider.license_note().to_string()
+        } else {
+            format!("{}. {}", copyright, provider.license_note())
+        };
+
+        Self {
+            provider: provider.name().to_string(),
+            url: provider.url().to_string(),
+            fetched_at: chrono::Local::now().to_rfc3339(),
+            license_note,
+            language: Some(provider.language()),
+        }
+    }
+
+    /// Build a [`Source`] for a puzzle opened from a local file.
+    pub fn local(path: &Path, puzzle: &puz_parse::Puzzle) -> Self {
+        let copyright = puzzle.info.copyright.trim();
+        let license_note = if copyright.is_empty() {
+            "Opened from a local file".to_string()
+        } else {
+            copyright.to_string()
+        };
+
+        Self {
+            provider: FILE_PROVIDER.to_string(),
+            url: path.display().to_string(),
+            fetched_at: chrono::Local::now().to_rfc3339(),
+            license_note,
+            language: None,
+        }
+    }
+
+    /// One-line attribution for exported files: provider, URL and license note.
+    pub fn attribution(&self) -> String {
+        format!(
+            "{}{} ({}). {}",
+            ATTRIBUTION_PREFIX, self.provider, self.url, self.license_note
+        )
+    }
+
+    /// Append the [attribution](Self::attribution) to the puzzle's notes, which
+    /// every export format keeps, unless they already carry one (e.g. a file
+    /// exported earlier, which keeps its original source).
+    pub fn annotate(&self, puzzle: &mut puz_parse::Puzzle) {
+        let notes = &mut puzzle.info.notes;
+        if notes
+            .lines()
+            .any(|line| line.starts_with(ATTRIBUTION_PREFIX))
+        {
+            return;
+        }
+        if !notes.trim().is_empty() {
+            notes.push('\n');
+        }
+        notes.push_str(&self.attribution());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn puzzle_with_copyright(copyright: &str) -> puz_parse::Puzzle {
+        puz_parse::Puzzle {
+            info: puz_parse::PuzzleInfo {
+                title: String::new(),
+                height: 1,
+                width: 1,
+                author: String::new(),
+                copyright: copyright.to_string(),
+                notes: String::new(),
+                version: "1.4".to_string(),
+                is_scrambled: false,
+            },
+            grid: puz_parse::Grid {
+                blank: vec!["-".to_string()],
+                solution: vec!["A".to_string()],
+            },
+            clues: puz_parse::Clues {
+                across: Default::default(),
+                down: Default::default(),
+            },
+            extensions: puz_parse::Extensions {
+                rebus: None,
+                circles: None,
+                given: None,
+            },
+        }
+    }
+
+    #[test]
+    fn test_fetched_uses_puzzle_copyright() {
+        let puzzle = puzzle_with_copyright("© 2025 Someone");
+        let source = Source::fetched(PuzzleProvider::Universal, &puzzle);
+        assert_eq!(source.provider, "Universal");
+        assert!(source.license_note.starts_with("© 2025 Someone. "));
+        assert!(!source.url.is_empty());
+    }
+
+    #[test]
+    fn test_local_keeps_path_and_copyright() {
+        let puzzle = puzzle_with_copyright("© 2025 Someone");
+        let source = Source::local(Path::new("puzzles/mine.puz"), &puzzle);
+        assert_eq!(source.provider, FILE_PROVIDER);
+        assert_eq!(source.url, "puzzles/mine.puz");
+        assert_eq!(source.license_note, "© 2025 Someone");
+    }
+
+    #[test]
+    fn test_annotate_keeps_the_first_attribution() {
+        let mut puzzle = puzzle_with_copyright("© 2025 Someone");
+        puzzle.info.notes = "No solution provided".to_string();
+        let source = Source::fetched(PuzzleProvider::Universal, &puzzle);
+        source.annotate(&mut puzzle);
+        Source::local(Path::new("export.json"), &puzzle).annotate(&mut puzzle);
+        assert_eq!(
+            puzzle.info.notes,
+            format!("No solution provided\n{}", source.attribution())
+        );
+    }
+
+    #[test]
+    fn test_fetched_falls_back_to_provider_note() {
+        let puzzle = puzzle_with_copyright("  ");
+        let source = Source::fetched(PuzzleProvider::GuardianCryptic, &puzzle);
+        assert_eq!(
+            source.license_note,
+            PuzzleProvider::GuardianCryptic.license_note()
+        );
+    }
+}
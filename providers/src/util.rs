@@ -1,42 +1,463 @@
 //! Shared utilities for puzzle providers.
 
+use crate::DateZone;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use tokio::sync::Semaphore;
+
 /// User-Agent string for HTTP requests.
 pub const USER_AGENT: &str = concat!("cruciverbal/", env!("CARGO_PKG_VERSION"));
 
-/// Create a configured reqwest client with standard headers.
-pub fn http_client() -> reqwest::Client {
-    reqwest::Client::builder()
-        .user_agent(USER_AGENT)
-        .build()
-        .unwrap_or_default()
+/// Politeness settings shared by all provider requests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NetworkSettings {
+    /// Maximum number of requests in flight at once, across all providers.
+    pub max_concurrent_requests: usize,
+    /// Minimum delay between two requests to the same host, in milliseconds.
+    pub per_host_delay_ms: u64,
+    /// How many times a request is retried after a connection error, a timeout,
+    /// or a 429/5xx response.
+    pub retries: u32,
+    /// Save data on metered connections: stop reading pages as soon as the
+    /// needed part has arrived and reuse values fetched earlier in the session.
+    pub low_bandwidth: bool,
+}
+
+impl Default for NetworkSettings {
+    fn default() -> Self {
+        Self {
+            max_concurrent_requests: 2,
+            per_host_delay_ms: 500,
+            retries: 2,
+            low_bandwidth: false,
+        }
+    }
+}
+
+static NETWORK_SETTINGS: OnceLock<NetworkSettings> = OnceLock::new();
+static REQUEST_SLOTS: OnceLock<Semaphore> = OnceLock::new();
+static LAST_REQUEST: Mutex<Option<HashMap<String, Instant>>> = Mutex::new(None);
+static BYTES_RECEIVED: AtomicU64 = AtomicU64::new(0);
+static DISABLED_PROVIDERS: OnceLock<Vec<String>> = OnceLock::new();
+static DATE_ZONE: OnceLock<DateZone> = OnceLock::new();
+
+/// Shared HTTP client, built on first use (TLS setup is slow).
+static CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+
+/// Turn off providers for this process, by name or slug (e.g. because the
+/// site's terms of service don't allow downloads where the user lives).
+///
+/// Disabled providers refuse to download. Must be called before the first
+/// download; later calls are ignored.
+pub fn set_disabled_providers(names: Vec<String>) {
+    let _ = DISABLED_PROVIDERS.set(names);
+}
+
+/// Choose the time zone that decides which day's puzzle is today, instead of
+/// each publisher's own.
+///
+/// Must be called before the first download; later calls are ignored.
+pub fn set_date_zone(zone: DateZone) {
+    let _ = DATE_ZONE.set(zone);
+}
+
+/// The time zone chosen with [`set_date_zone`].
+pub(crate) fn date_zone() -> DateZone {
+    DATE_ZONE.get().copied().unwrap_or_default()
+}
+
+/// Whether a provider was turned off with [`set_disabled_providers`].
+pub(crate) fn is_provider_disabled(name: &str, slug: &str) -> bool {
+    DISABLED_PROVIDERS
+        .get()
+        .is_some_and(|names| names.iter().any(|n| n == name || n == slug))
+}
+
+/// Total size of all responses received so far, as announced by `Content-Length`.
+///
+/// Compare the value before and after a download to get its size. Responses
+/// without a length (e.g. chunked ones) are not counted.
+pub fn bytes_received() -> u64 {
+    BYTES_RECEIVED.load(Ordering::Relaxed)
+}
+
+/// Set the network settings for this process.
+///
+/// Must be called before the first request; later calls are ignored.
+pub fn set_network_settings(settings: NetworkSettings) {
+    let _ = NETWORK_SETTINGS.set(settings);
+}
+
+fn network_settings() -> NetworkSettings {
+    *NETWORK_SETTINGS.get_or_init(NetworkSettings::default)
+}
+
+/// Whether low-bandwidth mode is enabled.
+pub fn low_bandwidth() -> bool {
+    network_settings().low_bandwidth
+}
+
+/// Headers of a response that tell whether it changed since, for conditional
+/// requests.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Validators {
+    /// URL the response came from.
+    pub url: String,
+    /// `ETag` header, sent back as `If-None-Match`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub etag: Option<String>,
+    /// `Last-Modified` header, sent back as `If-Modified-Since`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_modified: Option<String>,
+}
+
+impl Validators {
+    /// Validators of a response from `url`, if it has any.
+    fn of(url: &str, res: &reqwest::Response) -> Option<Validators> {
+        let header = |name| {
+            res.headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string)
+        };
+        let validators = Validators {
+            url: url.to_string(),
+            etag: header(reqwest::header::ETAG),
+            last_modified: header(reqwest::header::LAST_MODIFIED),
+        };
+        (validators.etag.is_some() || validators.last_modified.is_some()).then_some(validators)
+    }
+}
+
+/// State of a download run with [`with_validators`].
+#[derive(Debug, Default)]
+struct ConditionalFetch {
+    /// Validators of an earlier download, sent with the request to their URL.
+    previous: Option<Validators>,
+    /// Validators of the last successful response.
+    latest: Option<Validators>,
+    /// Whether the server answered 304 Not Modified.
+    not_modified: bool,
+}
+
+tokio::task_local! {
+    static CONDITIONAL: RefCell<ConditionalFetch>;
+}
+
+/// Run a download, making the request to the URL of `previous` conditional.
+///
+/// Returns the download's result, the validators of its last response (the one
+/// holding the puzzle), and whether the server answered 304 Not Modified, in
+/// which case the result is an error.
+pub(crate) async fn with_validators<T>(
+    previous: Option<Validators>,
+    fetch: impl Future<Output = T>,
+) -> (T, Option<Validators>, bool) {
+    let state = RefCell::new(ConditionalFetch {
+        previous,
+        ..Default::default()
+    });
+    CONDITIONAL
+        .scope(state, async {
+            let result = fetch.await;
+            let state = CONDITIONAL.with(|state| std::mem::take(&mut *state.borrow_mut()));
+            (result, state.latest, state.not_modified)
+        })
+        .await
+}
+
+/// Whether a request of the current [`with_validators`] download was answered
+/// with 304 Not Modified.
+pub(crate) fn not_modified() -> bool {
+    CONDITIONAL
+        .try_with(|state| state.borrow().not_modified)
+        .unwrap_or(false)
+}
+
+/// Reserve the next request slot for `host`, returning how long to wait for it.
+fn reserve_host_slot(host: &str, delay: Duration) -> Duration {
+    let mut last_request = LAST_REQUEST.lock().unwrap_or_else(|e| e.into_inner());
+    let last_request = last_request.get_or_insert_with(HashMap::new);
+
+    let now = Instant::now();
+    let slot = match last_request.get(host) {
+        Some(last) => (*last + delay).max(now),
+        None => now,
+    };
+    last_request.insert(host.to_string(), slot);
+    slot - now
+}
+
+/// HTTP client that enforces the [`NetworkSettings`]: a global limit on
+/// concurrent requests, a delay between requests to the same host, and retries.
+#[derive(Debug, Clone)]
+pub struct HttpClient {
+    inner: reqwest::Client,
+}
+
+impl HttpClient {
+    /// Send a GET request to `url`.
+    ///
+    /// Responses with an error status are returned as-is once the retries are used up.
+    pub async fn get(&self, url: &str) -> Result<reqwest::Response, reqwest::Error> {
+        self.get_with_headers(url, &[]).await
+    }
+
+    /// Send a GET request to `url` with extra headers.
+    pub async fn get_with_headers(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+    ) -> Result<reqwest::Response, reqwest::Error> {
+        let settings = network_settings();
+        let slots =
+            REQUEST_SLOTS.get_or_init(|| Semaphore::new(settings.max_concurrent_requests.max(1)));
+        let _permit = slots.acquire().await.ok();
+
+        let host = reqwest::Url::parse(url)
+            .ok()
+            .and_then(|url| url.host_str().map(str::to_string))
+            .unwrap_or_default();
+        let delay = Duration::from_millis(settings.per_host_delay_ms);
+
+        let mut attempt = 0;
+        loop {
+            tokio::time::sleep(reserve_host_slot(&host, delay)).await;
+
+            let mut request = self.inner.get(url);
+            for (name, value) in headers {
+                request = request.header(*name, *value);
+            }
+            let previous = CONDITIONAL
+                .try_with(|state| state.borrow().previous.clone())
+                .ok()
+                .flatten()
+                .filter(|previous| previous.url == url);
+            if let Some(previous) = previous {
+                if let Some(etag) = previous.etag {
+                    request = request.header(reqwest::header::IF_NONE_MATCH, etag);
+                }
+                if let Some(last_modified) = previous.last_modified {
+                    request = request.header(reqwest::header::IF_MODIFIED_SINCE, last_modified);
+                }
+            }
+            let result = request.send().await;
+            if let Ok(res) = &result {
+                BYTES_RECEIVED.fetch_add(res.content_length().unwrap_or(0), Ordering::Relaxed);
+                let _ = CONDITIONAL.try_with(|state| {
+                    let mut state = state.borrow_mut();
+                    if res.status() == reqwest::StatusCode::NOT_MODIFIED {
+                        state.not_modified = true;
+                    } else if res.status().is_success() {
+                        state.latest = Validators::of(url, res);
+                    }
+                });
+            }
+            let retryable = match &result {
+                Ok(res) => res.status().as_u16() == 429 || res.status().is_server_error(),
+                Err(e) => e.is_connect() || e.is_timeout(),
+            };
+            if !retryable || attempt >= settings.retries {
+                return result;
+            }
+
+            // back off before retrying: 1s, 2s, 4s, ...
+            tokio::time::sleep(Duration::from_secs(1 << attempt.min(5))).await;
+            attempt += 1;
+        }
+    }
+}
+
+/// Get a configured client with standard headers.
+///
+/// All clients share one connection pool, created by the first call.
+pub fn http_client() -> HttpClient {
+    let inner = CLIENT.get_or_init(|| {
+        reqwest::Client::builder()
+            .user_agent(USER_AGENT)
+            .build()
+            .unwrap_or_default()
+    });
+    HttpClient {
+        inner: inner.clone(),
+    }
 }
 
 /// Decode URL-encoded strings (percent encoding).
 ///
-/// Handles common percent-encoded characters and `+` as space.
+/// Handles common percent-encoded characters and `+` as space. Encoded bytes are
+/// read as UTF-8 (`%E2%80%99` is `’`), or as Latin-1 if they aren't valid UTF-8.
 pub fn url_decode(s: &str) -> String {
-    let mut result = String::with_capacity(s.len());
+    let mut result = Vec::with_capacity(s.len());
     let mut chars = s.chars().peekable();
 
     while let Some(c) = chars.next() {
         if c == '%' {
             let hex: String = chars.by_ref().take(2).collect();
             if let Ok(byte) = u8::from_str_radix(&hex, 16) {
-                result.push(byte as char);
+                result.push(byte);
             } else {
-                result.push('%');
-                result.push_str(&hex);
+                result.push(b'%');
+                result.extend_from_slice(hex.as_bytes());
             }
         } else if c == '+' {
-            result.push(' ');
+            result.push(b' ');
         } else {
-            result.push(c);
+            result.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+        }
+    }
+
+    // fall back to Latin-1 for encoders that don't use UTF-8
+    String::from_utf8(result)
+        .unwrap_or_else(|e| e.into_bytes().into_iter().map(char::from).collect())
+}
+
+/// Named HTML entities that show up in clue text, with their characters.
+const ENTITIES: [(&str, &str); 22] = [
+    ("amp", "&"),
+    ("lt", "<"),
+    ("gt", ">"),
+    ("quot", "\""),
+    ("apos", "'"),
+    ("nbsp", " "),
+    ("ndash", "–"),
+    ("mdash", "—"),
+    ("hellip", "..."),
+    ("lsquo", "'"),
+    ("rsquo", "'"),
+    ("ldquo", "\""),
+    ("rdquo", "\""),
+    ("eacute", "é"),
+    ("egrave", "è"),
+    ("agrave", "à"),
+    ("ccedil", "ç"),
+    ("ntilde", "ñ"),
+    ("ouml", "ö"),
+    ("uuml", "ü"),
+    ("deg", "°"),
+    ("pound", "£"),
+];
+
+/// UTF-8 punctuation read as Windows-1252 somewhere upstream ("â€™" for "'").
+const MOJIBAKE: [(&str, &str); 6] = [
+    ("â€™", "'"),
+    ("â€˜", "'"),
+    ("â€œ", "\""),
+    ("â€\u{9d}", "\""),
+    ("â€¦", "..."),
+    ("â€“", "–"),
+];
+
+/// Decode the HTML entities of `text`, named (`&amp;`) and numeric (`&#39;`,
+/// `&#x2019;`). Unknown entities are kept as they are.
+fn decode_entities(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let decoded = rest[1..]
+            .find(';')
+            .filter(|&end| end <= 10)
+            .and_then(|end| {
+                let name = &rest[1..end + 1];
+                let decoded = match name.strip_prefix('#') {
+                    Some(number) => {
+                        let code = match number.strip_prefix(['x', 'X']) {
+                            Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                            None => number.parse().ok(),
+                        };
+                        code.and_then(char::from_u32).map(String::from)
+                    }
+                    None => ENTITIES
+                        .iter()
+                        .find(|(entity, _)| *entity == name)
+                        .map(|(_, c)| c.to_string()),
+                };
+                decoded.map(|decoded| (decoded, end + 2))
+            });
+        match decoded {
+            Some((decoded, len)) => {
+                result.push_str(&decoded);
+                rest = &rest[len..];
+            }
+            None => {
+                result.push('&');
+                rest = &rest[1..];
+            }
         }
     }
+    result.push_str(rest);
+    result
+}
 
+/// Replace the HTML tags of `text`: italics become `_..._`, line breaks spaces,
+/// and other tags are dropped. A `<` that doesn't start a tag ("a < b") is kept.
+fn strip_tags(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('<') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let tag = rest[1..].find('>').map(|end| &rest[1..end + 1]);
+        let name = tag.map(|tag| {
+            tag.trim_start_matches('/')
+                .split(|c: char| c.is_whitespace() || c == '/')
+                .next()
+                .unwrap_or_default()
+                .to_ascii_lowercase()
+        });
+        match (tag, name) {
+            (Some(tag), Some(name)) if name.starts_with(|c: char| c.is_ascii_alphabetic()) => {
+                match name.as_str() {
+                    "i" | "em" => result.push('_'),
+                    "br" | "p" => result.push(' '),
+                    _ => {}
+                }
+                rest = &rest[tag.len() + 2..];
+            }
+            _ => {
+                result.push('<');
+                rest = &rest[1..];
+            }
+        }
+    }
+    result.push_str(rest);
     result
 }
 
+/// Clean up clue text from a provider for the terminal.
+///
+/// Decodes HTML entities, keeps italics as `_..._` and drops other tags,
+/// straightens smart quotes, fixes mis-decoded punctuation and collapses runs
+/// of whitespace.
+pub fn clean_clue_text(text: &str) -> String {
+    let mut text = text.to_string();
+    for (garbled, fixed) in MOJIBAKE {
+        text = text.replace(garbled, fixed);
+    }
+    // entities first, as some providers escape their markup ("&lt;i&gt;")
+    let text = strip_tags(&decode_entities(&text));
+    let text: String = text
+        .chars()
+        .map(|c| match c {
+            '‘' | '’' | '‚' | '‛' | '′' => '\'',
+            '“' | '”' | '„' | '″' => '"',
+            '\u{a0}' => ' ',
+            c => c,
+        })
+        .collect::<String>()
+        .replace('…', "...");
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -46,6 +467,18 @@ mod tests {
         assert_eq!(url_decode("hello%20world"), "hello world");
         assert_eq!(url_decode("hello+world"), "hello world");
         assert_eq!(url_decode("100%25"), "100%");
+        assert_eq!(url_decode("it%E2%80%99s"), "it’s");
+        assert_eq!(url_decode("caf%E9"), "café");
+    }
+
+    #[test]
+    fn test_reserve_host_slot_spaces_requests() {
+        let delay = Duration::from_millis(500);
+        assert_eq!(reserve_host_slot("a.test", delay), Duration::ZERO);
+        let wait = reserve_host_slot("a.test", delay);
+        assert!(wait > Duration::from_millis(400) && wait <= delay);
+        // other hosts are not delayed
+        assert_eq!(reserve_host_slot("b.test", delay), Duration::ZERO);
     }
 
     #[test]
@@ -53,4 +486,69 @@ mod tests {
         assert_eq!(url_decode("hello"), "hello");
         assert_eq!(url_decode(""), "");
     }
+
+    #[test]
+    fn test_clean_clue_text() {
+        // Guardian
+        assert_eq!(
+            clean_clue_text("Composer&#39;s <i>Carmen</i> &ndash; it&rsquo;s about love (5)"),
+            "Composer's _Carmen_ – it's about love (5)"
+        );
+        // Washington Post, with escaped markup and a line break
+        assert_eq!(
+            clean_clue_text("&lt;em&gt;Hamlet&lt;/em&gt; setting<br/>  (7)"),
+            "_Hamlet_ setting (7)"
+        );
+        // USA Today, after URL decoding
+        assert_eq!(
+            clean_clue_text("\u{201c}Star Wars\u{201d} droid, \u{2018}R2-__\u{2019}\u{2026}"),
+            "\"Star Wars\" droid, 'R2-__'..."
+        );
+        assert_eq!(
+            clean_clue_text("Donâ€™t <b>stop</b> believin&#x2019;"),
+            "Don't stop believin'"
+        );
+        // not markup
+        assert_eq!(clean_clue_text("1 < 2 & 3 > 2"), "1 < 2 & 3 > 2");
+        assert_eq!(
+            clean_clue_text("R&amp;B &unknown; caf&eacute;"),
+            "R&B &unknown; café"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_conditional_request() {
+        use std::io::{Read, Write};
+
+        // answers 304 when the ETag is sent back, and 200 with the ETag otherwise
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/puzzle", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            for stream in listener.incoming().take(2) {
+                let mut stream = stream.unwrap();
+                let mut request = [0; 1024];
+                let len = stream.read(&mut request).unwrap();
+                let request = String::from_utf8_lossy(&request[..len]).to_lowercase();
+                let response = if request.contains("if-none-match: \"v1\"") {
+                    "HTTP/1.1 304 Not Modified\r\nConnection: close\r\n\r\n"
+                } else {
+                    "HTTP/1.1 200 OK\r\nETag: \"v1\"\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok"
+                };
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+        let fetch = || async {
+            let res = http_client().get(&url).await.unwrap();
+            res.status().as_u16()
+        };
+
+        let (status, validators, not_modified) = with_validators(None, fetch()).await;
+        assert_eq!((status, not_modified), (200, false));
+        let validators = validators.unwrap();
+        assert_eq!(validators.etag.as_deref(), Some("\"v1\""));
+        assert!(!super::not_modified());
+
+        let (status, _, not_modified) = with_validators(Some(validators), fetch()).await;
+        assert_eq!((status, not_modified), (304, true));
+    }
 }
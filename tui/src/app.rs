@@ -1,9 +1,14 @@
 use crate::{
     game::{GameState, GameView},
     menu::MenuState,
-    preferences,
+    preferences::{self, Preferences},
+    stats::{self, StreakReminder},
+    terminal::Capabilities,
     theme::Theme,
-    views::theme_select::ThemeSelectState,
+    views::{
+        providers::ProvidersState, search::SearchState, settings::SettingsState,
+        theme_select::ThemeSelectState,
+    },
 };
 use color_eyre::eyre::Result;
 use crossterm::event::EventStream;
@@ -15,15 +20,39 @@ pub enum AppView {
     Menu,
     Help,
     ThemeSelect,
+    Settings,
+    Search,
+    Providers,
     Game(GameView),
 }
 
+impl AppView {
+    /// Whether the view only changes in response to input.
+    ///
+    /// Static views are redrawn only when [`AppState::dirty`] is set, while game
+    /// views have timers and animations and are redrawn every frame.
+    pub fn is_static(&self) -> bool {
+        !matches!(self, AppView::Game(_))
+    }
+}
+
 #[derive(Debug)]
 pub struct AppState {
     pub menu: MenuState,
     pub game: GameState,
     pub theme: &'static Theme,
+    /// What the terminal can draw, from detection or the preferences.
+    pub terminal: Capabilities,
     pub theme_select: ThemeSelectState,
+    pub settings: SettingsState,
+    pub search: SearchState,
+    pub providers: ProvidersState,
+    /// User preferences, loaded at startup and saved on change.
+    pub prefs: Preferences,
+    /// Streak reminder shown as a menu banner, if today's daily puzzle is unsolved.
+    pub reminder: Option<StreakReminder>,
+    /// Something changed since the last frame, so static views must be redrawn.
+    pub dirty: bool,
 }
 
 impl Default for AppState {
@@ -32,7 +61,14 @@ impl Default for AppState {
             menu: MenuState::default(),
             game: GameState::default(),
             theme: &crate::theme::DEFAULT,
+            terminal: Capabilities::default(),
             theme_select: ThemeSelectState::default(),
+            settings: SettingsState::default(),
+            search: SearchState::default(),
+            providers: ProvidersState::default(),
+            prefs: Preferences::default(),
+            reminder: None,
+            dirty: true,
         }
     }
 }
@@ -43,35 +79,34 @@ const FPS_RATE: Duration = Duration::from_millis(1000 / 35);
 pub struct App {
     /// Active application view.
     pub view: AppView,
-    /// Previous view to return to (e.g., when closing help).
-    pub previous_view: Option<AppView>,
     /// Application state.
     ///
     /// This is shared among all views.
     pub state: AppState,
     /// Is the application running?
     pub is_running: bool,
-    /// Event stream.
-    pub event_stream: EventStream,
+    /// Event stream, opened when the main loop first reads events.
+    pub event_stream: Option<EventStream>,
 }
 
 impl App {
     /// Construct a new instance of [`App`].
     pub fn new() -> Self {
-        // Clean up old auto-saves on startup
-        let _ = crate::save::cleanup_old_auto_saves();
-
-        // Load theme from preferences
+        // Load theme from preferences, which may name a user theme
         let prefs = preferences::load_preferences();
-        let theme = Theme::by_id(&prefs.theme_id);
+        crate::theme::load_user_themes();
+        let terminal = crate::terminal::capabilities(prefs.terminal_mode);
+        let theme = Theme::for_terminal(&prefs.theme_id, terminal.rgb_colors);
 
         Self {
             is_running: false,
-            event_stream: EventStream::new(),
+            event_stream: None,
             view: AppView::Menu,
-            previous_view: None,
             state: AppState {
                 theme,
+                terminal,
+                prefs,
+                reminder: stats::check_reminder(),
                 ..AppState::default()
             },
         }
@@ -80,25 +115,34 @@ impl App {
     /// Set the active view.
     pub fn set_view(&mut self, view: AppView) {
         self.view = view;
+        self.state.dirty = true;
     }
 
     /// Run the application's main loop.
     pub async fn run(mut self, mut terminal: ratatui::DefaultTerminal) -> Result<()> {
         self.is_running = true;
 
+        // Clean up old auto-saves in the background, so the menu shows right away
+        tokio::task::spawn_blocking(crate::save::cleanup_old_auto_saves);
+
         // create a ticker for animation updates
         let mut interval = tokio::time::interval(FPS_RATE);
 
         while self.is_running {
-            // draw first (to disguise async stuff in ticks)
-            terminal.draw(|frame| self.draw(frame))?;
+            // draw first (to disguise async stuff in ticks), skipping static
+            // views that haven't changed since the last frame
+            if self.state.dirty || !self.view.is_static() {
+                terminal.draw(|frame| self.draw(frame))?;
+                self.state.dirty = false;
+            }
 
-            // handle loading state - download puzzle
+            // handle loading state - start or collect the puzzle download
             if self.view == AppView::Game(GameView::Loading) {
-                self.download_puzzle().await;
-                continue;
+                self.poll_download().await;
             }
 
+            self.tick_game();
+
             // handle events with timeout to allow animation updates
             tokio::select! {
                 _ = interval.tick() => {
@@ -107,6 +151,7 @@ impl App {
                 }
                 result = self.handle_crossterm_events() => {
                     result?;
+                    self.update_crash_save();
                 }
             }
         }
@@ -114,132 +159,53 @@ impl App {
         Ok(())
     }
 
-    /// Download puzzle based on current selection state.
-    async fn download_puzzle(&mut self) {
-        use crate::game::GameView;
-        use cruciverbal_providers::PuzzleProvider;
-        use cruciverbal_providers::providers::*;
-
-        let date = self.state.game.selection.date.clone();
-        let use_latest = self.state.game.selection.use_latest;
-        let provider = PuzzleProvider::ALL
-            .get(self.state.game.selection.provider_idx)
-            .copied()
-            .unwrap_or_default();
+    /// Start playing a freshly downloaded (or cached) puzzle.
+    pub fn start_downloaded_puzzle(
+        &mut self,
+        mut puzzle: puz_parse::Puzzle,
+        source: Option<cruciverbal_providers::Source>,
+        puzzle_date: String,
+    ) {
+        cruciverbal_providers::entries::normalize_solution(&mut puzzle);
 
-        let result = match provider {
-            PuzzleProvider::LovattsCryptic => {
-                if use_latest {
-                    lovatts_cryptic::download(&chrono::Local::now().format("%Y-%m-%d").to_string())
-                        .await
-                } else {
-                    lovatts_cryptic::download(&date).await
-                }
-            }
-            // Guardian variants
-            PuzzleProvider::GuardianCryptic => {
-                if use_latest {
-                    guardian::download_latest(guardian::GuardianVariant::Cryptic).await
-                } else {
-                    // Guardian doesn't support date-based download, use latest
-                    guardian::download_latest(guardian::GuardianVariant::Cryptic).await
-                }
-            }
-            PuzzleProvider::GuardianEveryman => {
-                guardian::download_latest(guardian::GuardianVariant::Everyman).await
-            }
-            PuzzleProvider::GuardianSpeedy => {
-                guardian::download_latest(guardian::GuardianVariant::Speedy).await
-            }
-            PuzzleProvider::GuardianQuick => {
-                guardian::download_latest(guardian::GuardianVariant::Quick).await
-            }
-            PuzzleProvider::GuardianPrize => {
-                guardian::download_latest(guardian::GuardianVariant::Prize).await
-            }
-            PuzzleProvider::GuardianWeekend => {
-                guardian::download_latest(guardian::GuardianVariant::Weekend).await
-            }
-            PuzzleProvider::GuardianQuiptic => {
-                guardian::download_latest(guardian::GuardianVariant::Quiptic).await
-            }
-            // Washington Post
-            PuzzleProvider::WashingtonPost => {
-                if use_latest {
-                    wapo::download_latest().await
-                } else {
-                    // WaPo expects date in YYYY/MM/DD format
-                    let wapo_date = date.replace('-', "/");
-                    wapo::download(&wapo_date).await
-                }
-            }
-            // USA Today
-            PuzzleProvider::UsaToday => {
-                if use_latest {
-                    usa_today::download_latest().await
-                } else {
-                    usa_today::download(&date).await
-                }
-            }
-            // Simply Daily variants
-            PuzzleProvider::SimplyDaily => {
-                if use_latest {
-                    simply_daily::download_latest(simply_daily::SimplyDailyVariant::Regular).await
-                } else {
-                    simply_daily::download(simply_daily::SimplyDailyVariant::Regular, &date).await
-                }
-            }
-            PuzzleProvider::SimplyDailyCryptic => {
-                if use_latest {
-                    simply_daily::download_latest(simply_daily::SimplyDailyVariant::Cryptic).await
-                } else {
-                    simply_daily::download(simply_daily::SimplyDailyVariant::Cryptic, &date).await
-                }
-            }
-            PuzzleProvider::SimplyDailyQuick => {
-                if use_latest {
-                    simply_daily::download_latest(simply_daily::SimplyDailyVariant::Quick).await
-                } else {
-                    simply_daily::download(simply_daily::SimplyDailyVariant::Quick, &date).await
-                }
-            }
-            // Universal
-            PuzzleProvider::Universal => {
-                if use_latest {
-                    universal::download_latest().await
-                } else {
-                    universal::download(&date).await
-                }
-            }
-            // Daily Pop
-            PuzzleProvider::DailyPop => {
-                if use_latest {
-                    daily_pop::download_latest().await
-                } else {
-                    daily_pop::download(&date).await
-                }
-            }
-        };
+        // flag broken provider data before the user gets stuck on it
+        let warnings = cruciverbal_providers::entries::check_enumerations(&puzzle);
+        if !warnings.is_empty() {
+            self.state.game.notification = Some((
+                format!(
+                    "⚠ {} clue(s) don't match the grid, see CTRL+P",
+                    warnings.len()
+                ),
+                std::time::Instant::now() + std::time::Duration::from_secs(5),
+            ));
+        }
 
-        match result {
-            Ok(puzzle) => {
-                self.state.game.puzzle = Some(puzzle);
-                self.state.game.puzzle_date = if use_latest {
-                    // Use today's date for "latest" puzzles
-                    Some(chrono::Local::now().format("%Y-%m-%d").to_string())
-                } else {
-                    Some(date)
-                };
-                self.state.game.provider_idx = Some(self.state.game.selection.provider_idx);
-                self.state.game.grid = None; // Will be built on first draw
-                self.state.game.start_time = None; // Will be set on first draw
-                self.view = AppView::Game(GameView::Playing);
-            }
-            Err(e) => {
-                self.state.game.selection.error = Some(format!("Download failed: {}", e));
-                self.view = AppView::Game(GameView::Selecting);
-            }
+        if let Some(reason) = crate::views::game::unchecked_reason(&puzzle) {
+            self.state.game.notification = Some((
+                format!("{}: checking and reveals are off", reason),
+                std::time::Instant::now() + std::time::Duration::from_secs(5),
+            ));
         }
+
+        self.state.game.source = source;
+        self.state.game.puzzle = Some(puzzle);
+        self.state.game.puzzle_date = Some(puzzle_date);
+        self.state.game.provider_idx = Some(self.state.game.selection.provider_idx);
+        self.state.game.hot_seat = self.state.game.selection.mode.hot_seat();
+        self.state.game.grid = None; // Will be built on first draw
+        self.state.game.start_time = None; // Will be set on first draw
+        self.state.game.replay = Vec::new();
+        self.view = AppView::Game(GameView::Playing);
+    }
+
+    /// Open a puzzle loaded from a local file, skipping the menu.
+    pub fn open_puzzle_file(&mut self, path: &std::path::Path, puzzle: puz_parse::Puzzle) {
+        let source = cruciverbal_providers::Source::local(path, &puzzle);
+        let name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_else(|| "Unknown".to_string());
+        self.start_downloaded_puzzle(puzzle, Some(source), name);
     }
 
     /// Renders the user interface.
@@ -248,6 +214,9 @@ impl App {
             AppView::Menu => self.draw_menu(frame),
             AppView::Help => self.draw_help(frame),
             AppView::ThemeSelect => self.draw_theme_select(frame),
+            AppView::Settings => self.draw_settings(frame),
+            AppView::Search => self.draw_search(frame),
+            AppView::Providers => self.draw_providers(frame),
             AppView::Game(view) => self.draw_game(view, frame),
         }
     }
@@ -257,7 +226,10 @@ impl App {
         use crossterm::event::{Event, KeyEventKind, KeyModifiers};
         use futures::{FutureExt, StreamExt};
 
-        let event = self.event_stream.next().fuse().await;
+        let event_stream = self.event_stream.get_or_insert_with(EventStream::new);
+        let event = event_stream.next().fuse().await;
+        // any event (including a resize) may change what is on screen
+        self.state.dirty = true;
         match event {
             Some(Ok(evt)) => match evt {
                 Event::Key(key) if key.kind == KeyEventKind::Press => {
@@ -279,11 +251,21 @@ impl App {
                         AppView::Menu => self.handle_menu_input(key),
                         AppView::Help => self.handle_help_input(key),
                         AppView::ThemeSelect => self.handle_theme_select_input(key),
+                        AppView::Settings => self.handle_settings_input(key),
+                        AppView::Search => self.handle_search_input(key),
+                        AppView::Providers => self.handle_providers_input(key),
                         AppView::Game(view) => self.handle_game_input(view, key),
                     }
                 }
-                Event::Mouse(_) => {} // no mouse events
-                Event::Resize(_, _) => {}
+                Event::Mouse(mouse) => {
+                    if let AppView::Game(view) = self.view.clone() {
+                        self.handle_game_mouse(view, mouse);
+                    }
+                }
+                // a resize often comes before the terminal window is closed
+                Event::Resize(_, _) if self.state.game.edits_since_save > 0 => {
+                    self.auto_save_current_game();
+                }
                 _ => {}
             },
             _ => {}
@@ -291,8 +273,34 @@ impl App {
         Ok(())
     }
 
+    /// Whether a game is being played with changes that haven't been saved.
+    fn has_unsaved_game(&self) -> bool {
+        matches!(&self.view, AppView::Game(view) if view.is_in_game())
+            && self.state.game.edits_since_save > 0
+    }
+
+    /// Keep the game the panic hook saves up to date with the game in progress.
+    fn update_crash_save(&self) {
+        let save = if self.has_unsaved_game() {
+            self.game_save(true)
+        } else {
+            None
+        };
+        crate::save::set_crash_save(save);
+    }
+
     /// Set running to false to quit the application.
     pub fn quit(&mut self) {
         self.is_running = false;
     }
 }
+
+impl Drop for App {
+    /// Auto-save the game in progress when quitting with unsaved changes.
+    fn drop(&mut self) {
+        if self.has_unsaved_game() {
+            self.auto_save_current_game();
+        }
+        crate::save::set_crash_save(None);
+    }
+}
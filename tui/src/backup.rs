@@ -0,0 +1,235 @@
+//! Backup and restore of all application state.
+//!
+//! `cruciverbal backup export <file>` bundles preferences, saves, auto-saves, stats
+//! and the fetch log into a single JSON archive; `backup import <file> [PARTS]`
+//! restores all of it, or only the comma-separated parts given (e.g. `saves,stats`).
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Current backup archive version.
+const BACKUP_VERSION: u8 = 1;
+
+/// Error type for backup operations.
+#[derive(Error, Debug)]
+pub enum BackupError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("JSON error: {0}")]
+    Serde(#[from] serde_json::Error),
+    #[error("Could not determine home directory")]
+    NoHomeDir,
+    #[error(
+        "Unknown backup part: {0} (expected one of preferences, saves, autosaves, stats, fetches)"
+    )]
+    UnknownPart(String),
+    #[error("Unsupported backup version: {0}")]
+    UnsupportedVersion(u8),
+}
+
+/// A restorable part of the application state.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum BackupPart {
+    Preferences,
+    Saves,
+    Autosaves,
+    Stats,
+    Fetches,
+}
+
+impl BackupPart {
+    /// All parts, in restore order.
+    pub const ALL: [BackupPart; 5] = [
+        BackupPart::Preferences,
+        BackupPart::Saves,
+        BackupPart::Autosaves,
+        BackupPart::Stats,
+        BackupPart::Fetches,
+    ];
+
+    /// Name used on the command line and in the archive.
+    pub fn name(&self) -> &'static str {
+        match self {
+            BackupPart::Preferences => "preferences",
+            BackupPart::Saves => "saves",
+            BackupPart::Autosaves => "autosaves",
+            BackupPart::Stats => "stats",
+            BackupPart::Fetches => "fetches",
+        }
+    }
+
+    /// Parse a comma-separated list of part names.
+    pub fn parse_list(list: &str) -> Result<Vec<BackupPart>, BackupError> {
+        list.split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(|name| {
+                BackupPart::ALL
+                    .into_iter()
+                    .find(|part| part.name() == name)
+                    .ok_or_else(|| BackupError::UnknownPart(name.to_string()))
+            })
+            .collect()
+    }
+
+    /// Where this part lives on disk.
+    fn location(&self) -> Result<Location, BackupError> {
+        let location = match self {
+            BackupPart::Preferences => Location::File(
+                crate::preferences::preferences_path().map_err(|_| BackupError::NoHomeDir)?,
+            ),
+            BackupPart::Saves => {
+                Location::Dir(crate::save::saves_dir().map_err(|_| BackupError::NoHomeDir)?)
+            }
+            BackupPart::Autosaves => {
+                Location::Dir(crate::save::autosaves_dir().map_err(|_| BackupError::NoHomeDir)?)
+            }
+            BackupPart::Stats => {
+                Location::File(crate::stats::stats_path().map_err(|_| BackupError::NoHomeDir)?)
+            }
+            BackupPart::Fetches => Location::File(
+                crate::fetch_log::fetch_log_path().map_err(|_| BackupError::NoHomeDir)?,
+            ),
+        };
+        Ok(location)
+    }
+}
+
+/// A single file (`preferences.json`) or a directory of JSON files (saves).
+enum Location {
+    File(PathBuf),
+    Dir(PathBuf),
+}
+
+/// One file stored in a backup.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct BackupFile {
+    /// Part the file belongs to.
+    pub part: BackupPart,
+    /// File name, without directories.
+    pub name: String,
+    /// File contents.
+    pub contents: serde_json::Value,
+}
+
+/// Backup archive.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Backup {
+    /// Version for future compatibility.
+    pub version: u8,
+    /// Creation time, in RFC 3339 format.
+    pub created_at: String,
+    /// All backed up files.
+    pub files: Vec<BackupFile>,
+}
+
+impl Backup {
+    /// Number of files in the backup per part, skipping empty parts.
+    pub fn counts(&self) -> Vec<(BackupPart, usize)> {
+        BackupPart::ALL
+            .into_iter()
+            .map(|part| (part, self.files.iter().filter(|f| f.part == part).count()))
+            .filter(|(_, count)| *count > 0)
+            .collect()
+    }
+}
+
+/// Read a JSON file, skipping files that are missing or not valid JSON.
+///
+/// Compressed saves are stored decompressed, which loads just the same.
+fn read_json(path: &Path) -> Option<serde_json::Value> {
+    let contents = crate::save::read_save_json(path).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+/// Collect the current application state into a backup.
+pub fn collect() -> Result<Backup, BackupError> {
+    let mut files = Vec::new();
+    for part in BackupPart::ALL {
+        match part.location()? {
+            Location::File(path) => {
+                if let (Some(contents), Some(name)) = (read_json(&path), path.file_name()) {
+                    files.push(BackupFile {
+                        part,
+                        name: name.to_string_lossy().to_string(),
+                        contents,
+                    });
+                }
+            }
+            Location::Dir(dir) => {
+                let Ok(entries) = std::fs::read_dir(&dir) else {
+                    continue;
+                };
+                let mut paths: Vec<PathBuf> = entries
+                    .filter_map(|e| e.ok())
+                    .map(|e| e.path())
+                    .filter(|p| p.extension().is_some_and(|ext| ext == "json"))
+                    .collect();
+                paths.sort();
+                for path in paths {
+                    if let (Some(contents), Some(name)) = (read_json(&path), path.file_name()) {
+                        files.push(BackupFile {
+                            part,
+                            name: name.to_string_lossy().to_string(),
+                            contents,
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(Backup {
+        version: BACKUP_VERSION,
+        created_at: chrono::Local::now().to_rfc3339(),
+        files,
+    })
+}
+
+/// Write a backup of the current application state to `path`.
+pub fn export(path: &Path) -> Result<Backup, BackupError> {
+    let backup = collect()?;
+    std::fs::write(path, serde_json::to_string_pretty(&backup)?)?;
+    Ok(backup)
+}
+
+/// Read a backup archive from `path`.
+pub fn read(path: &Path) -> Result<Backup, BackupError> {
+    let backup: Backup = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+    if backup.version > BACKUP_VERSION {
+        return Err(BackupError::UnsupportedVersion(backup.version));
+    }
+    Ok(backup)
+}
+
+/// Restore `parts` of a backup, overwriting existing files with the same name.
+///
+/// Files already on disk that are not in the backup are kept.
+/// Returns the number of files restored.
+pub fn restore(backup: &Backup, parts: &[BackupPart]) -> Result<usize, BackupError> {
+    let mut restored = 0;
+    // restore in a fixed order, so that a restored `library_dir` preference applies to saves
+    for part in BackupPart::ALL.into_iter().filter(|p| parts.contains(p)) {
+        let location = part.location()?;
+        for file in backup.files.iter().filter(|f| f.part == part) {
+            let path = match &location {
+                Location::File(path) => path.clone(),
+                Location::Dir(dir) => {
+                    // only the file name is used, so archives can't write outside the directory
+                    let Some(name) = Path::new(&file.name).file_name() else {
+                        continue;
+                    };
+                    dir.join(name)
+                }
+            };
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(&path, serde_json::to_string_pretty(&file.contents)?)?;
+            restored += 1;
+        }
+    }
+    Ok(restored)
+}
@@ -0,0 +1,190 @@
+//! Cancellable, resumable bulk operations (archive downloads, conversions).
+//!
+//! A bulk job works through a list of items (dates, files) one at a time and
+//! reports progress on a channel. Finished items are recorded in a manifest in
+//! `bulk/` in the data directory after every step, so an interrupted job skips
+//! them when it is started again. The manifest is removed once every item has succeeded.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::future::Future;
+use std::path::PathBuf;
+use thiserror::Error;
+use tokio::sync::{mpsc, watch};
+
+/// Error type for bulk operations.
+#[derive(Error, Debug)]
+pub enum BulkError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("JSON error: {0}")]
+    Serde(#[from] serde_json::Error),
+    #[error("Could not determine home directory")]
+    NoHomeDir,
+}
+
+/// Persisted progress of a bulk job.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Manifest {
+    /// Items that finished successfully.
+    #[serde(default)]
+    pub done: BTreeSet<String>,
+    /// Items that failed on the last attempt, with their error.
+    #[serde(default)]
+    pub failed: BTreeMap<String, String>,
+}
+
+/// Progress events sent while a job runs.
+#[derive(Debug, Clone)]
+pub enum Progress {
+    /// Item was already done in an earlier run.
+    Skipped { item: String },
+    /// Item is being processed (`index` counts from 1).
+    Started {
+        item: String,
+        index: usize,
+        total: usize,
+    },
+    /// Item finished successfully.
+    Done { item: String },
+    /// Item failed; it is retried the next time the job runs.
+    Failed { item: String, error: String },
+    /// Job was cancelled; the remaining items are left for the next run.
+    Cancelled,
+}
+
+impl std::fmt::Display for Progress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Progress::Skipped { item } => write!(f, "{}: already done", item),
+            Progress::Started { item, index, total } => {
+                write!(f, "[{}/{}] {}...", index, total, item)
+            }
+            Progress::Done { item } => write!(f, "{}: done", item),
+            Progress::Failed { item, error } => write!(f, "{}: failed ({})", item, error),
+            Progress::Cancelled => write!(f, "Cancelled, run the same command again to resume"),
+        }
+    }
+}
+
+/// Outcome of a job run.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Summary {
+    pub done: usize,
+    pub skipped: usize,
+    pub failed: usize,
+    pub cancelled: bool,
+}
+
+/// Get the manifest path for a job (`bulk/{job_id}.json` in the data directory).
+pub fn manifest_path(job_id: &str) -> Result<PathBuf, BulkError> {
+    let dir = crate::paths::data_dir().ok_or(BulkError::NoHomeDir)?;
+    Ok(dir.join("bulk").join(format!("{}.json", job_id)))
+}
+
+/// A bulk job over a fixed list of items.
+pub struct BulkJob {
+    items: Vec<String>,
+    manifest: Manifest,
+    path: PathBuf,
+}
+
+impl BulkJob {
+    /// Open a job, resuming from its manifest if an earlier run was interrupted.
+    ///
+    /// `job_id` must identify the job (e.g. provider and date range), so that
+    /// running the same command again finds the same manifest.
+    pub fn open(job_id: &str, items: Vec<String>) -> Result<Self, BulkError> {
+        let path = manifest_path(job_id)?;
+        let manifest = match std::fs::read_to_string(&path) {
+            Ok(contents) => serde_json::from_str(&contents).unwrap_or_default(),
+            Err(_) => Manifest::default(),
+        };
+        Ok(Self {
+            items,
+            manifest,
+            path,
+        })
+    }
+
+    /// Whether an earlier run of this job left a manifest behind.
+    pub fn is_resumed(&self) -> bool {
+        !self.manifest.done.is_empty() || !self.manifest.failed.is_empty()
+    }
+
+    fn save_manifest(&self) -> Result<(), BulkError> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&self.path, serde_json::to_string_pretty(&self.manifest)?)?;
+        Ok(())
+    }
+
+    /// Run `step` on every item that is not done yet, in order.
+    ///
+    /// Progress is sent on `progress`; setting `cancel` to `true` stops the job,
+    /// abandoning the item in flight. The manifest is saved after every item.
+    pub async fn run<F, Fut>(
+        mut self,
+        mut step: F,
+        progress: mpsc::UnboundedSender<Progress>,
+        mut cancel: watch::Receiver<bool>,
+    ) -> Result<Summary, BulkError>
+    where
+        F: FnMut(String) -> Fut,
+        Fut: Future<Output = Result<(), String>>,
+    {
+        let mut summary = Summary::default();
+        let total = self.items.len();
+        let items = std::mem::take(&mut self.items);
+
+        for (index, item) in items.into_iter().enumerate() {
+            if self.manifest.done.contains(&item) {
+                summary.skipped += 1;
+                let _ = progress.send(Progress::Skipped { item });
+                continue;
+            }
+            if *cancel.borrow() {
+                summary.cancelled = true;
+                break;
+            }
+
+            let _ = progress.send(Progress::Started {
+                item: item.clone(),
+                index: index + 1,
+                total,
+            });
+            let result = tokio::select! {
+                result = step(item.clone()) => result,
+                _ = cancel.wait_for(|cancelled| *cancelled) => {
+                    summary.cancelled = true;
+                    break;
+                }
+            };
+
+            match result {
+                Ok(()) => {
+                    summary.done += 1;
+                    self.manifest.failed.remove(&item);
+                    self.manifest.done.insert(item.clone());
+                    let _ = progress.send(Progress::Done { item });
+                }
+                Err(error) => {
+                    summary.failed += 1;
+                    self.manifest.failed.insert(item.clone(), error.clone());
+                    let _ = progress.send(Progress::Failed { item, error });
+                }
+            }
+            self.save_manifest()?;
+        }
+
+        if summary.cancelled {
+            let _ = progress.send(Progress::Cancelled);
+            self.save_manifest()?;
+        } else if self.manifest.failed.is_empty() {
+            // everything succeeded, nothing to resume
+            let _ = std::fs::remove_file(&self.path);
+        }
+        Ok(summary)
+    }
+}
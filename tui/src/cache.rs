@@ -0,0 +1,293 @@
+//! Downloaded puzzle cache.
+//!
+//! Puzzles fetched for a specific date are stored in `cache/` in the data
+//! directory (or the configured cache directory) so they can be replayed
+//! offline. The cache is bounded by the `max_cache_mb` preference, evicting the least
+//! recently used entries first. In low-bandwidth mode entries are stored
+//! gzip-compressed, as `.json.gz`.
+
+use crate::preferences;
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use thiserror::Error;
+
+/// Error type for cache operations.
+#[derive(Error, Debug)]
+pub enum CacheError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("JSON error: {0}")]
+    Serde(#[from] serde_json::Error),
+    #[error("Could not determine home directory")]
+    NoHomeDir,
+}
+
+/// A cached puzzle along with its attribution.
+#[derive(Serialize, Deserialize)]
+pub struct CacheEntry {
+    /// The downloaded puzzle.
+    pub puzzle: puz_parse::Puzzle,
+    /// Where the puzzle came from.
+    pub source: Option<cruciverbal_providers::Source>,
+    /// Validators of the download, to check the puzzle for changes without
+    /// downloading it again.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validators: Option<cruciverbal_providers::util::Validators>,
+}
+
+/// Summary of a prune run.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PruneReport {
+    /// Number of entries removed.
+    pub removed: usize,
+    /// Bytes freed by removed entries.
+    pub freed_bytes: u64,
+    /// Bytes still used by the cache.
+    pub remaining_bytes: u64,
+}
+
+/// Get the cache directory path (`cache/` in the data directory).
+///
+/// Can be overridden with the `cache_dir` preference.
+pub fn cache_dir() -> Result<PathBuf, CacheError> {
+    if let Some(dir) = preferences::load_preferences().cache_dir {
+        return Ok(dir);
+    }
+    let dir = crate::paths::data_dir().ok_or(CacheError::NoHomeDir)?;
+    Ok(dir.join("cache"))
+}
+
+/// Maximum cache size in bytes, from preferences.
+pub fn max_cache_bytes() -> u64 {
+    preferences::load_preferences()
+        .max_cache_mb
+        .saturating_mul(1024 * 1024)
+}
+
+/// Provider name as used in cache file names (`guardian-cryptic`).
+fn provider_slug(provider_name: &str) -> String {
+    provider_name.to_lowercase().replace([' ', '/'], "-")
+}
+
+/// Cache file path for a provider and date: `{date}_{provider-slug}.json`.
+fn entry_path(dir: &Path, provider_name: &str, date: &str) -> PathBuf {
+    dir.join(format!("{}_{}.json", date, provider_slug(provider_name)))
+}
+
+/// Compressed variant of a cache file path (`.json.gz`).
+fn compressed_path(path: &Path) -> PathBuf {
+    path.with_extension("json.gz")
+}
+
+/// Whether `path` is a cache file, compressed or not: named
+/// `{date}_{provider-slug}.json` or `.json.gz` for a known provider.
+///
+/// Only these files are counted and evicted, so pointing the `cache_dir`
+/// preference at a folder with other files in it doesn't delete them.
+pub fn is_cache_file(path: &Path) -> bool {
+    let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+        return false;
+    };
+    let Some(stem) = name
+        .strip_suffix(".json.gz")
+        .or_else(|| name.strip_suffix(".json"))
+    else {
+        return false;
+    };
+    stem.split_once('_').is_some_and(|(date, slug)| {
+        chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").is_ok()
+            && cruciverbal_providers::PuzzleProvider::ALL
+                .iter()
+                .any(|provider| provider_slug(provider.name()) == slug)
+    })
+}
+
+/// File name of a cache file without its `.json` or `.json.gz` extension.
+pub fn entry_stem(path: &Path) -> Option<String> {
+    let name = path.file_name()?.to_string_lossy();
+    let name = name.strip_suffix(".gz").unwrap_or(&name);
+    Some(name.strip_suffix(".json").unwrap_or(name).to_string())
+}
+
+/// Look up a cached puzzle, marking it as recently used.
+pub fn load(provider_name: &str, date: &str) -> Option<CacheEntry> {
+    load_from(&cache_dir().ok()?, provider_name, date)
+}
+
+fn load_from(dir: &Path, provider_name: &str, date: &str) -> Option<CacheEntry> {
+    let path = entry_path(dir, provider_name, date);
+    let path = if path.exists() {
+        path
+    } else {
+        compressed_path(&path)
+    };
+    let entry = load_path(&path)?;
+
+    // bump the modification time so LRU eviction keeps this entry around
+    if let Ok(file) = std::fs::File::options().append(true).open(&path) {
+        let _ = file.set_modified(SystemTime::now());
+    }
+
+    Some(entry)
+}
+
+/// Read a cache file without touching its modification time.
+pub fn load_path(path: &Path) -> Option<CacheEntry> {
+    let mut contents = String::new();
+    if path.extension().is_some_and(|e| e == "gz") {
+        GzDecoder::new(std::fs::File::open(path).ok()?)
+            .read_to_string(&mut contents)
+            .ok()?;
+    } else {
+        contents = std::fs::read_to_string(path).ok()?;
+    }
+    serde_json::from_str(&contents).ok()
+}
+
+/// Store a puzzle in the cache, then prune it down to the configured size.
+pub fn store(provider_name: &str, date: &str, entry: &CacheEntry) -> Result<(), CacheError> {
+    let dir = cache_dir()?;
+    std::fs::create_dir_all(&dir)?;
+
+    let json = serde_json::to_string(entry)?;
+    let path = entry_path(&dir, provider_name, date);
+    let gz_path = compressed_path(&path);
+    if preferences::load_preferences().network.low_bandwidth {
+        let mut encoder = GzEncoder::new(std::fs::File::create(&gz_path)?, Compression::best());
+        encoder.write_all(json.as_bytes())?;
+        encoder.finish()?;
+        let _ = std::fs::remove_file(&path);
+    } else {
+        std::fs::write(&path, json)?;
+        let _ = std::fs::remove_file(&gz_path);
+    }
+
+    prune(max_cache_bytes())?;
+    Ok(())
+}
+
+/// Evict least recently used entries until the cache fits in `max_bytes`.
+pub fn prune(max_bytes: u64) -> Result<PruneReport, CacheError> {
+    let dir = cache_dir()?;
+    prune_dir(&dir, max_bytes)
+}
+
+fn prune_dir(dir: &Path, max_bytes: u64) -> Result<PruneReport, CacheError> {
+    let mut report = PruneReport::default();
+    if !dir.exists() {
+        return Ok(report);
+    }
+
+    let mut entries: Vec<(PathBuf, u64, SystemTime)> = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        if is_cache_file(&path) {
+            let meta = entry.metadata()?;
+            entries.push((path, meta.len(), meta.modified()?));
+        }
+    }
+
+    let mut total: u64 = entries.iter().map(|(_, size, _)| size).sum();
+
+    // Oldest first
+    entries.sort_by_key(|(_, _, mtime)| *mtime);
+
+    for (path, size, _) in entries {
+        if total <= max_bytes {
+            break;
+        }
+        std::fs::remove_file(&path)?;
+        total -= size;
+        report.removed += 1;
+        report.freed_bytes += size;
+    }
+
+    report.remaining_bytes = total;
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cruciverbal_providers::samples::SAMPLES;
+    use std::time::Duration;
+
+    /// Write a cache file last used `age_secs` ago.
+    fn write_aged(path: &Path, contents: &[u8], age_secs: u64) {
+        std::fs::write(path, contents).unwrap();
+        let file = std::fs::File::options().append(true).open(path).unwrap();
+        file.set_modified(SystemTime::now() - Duration::from_secs(age_secs))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_prune_dir() {
+        let dir = std::env::temp_dir().join(format!("cruciverbal-prune-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let (oldest, middle, newest) = (
+            entry_path(&dir, "Guardian Cryptic", "2025-01-28"),
+            compressed_path(&entry_path(&dir, "Guardian Cryptic", "2025-01-29")),
+            entry_path(&dir, "Guardian Quick", "2025-01-30"),
+        );
+        write_aged(&oldest, &[b' '; 100], 300);
+        write_aged(&middle, &[b' '; 100], 200);
+        write_aged(&newest, &[b' '; 100], 100);
+        // other files don't count towards the cache size and are never removed,
+        // in case the cache directory is shared
+        let others = [
+            "notes.txt",
+            "Sunday special.json",
+            "2025-01-27_not-a-provider.json",
+        ];
+        for name in others {
+            write_aged(&dir.join(name), &[b' '; 1000], 400);
+        }
+
+        // nothing to do while the cache fits
+        let report = prune_dir(&dir, 300).unwrap();
+        assert_eq!((report.removed, report.remaining_bytes), (0, 300));
+
+        // least recently used entries go first, until the cache fits
+        let report = prune_dir(&dir, 150).unwrap();
+        assert_eq!(report.removed, 2);
+        assert_eq!(report.freed_bytes, 200);
+        assert_eq!(report.remaining_bytes, 100);
+        assert!(!oldest.exists() && !middle.exists() && newest.exists());
+
+        let report = prune_dir(&dir, 0).unwrap();
+        assert_eq!((report.removed, report.remaining_bytes), (1, 0));
+        assert!(others.iter().all(|name| dir.join(name).exists()));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_load_refreshes_recency() {
+        let dir = std::env::temp_dir().join(format!("cruciverbal-cache-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let entry = CacheEntry {
+            puzzle: SAMPLES[0].puzzle().unwrap(),
+            source: None,
+            validators: None,
+        };
+        let json = serde_json::to_vec(&entry).unwrap();
+        let older = entry_path(&dir, "Guardian Quick", "2025-01-30");
+        let newer = entry_path(&dir, "Guardian Quick", "2025-01-31");
+        write_aged(&older, &json, 200);
+        write_aged(&newer, &json, 100);
+
+        // loading the older entry makes it the most recently used one
+        assert!(load_from(&dir, "Guardian Quick", "2025-01-30").is_some());
+        let report = prune_dir(&dir, json.len() as u64).unwrap();
+        assert_eq!(report.removed, 1);
+        assert!(older.exists() && !newer.exists());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
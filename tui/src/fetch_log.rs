@@ -0,0 +1,230 @@
+//! Log of puzzle downloads.
+//!
+//! Every download attempt (provider, date, duration, size and outcome) is
+//! recorded in `fetches.json` in the data directory, together with the last
+//! successful download per provider. The providers screen shows how reliable
+//! each provider has been, and `cruciverbal fetch-log` prints the recent attempts
+//! for bug reports.
+
+use chrono::NaiveDate;
+use cruciverbal_providers::util::Validators;
+use cruciverbal_providers::{Fetched, ProviderError, PuzzleProvider};
+use puz_parse::Puzzle;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::Instant;
+use thiserror::Error;
+
+/// Number of download attempts kept in the log.
+const MAX_RECORDS: usize = 500;
+
+/// Error type for fetch log operations.
+#[derive(Error, Debug)]
+pub enum FetchLogError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("JSON error: {0}")]
+    Serde(#[from] serde_json::Error),
+    #[error("Could not determine home directory")]
+    NoHomeDir,
+}
+
+/// A single download attempt.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct FetchRecord {
+    /// Provider display name (e.g., "Guardian Cryptic").
+    pub provider_name: String,
+    /// Requested puzzle date (None = latest puzzle).
+    pub date: Option<String>,
+    /// When the download started, in RFC 3339 format.
+    pub started_at: String,
+    /// How long the download took, in milliseconds.
+    pub duration_ms: u64,
+    /// Size of the responses, as announced by the server (0 if unknown).
+    pub bytes: u64,
+    /// Error message, if the download failed.
+    #[serde(default)]
+    pub error: Option<String>,
+    /// Whether the puzzle was unchanged since it was last downloaded, so it
+    /// wasn't downloaded again.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub not_modified: bool,
+}
+
+impl FetchRecord {
+    pub fn is_ok(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+impl std::fmt::Display for FetchRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} {}: ",
+            self.started_at,
+            self.provider_name,
+            self.date.as_deref().unwrap_or("latest")
+        )?;
+        let stats = format!(
+            "{:.1}s, {:.1} KB",
+            self.duration_ms as f64 / 1000.0,
+            self.bytes as f64 / 1024.0
+        );
+        match &self.error {
+            None if self.not_modified => write!(f, "unchanged ({})", stats),
+            None => write!(f, "ok ({})", stats),
+            Some(error) => write!(f, "failed ({}): {}", stats, error),
+        }
+    }
+}
+
+/// Download statistics for one provider.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Reliability {
+    /// Successful attempts.
+    pub ok: usize,
+    /// All attempts.
+    pub total: usize,
+    /// Average duration of successful attempts, in milliseconds.
+    pub average_ms: Option<u64>,
+}
+
+/// Persisted fetch history.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct FetchLog {
+    /// Last successful fetch per provider name, in RFC 3339 format.
+    #[serde(default)]
+    pub last_fetch: HashMap<String, String>,
+    /// Recent download attempts, oldest first.
+    #[serde(default)]
+    pub recent: Vec<FetchRecord>,
+}
+
+impl FetchLog {
+    /// Add an attempt, dropping the oldest ones beyond [`MAX_RECORDS`].
+    pub fn record(&mut self, record: FetchRecord) {
+        if record.is_ok() {
+            self.last_fetch
+                .insert(record.provider_name.clone(), record.started_at.clone());
+        }
+        self.recent.push(record);
+        if self.recent.len() > MAX_RECORDS {
+            self.recent.drain(..self.recent.len() - MAX_RECORDS);
+        }
+    }
+
+    /// Statistics for a provider's logged attempts, if there are any.
+    pub fn reliability(&self, provider_name: &str) -> Option<Reliability> {
+        let records: Vec<&FetchRecord> = self
+            .recent
+            .iter()
+            .filter(|r| r.provider_name == provider_name)
+            .collect();
+        if records.is_empty() {
+            return None;
+        }
+
+        let durations: Vec<u64> = records
+            .iter()
+            .filter(|r| r.is_ok())
+            .map(|r| r.duration_ms)
+            .collect();
+        Some(Reliability {
+            ok: durations.len(),
+            total: records.len(),
+            average_ms: (!durations.is_empty())
+                .then(|| durations.iter().sum::<u64>() / durations.len() as u64),
+        })
+    }
+
+    /// The most recent failed attempt for a provider.
+    pub fn last_error(&self, provider_name: &str) -> Option<&FetchRecord> {
+        self.recent
+            .iter()
+            .rev()
+            .find(|r| r.provider_name == provider_name && !r.is_ok())
+    }
+}
+
+/// Get the fetch log file path (`fetches.json` in the data directory).
+pub fn fetch_log_path() -> Result<PathBuf, FetchLogError> {
+    let dir = crate::paths::data_dir().ok_or(FetchLogError::NoHomeDir)?;
+    Ok(dir.join("fetches.json"))
+}
+
+/// Load the fetch log from disk.
+///
+/// Returns an empty log if the file doesn't exist or can't be read.
+pub fn load_fetch_log() -> FetchLog {
+    let Ok(path) = fetch_log_path() else {
+        return FetchLog::default();
+    };
+
+    match std::fs::read_to_string(&path) {
+        Ok(contents) => serde_json::from_str(&contents).unwrap_or_default(),
+        Err(_) => FetchLog::default(),
+    }
+}
+
+/// Append a download attempt to the persisted log.
+pub fn record_fetch(record: FetchRecord) -> Result<(), FetchLogError> {
+    let mut log = load_fetch_log();
+    log.record(record);
+
+    let path = fetch_log_path()?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&path, serde_json::to_string_pretty(&log)?)?;
+    Ok(())
+}
+
+/// Download a puzzle from `provider` (`date` = None for the latest one), logging
+/// the attempt whether it succeeds or not.
+///
+/// Returns the puzzle with its publication date, if known (see
+/// [`PuzzleProvider::download_dated`]), and the validators of its response, to
+/// check it for changes later with [`download_if_modified`].
+pub async fn download(
+    provider: PuzzleProvider,
+    date: Option<&str>,
+) -> Result<(Puzzle, Option<NaiveDate>, Option<Validators>), ProviderError> {
+    match download_if_modified(provider, date, None).await? {
+        Fetched::Modified {
+            puzzle,
+            date,
+            validators,
+        } => Ok((*puzzle, date, validators)),
+        // there was nothing to compare against
+        Fetched::NotModified => Err(ProviderError::Other(
+            "unexpected 304 Not Modified response".to_string(),
+        )),
+    }
+}
+
+/// Download a puzzle unless it is unchanged since the download that returned
+/// `previous`, logging the attempt whether it succeeds or not.
+pub async fn download_if_modified(
+    provider: PuzzleProvider,
+    date: Option<&str>,
+    previous: Option<Validators>,
+) -> Result<Fetched, ProviderError> {
+    let started_at = chrono::Local::now().to_rfc3339();
+    let started = Instant::now();
+    let bytes_before = cruciverbal_providers::util::bytes_received();
+
+    let result = provider.download_if_modified(date, previous).await;
+
+    let _ = record_fetch(FetchRecord {
+        provider_name: provider.name().to_string(),
+        date: date.map(str::to_string),
+        started_at,
+        duration_ms: started.elapsed().as_millis() as u64,
+        bytes: cruciverbal_providers::util::bytes_received().saturating_sub(bytes_before),
+        error: result.as_ref().err().map(|e| e.to_string()),
+        not_modified: matches!(result, Ok(Fetched::NotModified)),
+    });
+    result
+}
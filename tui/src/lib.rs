@@ -1,8 +1,19 @@
 mod app;
 pub use app::{App, AppView};
 
+pub mod backup;
+pub mod bulk;
+pub mod cache;
+pub mod fetch_log;
+pub mod library;
+pub mod paths;
+pub mod patterns;
 pub mod preferences;
 pub mod save;
+pub mod share;
+pub mod stats;
+pub mod sync;
+pub mod terminal;
 pub mod theme;
 pub mod views;
 pub use views::*;
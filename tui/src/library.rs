@@ -0,0 +1,239 @@
+//! Clue/answer concordance over the puzzle library.
+//!
+//! Every entry of every saved game and cached download is indexed in
+//! `index.json` in the data directory, so previous appearances of an answer or
+//! clue ("where have I seen ETUI before?") can be looked up. The index is
+//! rebuilt whenever a library file is added, removed or newer than the index.
+
+use crate::{cache, save};
+use cruciverbal_providers::entries::{self, EntryDirection};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use thiserror::Error;
+
+/// Error type for library index operations.
+#[derive(Error, Debug)]
+pub enum LibraryError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("JSON error: {0}")]
+    Serde(#[from] serde_json::Error),
+    #[error("Could not determine home directory")]
+    NoHomeDir,
+}
+
+/// Where an indexed puzzle is stored.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LibraryKind {
+    /// A saved (or auto-saved) game.
+    Save,
+    /// A cached download.
+    Cache,
+}
+
+/// A single clue/answer pair in the index.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct IndexEntry {
+    /// Answer letters (uppercase).
+    pub answer: String,
+    /// Clue text.
+    pub clue: String,
+    pub direction: EntryDirection,
+    /// Clue number.
+    pub number: u16,
+    /// Position of the entry's first cell (row, col).
+    pub start: (usize, usize),
+    /// Puzzle title.
+    pub title: String,
+    /// Puzzle date string (e.g., "2025-01-30").
+    pub puzzle_date: String,
+    /// Provider name.
+    pub provider_name: String,
+    /// File the puzzle was indexed from.
+    pub path: PathBuf,
+    pub kind: LibraryKind,
+}
+
+/// The persisted concordance index.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct LibraryIndex {
+    /// When the index was built (Unix epoch seconds).
+    pub built_at: u64,
+    /// Number of library files scanned, to notice deletions.
+    #[serde(default)]
+    pub file_count: usize,
+    /// All indexed entries.
+    pub entries: Vec<IndexEntry>,
+}
+
+impl LibraryIndex {
+    /// Build the index by scanning saves, auto-saves and the download cache.
+    ///
+    /// The same puzzle found in several files is only indexed once.
+    pub fn build() -> Self {
+        let files = library_files();
+        let mut index = LibraryIndex {
+            built_at: now_secs(),
+            file_count: files.len(),
+            entries: Vec::new(),
+        };
+        let mut seen: Vec<(String, String)> = Vec::new();
+
+        for (path, kind) in files {
+            let Some((puzzle, puzzle_date, provider_name)) = read_puzzle(&path, kind) else {
+                continue;
+            };
+
+            let key = (provider_name.clone(), puzzle_date.clone());
+            if seen.contains(&key) {
+                continue;
+            }
+            seen.push(key);
+
+            for entry in entries::entries(&puzzle) {
+                index.entries.push(IndexEntry {
+                    answer: entry.answer.to_uppercase(),
+                    clue: entry.clue,
+                    direction: entry.direction,
+                    number: entry.number,
+                    start: (entry.row, entry.col),
+                    title: puzzle.info.title.clone(),
+                    puzzle_date: puzzle_date.clone(),
+                    provider_name: provider_name.clone(),
+                    path: path.clone(),
+                    kind,
+                });
+            }
+        }
+
+        index
+    }
+
+    /// Search answers and clues for `query` (case-insensitive).
+    ///
+    /// Exact answer matches come first, then partial answer matches, then clue matches.
+    pub fn search(&self, query: &str) -> Vec<&IndexEntry> {
+        let query = query.trim().to_uppercase();
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        let mut exact = Vec::new();
+        let mut partial = Vec::new();
+        let mut clues = Vec::new();
+        for entry in &self.entries {
+            if entry.answer == query {
+                exact.push(entry);
+            } else if entry.answer.contains(&query) {
+                partial.push(entry);
+            } else if entry.clue.to_uppercase().contains(&query) {
+                clues.push(entry);
+            }
+        }
+
+        exact.extend(partial);
+        exact.extend(clues);
+        exact
+    }
+}
+
+/// Get the index file path (`index.json` in the data directory).
+pub fn index_path() -> Result<PathBuf, LibraryError> {
+    let dir = crate::paths::data_dir().ok_or(LibraryError::NoHomeDir)?;
+    Ok(dir.join("index.json"))
+}
+
+/// Load the index from disk, rebuilding (and saving) it if any library file changed.
+pub fn load_or_build() -> Result<LibraryIndex, LibraryError> {
+    let path = index_path()?;
+    let existing: Option<LibraryIndex> = std::fs::read_to_string(&path)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok());
+
+    if let Some(index) = existing {
+        let files = library_files();
+        let is_stale = files.len() != index.file_count
+            || files
+                .iter()
+                .any(|(file, _)| modified_secs(file) >= index.built_at);
+        if !is_stale {
+            return Ok(index);
+        }
+    }
+
+    let index = LibraryIndex::build();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&path, serde_json::to_string(&index)?)?;
+    Ok(index)
+}
+
+/// All puzzle files in the library.
+fn library_files() -> Vec<(PathBuf, LibraryKind)> {
+    let dirs = [
+        (save::saves_dir().ok(), LibraryKind::Save),
+        (save::autosaves_dir().ok(), LibraryKind::Save),
+        (cache::cache_dir().ok(), LibraryKind::Cache),
+    ];
+
+    let mut files = Vec::new();
+    for (dir, kind) in dirs {
+        let Some(Ok(read_dir)) = dir.map(std::fs::read_dir) else {
+            continue;
+        };
+        for entry in read_dir.flatten() {
+            let path = entry.path();
+            let is_puzzle_file = match kind {
+                LibraryKind::Save => path.extension().is_some_and(|e| e == "json"),
+                LibraryKind::Cache => cache::is_cache_file(&path),
+            };
+            if is_puzzle_file {
+                files.push((path, kind));
+            }
+        }
+    }
+    files
+}
+
+/// Read a library file, returning the puzzle, its date and provider name.
+fn read_puzzle(path: &Path, kind: LibraryKind) -> Option<(puz_parse::Puzzle, String, String)> {
+    match kind {
+        LibraryKind::Save => {
+            let game_save = save::load_game(path).ok()?;
+            Some((
+                game_save.puzzle,
+                game_save.puzzle_date,
+                game_save.provider_name,
+            ))
+        }
+        LibraryKind::Cache => {
+            let entry = cache::load_path(path)?;
+            // cache files are named `{date}_{provider-slug}.json`, or `.json.gz` if compressed
+            let stem = cache::entry_stem(path)?;
+            let (date, slug) = stem.split_once('_')?;
+            let provider_name = entry
+                .source
+                .map(|source| source.provider)
+                .unwrap_or_else(|| slug.to_string());
+            Some((entry.puzzle, date.to_string(), provider_name))
+        }
+    }
+}
+
+fn modified_secs(path: &Path) -> u64 {
+    std::fs::metadata(path)
+        .and_then(|meta| meta.modified())
+        .ok()
+        .and_then(|mtime| mtime.duration_since(UNIX_EPOCH).ok())
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
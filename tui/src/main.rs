@@ -1,12 +1,710 @@
-use cruciverbal::App;
+use crossterm::event::{DisableMouseCapture, EnableMouseCapture};
+use cruciverbal::{App, backup, bulk, cache, fetch_log, save, stats, sync};
+use cruciverbal_providers::util::Validators;
+use cruciverbal_providers::{DateZone, Fetched, PuzzleProvider, Source, formats};
+use std::path::{Path, PathBuf};
+
+const USAGE: &str = "usage:
+  cruciverbal                         start the terminal UI
+  cruciverbal [play] FILE             open a puzzle file in the terminal UI
+  cruciverbal play FILE --key KEY     unlock a scrambled .puz solution first
+                                      (KEY: the 4-digit key, or \"auto\" to find it)
+  cruciverbal download --provider NAME [--date YYYY-MM-DD] [-o FILE]
+                                      download a puzzle (to the cache, or to FILE)
+  cruciverbal download --provider NAME --from YYYY-MM-DD --to YYYY-MM-DD [--recheck]
+                                      download a date range into the cache (resumable);
+                                      --recheck also updates cached puzzles that changed
+  cruciverbal convert IN OUT [--enumerations]
+                                      convert a puzzle file (OUT: .json, or .svg image),
+                                      appending enumerations like (5) to the clues
+  cruciverbal convert IN OUT.svg --solution
+                                      render the grid filled in with the solution
+  cruciverbal convert --into DIR IN...
+                                      convert many files into DIR as .json (resumable)
+  cruciverbal providers               list providers with their schedule, size and difficulty
+  cruciverbal cache prune [MAX_MB]    trim the puzzle cache
+  cruciverbal solution SAVE           print the solution, colored by the saved fill,
+                                      and the answers with their word breaks
+  cruciverbal solution SAVE --fetch   download the published solution of a Guardian
+                                      prize puzzle into the save first
+  cruciverbal lint FILE [--strict] [--blocklist WORDS]
+                                      check a puzzle for errors before publishing
+                                      (exits 1 on errors, or on warnings with --strict);
+                                      WORDS is a file of banned entries, one per line
+  cruciverbal remind                  warn if today's daily puzzle is unsolved
+  cruciverbal fetch-daily [NAMES] [--recheck]
+                                      cache today's puzzles, for cron (NAMES: a,b,...);
+                                      --recheck also updates cached puzzles that changed
+  cruciverbal fetch-log [COUNT]       print the latest downloads, to include in bug reports
+  cruciverbal backup export FILE      back up preferences, saves and stats
+  cruciverbal backup import FILE [PARTS]
+  cruciverbal sync                    exchange saves and stats with the remote set
+                                      in the preferences (a git repo, WebDAV folder
+                                      or directory)";
 
 #[tokio::main]
 async fn main() -> color_eyre::Result<()> {
     color_eyre::install()?;
+    let prefs = cruciverbal::preferences::load_preferences();
+    cruciverbal_providers::util::set_network_settings(prefs.network);
+    cruciverbal_providers::util::set_disabled_providers(prefs.disabled_providers);
+    if let Some(zone) = prefs.timezone.as_deref().and_then(DateZone::parse) {
+        cruciverbal_providers::util::set_date_zone(zone);
+    }
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let mut puzzle_file = None;
+    match args.as_slice() {
+        [] => {}
+        ["help" | "--help" | "-h"] => {
+            println!("{}", USAGE);
+            return Ok(());
+        }
+        ["providers"] => return list_providers(),
+        ["download", options @ ..] => return download(options).await,
+        ["convert", "--into", dir, inputs @ ..] if !inputs.is_empty() => {
+            return convert_many(dir, inputs).await;
+        }
+        ["convert", input, output, options @ ..] => return convert(input, output, options),
+        ["cache", "prune"] => return prune_cache(cache::max_cache_bytes()),
+        ["cache", "prune", max_mb] => {
+            let max_mb: u64 = max_mb.parse()?;
+            return prune_cache(max_mb.saturating_mul(1024 * 1024));
+        }
+        ["solution", file] => return print_solution(file),
+        ["solution", file, "--fetch"] => return fetch_solution(file).await,
+        ["lint", file, options @ ..] => return lint(file, options),
+        ["remind"] => return remind(),
+        ["fetch-daily"] => return fetch_daily(None, false).await,
+        ["fetch-daily", "--recheck"] => return fetch_daily(None, true).await,
+        ["fetch-daily", names] => return fetch_daily(Some(names), false).await,
+        ["fetch-daily", names, "--recheck"] => return fetch_daily(Some(names), true).await,
+        ["fetch-log"] => return print_fetch_log(20),
+        ["fetch-log", count] => return print_fetch_log(count.parse()?),
+        ["backup", "export", file] => return export_backup(file),
+        ["backup", "import", file] => return import_backup(file, &backup::BackupPart::ALL),
+        ["backup", "import", file, parts] => {
+            return import_backup(file, &backup::BackupPart::parse_list(parts)?);
+        }
+        ["sync"] => return run_sync().await,
+        [path] | ["play", path] if !path.starts_with('-') => {
+            let path = PathBuf::from(path);
+            let puzzle = load_puzzle(&path)?;
+            puzzle_file = Some((path, puzzle));
+        }
+        ["play", path, "--key", key] => {
+            let path = PathBuf::from(path);
+            let puzzle = unlock_puzzle(&path, key)?;
+            puzzle_file = Some((path, puzzle));
+        }
+        _ => {
+            eprintln!("{}", USAGE);
+            std::process::exit(2);
+        }
+    }
 
     let terminal = ratatui::init();
-    let app = App::new();
+    // auto-save the game in progress on a crash, before the terminal is restored
+    let restore_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        cruciverbal::save::save_on_crash();
+        restore_hook(info);
+    }));
+    crossterm::execute!(std::io::stdout(), EnableMouseCapture)?;
+    let mut app = App::new();
+    if let Some((path, puzzle)) = puzzle_file {
+        app.open_puzzle_file(&path, puzzle);
+    }
     let result = app.run(terminal).await;
+    let _ = crossterm::execute!(std::io::stdout(), DisableMouseCapture);
     ratatui::restore();
     result
 }
+
+/// Load a puzzle file, detecting its format.
+fn load_puzzle(path: &Path) -> color_eyre::Result<puz_parse::Puzzle> {
+    formats::load_file(path).map_err(|e| color_eyre::eyre::eyre!("{}: {}", path.display(), e))
+}
+
+/// Load a `.puz` file and unlock its scrambled solution with `key`, or by trying
+/// every key when it's "auto".
+fn unlock_puzzle(path: &Path, key: &str) -> color_eyre::Result<puz_parse::Puzzle> {
+    let key = match key {
+        "auto" => None,
+        key => match key.parse::<u16>() {
+            Ok(key) if key <= formats::scramble::MAX_KEY => Some(key),
+            _ => color_eyre::eyre::bail!("the key must be 4 digits, or \"auto\""),
+        },
+    };
+    let puzzle = load_puzzle(path)?;
+    let (puzzle, found) = formats::scramble::unlock_file(path, &puzzle, key)
+        .map_err(|e| color_eyre::eyre::eyre!("{}: {}", path.display(), e))?;
+    if key.is_none() {
+        println!("Unlocked with key {:04}", found);
+    }
+    Ok(puzzle)
+}
+
+/// Print the provider names accepted by `download --provider`.
+fn list_providers() -> color_eyre::Result<()> {
+    for provider in PuzzleProvider::ALL {
+        let disabled = if provider.is_enabled() {
+            ""
+        } else {
+            " (disabled)"
+        };
+        println!("{:<24} {}{}", provider.slug(), provider.summary(), disabled);
+    }
+    Ok(())
+}
+
+/// Download a puzzle, storing it in the cache or writing it to `-o FILE`.
+async fn download(options: &[&str]) -> color_eyre::Result<()> {
+    let mut provider = None;
+    let mut date = None;
+    let mut range = (None, None);
+    let mut output = None;
+    let mut recheck = false;
+    let mut options = options.iter();
+    while let Some(option) = options.next() {
+        if *option == "--recheck" {
+            recheck = true;
+            continue;
+        }
+        let value = options.next().copied();
+        match (*option, value) {
+            ("--provider" | "-p", Some(name)) => {
+                provider = Some(PuzzleProvider::from_slug(name).ok_or_else(|| {
+                    color_eyre::eyre::eyre!(
+                        "unknown provider {} (see `cruciverbal providers`)",
+                        name
+                    )
+                })?);
+            }
+            ("--date" | "-d", Some(value)) => {
+                chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")?;
+                date = Some(value);
+            }
+            ("--from", Some(value)) => {
+                range.0 = Some(chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")?);
+            }
+            ("--to", Some(value)) => {
+                range.1 = Some(chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")?);
+            }
+            ("--output" | "-o", Some(value)) => output = Some(PathBuf::from(value)),
+            _ => {
+                eprintln!("{}", USAGE);
+                std::process::exit(2);
+            }
+        }
+    }
+    let Some(provider) = provider else {
+        eprintln!("{}", USAGE);
+        std::process::exit(2);
+    };
+    if date.is_some() && !provider.supports_dates() {
+        return Err(color_eyre::eyre::eyre!(
+            "{} only offers its latest puzzle",
+            provider.name()
+        ));
+    }
+    match range {
+        (Some(from), Some(to)) if date.is_none() && output.is_none() => {
+            return download_range(provider, from, to, recheck).await;
+        }
+        (None, None) if !recheck => {}
+        _ => {
+            eprintln!("{}", USAGE);
+            std::process::exit(2);
+        }
+    }
+
+    let (mut puzzle, published, validators) = fetch_log::download(provider, date)
+        .await
+        .map_err(|e| color_eyre::eyre::eyre!("{}: {}", provider.name(), e))?;
+    let source = Source::fetched(provider, &puzzle);
+    for warning in cruciverbal_providers::entries::check_enumerations(&puzzle) {
+        eprintln!("warning: {}", warning);
+    }
+
+    match output {
+        Some(path) => {
+            source.annotate(&mut puzzle);
+            formats::save_file(&path, &puzzle).map_err(|e| color_eyre::eyre::eyre!("{}", e))?;
+            println!("Saved {} to {}", puzzle.info.title, path.display());
+        }
+        None => {
+            // the latest puzzle may be from an earlier day if today's isn't out yet
+            let puzzle_date = published
+                .map(|d| d.format("%Y-%m-%d").to_string())
+                .or_else(|| date.map(str::to_string))
+                .unwrap_or_else(|| provider.today().format("%Y-%m-%d").to_string());
+            let title = puzzle.info.title.clone();
+            let entry = cache::CacheEntry {
+                puzzle,
+                source: Some(source),
+                validators,
+            };
+            cache::store(provider.name(), &puzzle_date, &entry)?;
+            println!("Cached {} ({} {})", title, provider.name(), puzzle_date);
+        }
+    }
+    Ok(())
+}
+
+/// Download today's puzzle from each configured provider into the cache.
+///
+/// Providers come from `names` (comma-separated slugs) or the `fetch_daily`
+/// preference, falling back to the streak reminder's daily provider. Exits with
+/// a non-zero status if any download fails, so it can be run from cron.
+///
+/// Puzzles that are already cached are skipped, or with `recheck` downloaded
+/// again if they changed (e.g. corrected by the publisher).
+async fn fetch_daily(names: Option<&str>, recheck: bool) -> color_eyre::Result<()> {
+    let providers: Vec<PuzzleProvider> = match names {
+        Some(names) => names
+            .split(',')
+            .map(|name| {
+                PuzzleProvider::from_slug(name.trim()).ok_or_else(|| {
+                    color_eyre::eyre::eyre!(
+                        "unknown provider {} (see `cruciverbal providers`)",
+                        name
+                    )
+                })
+            })
+            .collect::<Result<_, _>>()?,
+        None => {
+            let prefs = cruciverbal::preferences::load_preferences();
+            let names = if prefs.fetch_daily.is_empty() {
+                prefs.daily_provider.into_iter().collect()
+            } else {
+                prefs.fetch_daily
+            };
+            names
+                .iter()
+                .filter_map(|name| PuzzleProvider::ALL.into_iter().find(|p| p.name() == name))
+                .collect()
+        }
+    };
+    if providers.is_empty() {
+        return Err(color_eyre::eyre::eyre!(
+            "no providers to fetch, set `fetch_daily` in preferences.json or pass NAMES"
+        ));
+    }
+
+    let mut failed = 0;
+    for provider in providers {
+        let today = provider.today();
+        let date = today.format("%Y-%m-%d").to_string();
+        if provider.supports_dates() && !provider.schedule().publishes_on(today) {
+            println!("{}: no puzzle today", provider.name());
+            continue;
+        }
+        let cached = cache::load(provider.name(), &date);
+        if cached.is_some() && !recheck {
+            println!("{}: already cached", provider.name());
+            continue;
+        }
+
+        // providers without an archive only offer their latest puzzle
+        let requested = provider.supports_dates().then_some(date.as_str());
+        let was_cached = cached.is_some();
+        let previous = cached.and_then(|entry| entry.validators);
+        match cache_puzzle(provider, &date, requested, previous).await {
+            Ok(false) => println!("{}: unchanged", provider.name()),
+            Ok(true) if was_cached => println!("{}: updated", provider.name()),
+            Ok(true) => println!("{}: cached", provider.name()),
+            Err(e) => {
+                failed += 1;
+                eprintln!("{}: failed ({})", provider.name(), e);
+            }
+        }
+    }
+
+    if failed > 0 {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// Run a bulk job, printing its progress. CTRL+C cancels it, leaving the
+/// manifest behind so the same command resumes it.
+async fn run_bulk<F, Fut>(job: bulk::BulkJob, step: F) -> color_eyre::Result<()>
+where
+    F: FnMut(String) -> Fut,
+    Fut: std::future::Future<Output = Result<(), String>>,
+{
+    if job.is_resumed() {
+        println!("Resuming an interrupted run");
+    }
+
+    let (cancel_tx, cancel_rx) = tokio::sync::watch::channel(false);
+    tokio::spawn(async move {
+        if tokio::signal::ctrl_c().await.is_ok() {
+            let _ = cancel_tx.send(true);
+        }
+    });
+
+    let (progress_tx, mut progress_rx) = tokio::sync::mpsc::unbounded_channel();
+    let print_progress = async {
+        while let Some(progress) = progress_rx.recv().await {
+            println!("{}", progress);
+        }
+    };
+    let (summary, ()) = tokio::join!(job.run(step, progress_tx, cancel_rx), print_progress);
+    let summary = summary?;
+
+    println!(
+        "{} done, {} already done, {} failed",
+        summary.done, summary.skipped, summary.failed
+    );
+    if summary.failed > 0 || summary.cancelled {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// Download a puzzle (`requested` = None for the latest one) into the cache
+/// under the date it was published on, or `date` if that isn't known, unless it is unchanged since the download that returned
+/// `previous`. Returns whether the cache was updated.
+async fn cache_puzzle(
+    provider: PuzzleProvider,
+    date: &str,
+    requested: Option<&str>,
+    previous: Option<Validators>,
+) -> Result<bool, String> {
+    let fetched = fetch_log::download_if_modified(provider, requested, previous)
+        .await
+        .map_err(|e| e.to_string())?;
+    let Fetched::Modified {
+        puzzle,
+        validators,
+        date: published,
+    } = fetched
+    else {
+        return Ok(false);
+    };
+    let date = published.map_or_else(|| date.to_string(), |d| d.format("%Y-%m-%d").to_string());
+    let source = Source::fetched(provider, &puzzle);
+    let entry = cache::CacheEntry {
+        puzzle: *puzzle,
+        source: Some(source),
+        validators,
+    };
+    cache::store(provider.name(), &date, &entry).map_err(|e| e.to_string())?;
+    Ok(true)
+}
+
+/// Download every published puzzle from `from` to `to` into the cache.
+///
+/// Cached puzzles are skipped, or with `recheck` downloaded again if they changed.
+async fn download_range(
+    provider: PuzzleProvider,
+    from: chrono::NaiveDate,
+    to: chrono::NaiveDate,
+    recheck: bool,
+) -> color_eyre::Result<()> {
+    if !provider.supports_dates() {
+        return Err(color_eyre::eyre::eyre!(
+            "{} only offers its latest puzzle",
+            provider.name()
+        ));
+    }
+
+    let today = provider.today();
+    let dates: Vec<String> = from
+        .iter_days()
+        .take_while(|date| *date <= to)
+        .filter(|date| provider.is_available(*date, today))
+        .map(|date| date.format("%Y-%m-%d").to_string())
+        .collect();
+    let mode = if recheck { "recheck" } else { "download" };
+    let job_id = format!("{}_{}_{}_{}", mode, provider.slug(), from, to);
+    let job = bulk::BulkJob::open(&job_id, dates)?;
+
+    run_bulk(job, |date| async move {
+        let cached = cache::load(provider.name(), &date);
+        if cached.is_some() && !recheck {
+            return Ok(());
+        }
+        let previous = cached.and_then(|entry| entry.validators);
+        cache_puzzle(provider, &date, Some(&date), previous)
+            .await
+            .map(|_| ())
+    })
+    .await
+}
+
+/// Convert many puzzle files into `dir`, as `{file stem}.json`.
+async fn convert_many(dir: &str, inputs: &[&str]) -> color_eyre::Result<()> {
+    let dir = PathBuf::from(dir);
+    std::fs::create_dir_all(&dir)?;
+
+    let name = dir
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let job_id = format!("convert_{}", name);
+    let job = bulk::BulkJob::open(&job_id, inputs.iter().map(|s| s.to_string()).collect())?;
+
+    run_bulk(job, |input| {
+        let dir = dir.clone();
+        async move {
+            let input = PathBuf::from(input);
+            let mut puzzle = formats::load_file(&input).map_err(|e| e.to_string())?;
+            Source::local(&input, &puzzle).annotate(&mut puzzle);
+            let stem = input.file_stem().unwrap_or_default();
+            let output = dir.join(stem).with_extension("json");
+            formats::save_file(&output, &puzzle).map_err(|e| e.to_string())
+        }
+    })
+    .await
+}
+
+/// Convert a puzzle file to another format, or render it to an SVG image.
+fn convert(input: &str, output: &str, options: &[&str]) -> color_eyre::Result<()> {
+    let mut show_solution = false;
+    let mut enumerations = false;
+    for option in options {
+        match *option {
+            "--solution" => show_solution = true,
+            "--enumerations" => enumerations = true,
+            _ => {
+                eprintln!("{}", USAGE);
+                std::process::exit(2);
+            }
+        }
+    }
+
+    let mut puzzle = load_puzzle(Path::new(input))?;
+    Source::local(Path::new(input), &puzzle).annotate(&mut puzzle);
+    if enumerations {
+        cruciverbal_providers::entries::append_enumerations(&mut puzzle);
+    }
+    let output_path = Path::new(output);
+    if output_path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("svg"))
+    {
+        std::fs::write(output_path, formats::svg::render(&puzzle, show_solution))?;
+    } else if show_solution {
+        return Err(color_eyre::eyre::eyre!(
+            "--solution only applies to .svg output"
+        ));
+    } else {
+        formats::save_file(output_path, &puzzle).map_err(|e| color_eyre::eyre::eyre!("{}", e))?;
+    }
+    println!("Converted {} to {}", input, output);
+    Ok(())
+}
+
+/// Evict cached puzzles until the cache fits in `max_bytes`.
+fn prune_cache(max_bytes: u64) -> color_eyre::Result<()> {
+    let report = cache::prune(max_bytes)?;
+    println!(
+        "Removed {} cached puzzle(s), freed {} KB, {} KB remaining",
+        report.removed,
+        report.freed_bytes / 1024,
+        report.remaining_bytes / 1024
+    );
+    Ok(())
+}
+
+/// Print the solution grid of a saved game, colored by the saved fill: green
+/// letters were entered correctly, red ones wrongly, and dim ones not at all.
+fn print_solution(path: &str) -> color_eyre::Result<()> {
+    use crossterm::style::Stylize;
+
+    let game_save = save::load_game(Path::new(path))?;
+    let accept_unaccented = cruciverbal::preferences::load_preferences().accept_unaccented;
+    let (mut correct, mut wrong, mut empty) = (0, 0, 0);
+    for (row, solution_row) in game_save.puzzle.grid.solution.iter().enumerate() {
+        let mut line = Vec::new();
+        for (col, solution) in solution_row.chars().enumerate() {
+            if solution == '.' {
+                line.push("█".dark_grey().to_string());
+                continue;
+            }
+            let user_letter = game_save
+                .user_letters
+                .get(row)
+                .and_then(|letters| letters.get(col))
+                .copied()
+                .flatten();
+            let letter = match user_letter {
+                Some(letter)
+                    if cruciverbal_providers::entries::letters_match(
+                        letter,
+                        solution,
+                        accept_unaccented,
+                    ) =>
+                {
+                    correct += 1;
+                    solution.green()
+                }
+                Some(_) => {
+                    wrong += 1;
+                    solution.red()
+                }
+                None => {
+                    empty += 1;
+                    solution.dim()
+                }
+            };
+            line.push(letter.to_string());
+        }
+        println!("{}", line.join(" "));
+    }
+    println!();
+    for entry in cruciverbal_providers::entries::entries(&game_save.puzzle) {
+        println!(
+            "{:>3} {:<6} {}",
+            entry.number,
+            entry.direction.name(),
+            entry.display_answer()
+        );
+    }
+    println!();
+    println!(
+        "{} correct, {} wrong, {} empty",
+        correct.to_string().green(),
+        wrong.to_string().red(),
+        empty.to_string().dim()
+    );
+    Ok(())
+}
+
+/// Download the published solution of a saved Guardian puzzle that came without
+/// one, write it into the save and print the re-scored fill.
+async fn fetch_solution(path: &str) -> color_eyre::Result<()> {
+    use cruciverbal_providers::{entries, guardian};
+
+    let mut game_save = save::load_game(Path::new(path))?;
+    if entries::has_solution(&game_save.puzzle) {
+        println!("The save already has its solution");
+        return print_solution(path);
+    }
+
+    let variant = PuzzleProvider::ALL
+        .iter()
+        .find(|provider| provider.name() == game_save.provider_name)
+        .and_then(|provider| provider.guardian_variant())
+        .ok_or_else(|| color_eyre::eyre::eyre!("only Guardian solutions can be downloaded"))?;
+    let number = guardian::puzzle_number(&game_save.puzzle.info.title).ok_or_else(|| {
+        color_eyre::eyre::eyre!("no puzzle number in \"{}\"", game_save.puzzle.info.title)
+    })?;
+    let solved = guardian::download_solution(variant, number)
+        .await
+        .map_err(|e| color_eyre::eyre::eyre!("{}", e))?;
+    let accept_unaccented = cruciverbal::preferences::load_preferences().accept_unaccented;
+    let filled = game_save.backfill_solution(&solved, accept_unaccented)?;
+    game_save.saved_at = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    save::write_save(Path::new(path), &game_save)?;
+    println!("Filled in {} letters of the solution", filled);
+    println!();
+    print_solution(path)
+}
+
+/// Check a puzzle file and print its issues.
+///
+/// Exits with a non-zero status if there are errors (or warnings, with `--strict`),
+/// so it can be used in pre-publish scripts.
+fn lint(path: &str, options: &[&str]) -> color_eyre::Result<()> {
+    use cruciverbal_providers::lint::{self, Blocklist, Severity};
+
+    let mut strict = false;
+    let mut blocklist = None;
+    let mut options = options.iter();
+    while let Some(option) = options.next() {
+        match *option {
+            "--strict" => strict = true,
+            "--blocklist" => {
+                let file = options
+                    .next()
+                    .ok_or_else(|| color_eyre::eyre::eyre!("--blocklist needs a file"))?;
+                blocklist = Some(Blocklist::load(Path::new(file))?);
+            }
+            other => return Err(color_eyre::eyre::eyre!("unknown lint option: {}", other)),
+        }
+    }
+
+    let puzzle = load_puzzle(Path::new(path))?;
+    let mut issues = lint::lint(&puzzle);
+    if let Some(blocklist) = &blocklist {
+        issues.extend(lint::check_blocklist(&puzzle, blocklist));
+        issues.sort_by_key(|issue| std::cmp::Reverse(issue.severity));
+    }
+    for issue in &issues {
+        println!("{}", issue);
+    }
+
+    let count = |severity| issues.iter().filter(|i| i.severity == severity).count();
+    let (errors, warnings) = (count(Severity::Error), count(Severity::Warning));
+    println!(
+        "{}: {} error(s), {} warning(s), {} note(s)",
+        path,
+        errors,
+        warnings,
+        count(Severity::Note)
+    );
+    if errors > 0 || (strict && warnings > 0) {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// Print a warning if today's daily puzzle is unsolved and the streak is at risk.
+///
+/// Exits with a non-zero status when a reminder is due, so it can be run from a scheduler.
+fn remind() -> color_eyre::Result<()> {
+    if let Some(reminder) = stats::check_reminder() {
+        println!("{}", reminder.message());
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// Sync saves and stats with the configured remote.
+async fn run_sync() -> color_eyre::Result<()> {
+    let prefs = cruciverbal::preferences::load_preferences();
+    let report = sync::sync(&prefs.sync).await?;
+    println!(
+        "pulled {} save(s), pushed {} save(s), added {} solve(s)",
+        report.pulled, report.pushed, report.solves_added
+    );
+    Ok(())
+}
+
+/// Print the last `count` download attempts, oldest first.
+fn print_fetch_log(count: usize) -> color_eyre::Result<()> {
+    let log = fetch_log::load_fetch_log();
+    for record in &log.recent[log.recent.len().saturating_sub(count)..] {
+        println!("{}", record);
+    }
+    Ok(())
+}
+
+/// Write all application state to a backup archive.
+fn export_backup(file: &str) -> color_eyre::Result<()> {
+    let backup = backup::export(std::path::Path::new(file))?;
+    println!("Exported to {}", file);
+    for (part, count) in backup.counts() {
+        println!("  {}: {} file(s)", part.name(), count);
+    }
+    Ok(())
+}
+
+/// Restore the given parts of a backup archive.
+fn import_backup(file: &str, parts: &[backup::BackupPart]) -> color_eyre::Result<()> {
+    let backup = backup::read(std::path::Path::new(file))?;
+    let restored = backup::restore(&backup, parts)?;
+    println!(
+        "Restored {} file(s) from a backup made at {}",
+        restored, backup.created_at
+    );
+    Ok(())
+}
@@ -0,0 +1,262 @@
+//! Where files are stored.
+//!
+//! Preferences go in the platform config directory and everything else (saves,
+//! stats, the cache, ...) in the platform data directory, both in a
+//! `cruciverbal` subdirectory:
+//!
+//! | Platform | Config                                | Data                                    |
+//! | -------- | ------------------------------------- | --------------------------------------- |
+//! | Linux    | `$XDG_CONFIG_HOME` or `~/.config`     | `$XDG_DATA_HOME` or `~/.local/share`    |
+//! | macOS    | `~/Library/Application Support`       | `~/Library/Application Support`         |
+//! | Windows  | `%APPDATA%`                           | `%APPDATA%`                             |
+//!
+//! Setting `CRUCIVERBAL_HOME` keeps both in that one directory instead. Files in
+//! the legacy `~/.cruciverbal/` are moved to the new locations the first time
+//! they are looked up, merging directories that exist in both places and keeping
+//! the newer of two copies of a file. If a file and a directory of the same name
+//! are in the way, nothing is moved and the legacy directory stays in use.
+
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Environment variable overriding both directories.
+pub const HOME_ENV: &str = "CRUCIVERBAL_HOME";
+
+/// File kept in the config directory; everything else is data.
+const CONFIG_FILE: &str = "preferences.json";
+
+/// The config and data directories.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Roots {
+    config: PathBuf,
+    data: PathBuf,
+}
+
+/// Directory for the preferences.
+pub fn config_dir() -> Option<PathBuf> {
+    roots().map(|roots| roots.config)
+}
+
+/// Directory for saves, stats, the cache and other data.
+pub fn data_dir() -> Option<PathBuf> {
+    roots().map(|roots| roots.data)
+}
+
+/// The platform directories, resolved (and migrated to) once per process.
+static PLATFORM_ROOTS: OnceLock<Option<Roots>> = OnceLock::new();
+
+/// Find the directories: `CRUCIVERBAL_HOME` if set, otherwise the platform ones.
+fn roots() -> Option<Roots> {
+    if let Some(home) = std::env::var_os(HOME_ENV).filter(|home| !home.is_empty()) {
+        let home = PathBuf::from(home);
+        return Some(Roots {
+            config: home.clone(),
+            data: home,
+        });
+    }
+    PLATFORM_ROOTS.get_or_init(platform_roots).clone()
+}
+
+/// The platform directories, moving files over from the legacy directory first.
+fn platform_roots() -> Option<Roots> {
+    let legacy = dirs::home_dir()?.join(".cruciverbal");
+    let roots = Roots {
+        config: dirs::config_dir()?.join("cruciverbal"),
+        data: dirs::data_dir()?.join("cruciverbal"),
+    };
+    Some(resolve(&legacy, roots))
+}
+
+/// Migrate `legacy` into `roots`.
+///
+/// The legacy directory stays in use only if it can't be migrated without
+/// conflicts, checked before anything is moved. Once files start moving the new
+/// directories are used, even if some files are left behind by an error, so
+/// moved files never drop out of view.
+fn resolve(legacy: &Path, roots: Roots) -> Roots {
+    if !legacy.exists() {
+        return roots;
+    }
+    if !matches!(conflicts(legacy, &roots), Ok(conflicts) if conflicts.is_empty()) {
+        return Roots {
+            config: legacy.to_path_buf(),
+            data: legacy.to_path_buf(),
+        };
+    }
+    let _ = migrate(legacy, &roots);
+    roots
+}
+
+/// Where an entry of the legacy directory goes.
+fn target_dir<'a>(roots: &'a Roots, name: &std::ffi::OsStr) -> &'a Path {
+    if name == CONFIG_FILE {
+        &roots.config
+    } else {
+        &roots.data
+    }
+}
+
+/// Paths in the legacy directory that can't be merged into the new directories,
+/// because one side has a file and the other a directory of the same name.
+fn conflicts(legacy: &Path, roots: &Roots) -> std::io::Result<Vec<PathBuf>> {
+    fn walk(source: &Path, target: &Path, found: &mut Vec<PathBuf>) -> std::io::Result<()> {
+        if !target.exists() {
+            return Ok(());
+        }
+        match (source.is_dir(), target.is_dir()) {
+            (true, true) => {
+                for entry in std::fs::read_dir(source)? {
+                    let entry = entry?;
+                    walk(&entry.path(), &target.join(entry.file_name()), found)?;
+                }
+            }
+            (false, false) => {}
+            _ => found.push(source.to_path_buf()),
+        }
+        Ok(())
+    }
+
+    let mut found = Vec::new();
+    for entry in std::fs::read_dir(legacy)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        walk(
+            &entry.path(),
+            &target_dir(roots, &name).join(&name),
+            &mut found,
+        )?;
+    }
+    Ok(found)
+}
+
+/// Move the legacy directory's files to the new directories, removing it once
+/// it is empty.
+fn migrate(legacy: &Path, roots: &Roots) -> std::io::Result<()> {
+    for entry in std::fs::read_dir(legacy)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        let dir = target_dir(roots, &name);
+        std::fs::create_dir_all(dir)?;
+        merge(&entry.path(), &dir.join(&name))?;
+    }
+    remove_if_empty(legacy)
+}
+
+/// Move `source` to `target`, merging directories entry by entry. Of two copies of
+/// a file the newer one is kept. A file and a directory of the same name are an
+/// error (see [`conflicts`]).
+fn merge(source: &Path, target: &Path) -> std::io::Result<()> {
+    if !target.exists() {
+        return std::fs::rename(source, target);
+    }
+
+    match (source.is_dir(), target.is_dir()) {
+        (true, true) => {
+            for entry in std::fs::read_dir(source)? {
+                let entry = entry?;
+                merge(&entry.path(), &target.join(entry.file_name()))?;
+            }
+            remove_if_empty(source)
+        }
+        (false, false) => {
+            let modified = |path: &Path| std::fs::metadata(path).and_then(|m| m.modified());
+            if modified(source)? > modified(target)? {
+                std::fs::rename(source, target)
+            } else {
+                std::fs::remove_file(source)
+            }
+        }
+        _ => Err(std::io::Error::new(
+            std::io::ErrorKind::AlreadyExists,
+            format!("{} is in the way of {}", target.display(), source.display()),
+        )),
+    }
+}
+
+/// Remove `dir` if nothing is left in it.
+fn remove_if_empty(dir: &Path) -> std::io::Result<()> {
+    if std::fs::read_dir(dir)?.next().is_none() {
+        std::fs::remove_dir(dir)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_migrate() {
+        let dir = std::env::temp_dir().join(format!("cruciverbal-paths-{}", std::process::id()));
+        let legacy = dir.join(".cruciverbal");
+        let roots = Roots {
+            config: dir.join("config"),
+            data: dir.join("data"),
+        };
+        std::fs::create_dir_all(legacy.join("saves")).unwrap();
+        std::fs::write(legacy.join("saves").join("a.json"), "{}").unwrap();
+        std::fs::write(legacy.join(CONFIG_FILE), "{}").unwrap();
+        std::fs::write(legacy.join("stats.json"), "{}").unwrap();
+
+        migrate(&legacy, &roots).unwrap();
+        assert!(roots.config.join(CONFIG_FILE).exists());
+        assert!(roots.data.join("saves").join("a.json").exists());
+        assert!(roots.data.join("stats.json").exists());
+        assert!(!legacy.exists());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_migrate_into_existing() {
+        let dir =
+            std::env::temp_dir().join(format!("cruciverbal-paths-existing-{}", std::process::id()));
+        let legacy = dir.join(".cruciverbal");
+        let roots = Roots {
+            config: dir.join("config"),
+            data: dir.join("data"),
+        };
+        let age = |path: &Path, secs| {
+            let time = std::time::SystemTime::now() - std::time::Duration::from_secs(secs);
+            std::fs::File::options()
+                .append(true)
+                .open(path)
+                .unwrap()
+                .set_modified(time)
+                .unwrap();
+        };
+
+        // saves/ exists in both places, with one file in common
+        let (old_saves, new_saves) = (legacy.join("saves"), roots.data.join("saves"));
+        std::fs::create_dir_all(&old_saves).unwrap();
+        std::fs::create_dir_all(&new_saves).unwrap();
+        std::fs::write(old_saves.join("a.json"), "legacy only").unwrap();
+        std::fs::write(old_saves.join("b.json"), "legacy, newer").unwrap();
+        std::fs::write(new_saves.join("b.json"), "new, older").unwrap();
+        age(&new_saves.join("b.json"), 60);
+        std::fs::write(legacy.join("stats.json"), "legacy, older").unwrap();
+        age(&legacy.join("stats.json"), 60);
+        std::fs::write(roots.data.join("stats.json"), "new, newer").unwrap();
+
+        assert_eq!(resolve(&legacy, roots.clone()), roots);
+        let read = |path: PathBuf| std::fs::read_to_string(path).unwrap();
+        assert_eq!(read(new_saves.join("a.json")), "legacy only");
+        assert_eq!(read(new_saves.join("b.json")), "legacy, newer");
+        assert_eq!(read(roots.data.join("stats.json")), "new, newer");
+        assert!(!legacy.exists());
+
+        // a conflict anywhere keeps the legacy directory in use, with nothing moved
+        std::fs::create_dir_all(legacy.join("cache")).unwrap();
+        std::fs::write(roots.data.join("cache"), "").unwrap();
+        std::fs::write(legacy.join(CONFIG_FILE), "legacy").unwrap();
+        std::fs::write(legacy.join("stats.json"), "legacy").unwrap();
+        assert_eq!(conflicts(&legacy, &roots).unwrap(), [legacy.join("cache")]);
+        assert_eq!(resolve(&legacy, roots.clone()).data, legacy);
+        assert_eq!(read(legacy.join(CONFIG_FILE)), "legacy");
+        assert_eq!(read(legacy.join("stats.json")), "legacy");
+        assert!(!roots.config.join(CONFIG_FILE).exists());
+        assert!(merge(&legacy.join("cache"), &roots.data.join("cache")).is_err());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
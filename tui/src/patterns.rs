@@ -0,0 +1,230 @@
+//! Answer patterns for external solver tools.
+//!
+//! The unsolved words of a game are exported to `shared/` in the data directory
+//! as JSON and CSV, one row per word with its pattern (`?` for unknown letters)
+//! and clue. Answers filled into the `answer` column of either file can be
+//! imported back into the game.
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use thiserror::Error;
+
+/// Marks an unknown letter in a pattern.
+pub const UNKNOWN: char = '?';
+
+/// CSV column names, in order.
+const CSV_HEADER: [&str; 5] = ["number", "direction", "pattern", "clue", "answer"];
+
+/// Error type for pattern export and import.
+#[derive(Error, Debug)]
+pub enum PatternsError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("JSON error: {0}")]
+    Serde(#[from] serde_json::Error),
+    #[error("CSV error on line {0}: {1}")]
+    Csv(usize, String),
+    #[error("Could not determine home directory")]
+    NoHomeDir,
+}
+
+/// An unsolved word of the puzzle.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SlotPattern {
+    /// Clue number.
+    pub number: usize,
+    /// "Across" or "Down".
+    pub direction: String,
+    /// Entered letters, with [`UNKNOWN`] for empty cells (e.g. "?A??E").
+    pub pattern: String,
+    pub clue: String,
+    /// Answer to apply on import (empty when exported).
+    #[serde(default)]
+    pub answer: String,
+}
+
+/// Paths of the JSON and CSV pattern files for a game.
+pub fn pattern_paths(date: &str, provider_name: &str) -> Result<(PathBuf, PathBuf), PatternsError> {
+    let dir = crate::share::shared_dir().map_err(|_| PatternsError::NoHomeDir)?;
+    let stem = crate::save::generate_filename(date, provider_name);
+    let stem = stem.trim_end_matches(".json");
+    Ok((
+        dir.join(format!("{}.patterns.json", stem)),
+        dir.join(format!("{}.patterns.csv", stem)),
+    ))
+}
+
+/// Write the patterns as JSON and CSV, returning the JSON path.
+pub fn export_patterns(
+    date: &str,
+    provider_name: &str,
+    slots: &[SlotPattern],
+) -> Result<PathBuf, PatternsError> {
+    let (json_path, csv_path) = pattern_paths(date, provider_name)?;
+    if let Some(parent) = json_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&json_path, serde_json::to_string_pretty(slots)?)?;
+    std::fs::write(&csv_path, to_csv(slots))?;
+    Ok(json_path)
+}
+
+/// Read back the most recently modified pattern file of a game, returning its
+/// path and rows.
+pub fn import_patterns(
+    date: &str,
+    provider_name: &str,
+) -> Result<(PathBuf, Vec<SlotPattern>), PatternsError> {
+    let (json_path, csv_path) = pattern_paths(date, provider_name)?;
+    let modified = |path: &PathBuf| std::fs::metadata(path).and_then(|m| m.modified()).ok();
+
+    let use_csv = match (modified(&json_path), modified(&csv_path)) {
+        (Some(json), Some(csv)) => csv > json,
+        (None, Some(_)) => true,
+        _ => false,
+    };
+    if use_csv {
+        let slots = from_csv(&std::fs::read_to_string(&csv_path)?)?;
+        Ok((csv_path, slots))
+    } else {
+        let slots = serde_json::from_str(&std::fs::read_to_string(&json_path)?)?;
+        Ok((json_path, slots))
+    }
+}
+
+/// Quote a CSV field if it contains separators, quotes or line breaks.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Format patterns as CSV with a header row.
+pub fn to_csv(slots: &[SlotPattern]) -> String {
+    let mut csv = CSV_HEADER.join(",");
+    csv.push('\n');
+    for slot in slots {
+        let fields = [
+            slot.number.to_string(),
+            slot.direction.clone(),
+            slot.pattern.clone(),
+            slot.clue.clone(),
+            slot.answer.clone(),
+        ];
+        let fields: Vec<String> = fields.iter().map(|f| csv_field(f)).collect();
+        csv.push_str(&fields.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+/// Split CSV text into records of fields, handling quoted fields.
+fn csv_records(text: &str) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => record.push(std::mem::take(&mut field)),
+            '\r' if !quoted => {}
+            '\n' if !quoted => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            _ => field.push(c),
+        }
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+    records
+}
+
+/// Parse patterns from CSV, finding the columns by the header row.
+///
+/// The `answer` column may be missing, so tools can send back fewer columns.
+pub fn from_csv(text: &str) -> Result<Vec<SlotPattern>, PatternsError> {
+    let mut records = csv_records(text).into_iter();
+    let header = records.next().unwrap_or_default();
+    let column = |name: &str| {
+        header
+            .iter()
+            .position(|h| h.trim().eq_ignore_ascii_case(name))
+    };
+    let (Some(number), Some(direction)) = (column("number"), column("direction")) else {
+        return Err(PatternsError::Csv(
+            1,
+            "header needs number and direction columns".to_string(),
+        ));
+    };
+    let (pattern, clue, answer) = (column("pattern"), column("clue"), column("answer"));
+
+    let mut slots = Vec::new();
+    for (i, record) in records.enumerate() {
+        let line = i + 2;
+        if record.iter().all(|f| f.trim().is_empty()) {
+            continue;
+        }
+        let get = |idx: Option<usize>| {
+            idx.and_then(|idx| record.get(idx))
+                .map(|f| f.trim().to_string())
+                .unwrap_or_default()
+        };
+        let number = get(Some(number))
+            .parse()
+            .map_err(|_| PatternsError::Csv(line, "invalid clue number".to_string()))?;
+        slots.push(SlotPattern {
+            number,
+            direction: get(Some(direction)),
+            pattern: get(pattern),
+            clue: get(clue),
+            answer: get(answer),
+        });
+    }
+    Ok(slots)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_round_trip() {
+        let slots = vec![
+            SlotPattern {
+                number: 1,
+                direction: "Across".to_string(),
+                pattern: "?A??E".to_string(),
+                clue: "Say \"cheese\", perhaps (5)".to_string(),
+                answer: String::new(),
+            },
+            SlotPattern {
+                number: 12,
+                direction: "Down".to_string(),
+                pattern: "???".to_string(),
+                clue: "Feline".to_string(),
+                answer: "cat".to_string(),
+            },
+        ];
+        assert_eq!(from_csv(&to_csv(&slots)).unwrap(), slots);
+
+        // columns are found by name, and the answer column is optional
+        let csv = "direction,number\r\nAcross,3\r\n\r\n";
+        let slots = from_csv(csv).unwrap();
+        assert_eq!(slots.len(), 1);
+        assert_eq!((slots[0].number, slots[0].answer.as_str()), (3, ""));
+
+        assert!(from_csv("number,direction\nx,Down\n").is_err());
+    }
+}
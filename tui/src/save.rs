@@ -1,12 +1,31 @@
 //! Save/Load game functionality.
 //!
-//! Explicit saves go to `~/.cruciverbal/saves/`, auto-saves go to `~/.cruciverbal/autosaves/`.
+//! Explicit saves go to `saves/` in the data directory (see [`crate::paths`]) or
+//! the configured library directory, auto-saves go to `autosaves/`.
+//!
+//! Saves are written gzip-compressed behind [`COMPRESSED_MAGIC`] and a CRC-32 of
+//! the JSON, which is checked on load. Plain JSON saves from older versions are
+//! still read.
 
-use crate::views::game::{CompletionState, Direction};
+use crate::views::game::{CompletionState, Direction, ReplayEvent, unchecked_reason};
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
 use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
+use std::sync::{Mutex, PoisonError, TryLockError};
 use thiserror::Error;
 
+/// Current save file version.
+///
+/// Older saves are upgraded when loaded, one version at a time (see [`MIGRATIONS`]).
+pub const SAVE_VERSION: u8 = 2;
+
+/// Start of a compressed save file, followed by the CRC-32 of the JSON (little
+/// endian) and the gzip-compressed JSON.
+pub const COMPRESSED_MAGIC: &[u8; 8] = b"CRUCSAV\x01";
+
 /// Error type for save/load operations.
 #[derive(Error, Debug)]
 pub enum SaveError {
@@ -18,6 +37,8 @@ pub enum SaveError {
     NoHomeDir,
     #[error("Invalid save file: {0}")]
     InvalidSave(String),
+    #[error("Invalid save name: {0}")]
+    InvalidName(String),
 }
 
 /// Serializable game save data.
@@ -49,22 +70,39 @@ pub struct GameSave {
     /// Timestamp when saved (Unix epoch seconds).
     #[serde(default)]
     pub saved_at: u64,
+    /// Where the puzzle came from, if known.
+    #[serde(default)]
+    pub source: Option<cruciverbal_providers::Source>,
+    /// Target time in seconds, if one was set.
+    #[serde(default)]
+    pub target_secs: Option<u64>,
+    /// Letter changes with their solve times, for the replay viewer.
+    #[serde(default)]
+    pub replay: Vec<ReplayEvent>,
+    /// Checks and reveals used so far.
+    #[serde(default)]
+    pub assists: crate::stats::Assists,
 }
 
-/// Get the saves directory path (`~/.cruciverbal/saves/`).
+/// Get the saves directory path (`saves/` in the data directory).
+///
+/// Can be overridden with the `library_dir` preference.
 pub fn saves_dir() -> Result<PathBuf, SaveError> {
-    let home = dirs::home_dir().ok_or(SaveError::NoHomeDir)?;
-    Ok(home.join(".cruciverbal").join("saves"))
+    if let Some(dir) = crate::preferences::load_preferences().library_dir {
+        return Ok(dir);
+    }
+    let dir = crate::paths::data_dir().ok_or(SaveError::NoHomeDir)?;
+    Ok(dir.join("saves"))
 }
 
-/// Get the auto-saves directory path (`~/.cruciverbal/autosaves/`).
+/// Get the auto-saves directory path (`autosaves/` in the data directory).
 pub fn autosaves_dir() -> Result<PathBuf, SaveError> {
-    let home = dirs::home_dir().ok_or(SaveError::NoHomeDir)?;
-    Ok(home.join(".cruciverbal").join("autosaves"))
+    let dir = crate::paths::data_dir().ok_or(SaveError::NoHomeDir)?;
+    Ok(dir.join("autosaves"))
 }
 
 /// Generate a filename for a save: `{date}_{provider-slug}.json`.
-fn generate_filename(date: &str, provider_name: &str) -> String {
+pub(crate) fn generate_filename(date: &str, provider_name: &str) -> String {
     let slug = provider_name
         .to_lowercase()
         .replace(' ', "-")
@@ -75,7 +113,7 @@ fn generate_filename(date: &str, provider_name: &str) -> String {
 /// Save a game to disk.
 ///
 /// Returns the path where the save was written.
-/// Explicit saves go to `~/.cruciverbal/saves/`, auto-saves go to `~/.cruciverbal/autosaves/`.
+/// Explicit saves go to `saves/` in the data directory, auto-saves go to `autosaves/`.
 pub fn save_game(save: &GameSave) -> Result<PathBuf, SaveError> {
     let dir = if save.is_auto_save {
         autosaves_dir()?
@@ -86,40 +124,419 @@ pub fn save_game(save: &GameSave) -> Result<PathBuf, SaveError> {
 
     let filename = generate_filename(&save.puzzle_date, &save.provider_name);
     let path = dir.join(filename);
-
-    let json = serde_json::to_string_pretty(save)?;
-    std::fs::write(&path, json)?;
+    write_save(&path, save)?;
 
     Ok(path)
 }
 
-/// Load a game from a save file.
+/// Game to auto-save if the app panics, kept up to date by the main loop.
+static CRASH_SAVE: Mutex<Option<GameSave>> = Mutex::new(None);
+
+/// Set the game to auto-save if the app panics, or clear it with `None`.
+pub fn set_crash_save(save: Option<GameSave>) {
+    *CRASH_SAVE.lock().unwrap_or_else(PoisonError::into_inner) = save;
+}
+
+/// Auto-save the game set with [`set_crash_save`], called from the panic hook.
+///
+/// The hook runs before unwinding, so this works for panics in spawned tasks
+/// and with `panic = "abort"`, where the app is never dropped.
+pub fn save_on_crash() {
+    let mut slot = match CRASH_SAVE.try_lock() {
+        Ok(slot) => slot,
+        Err(TryLockError::Poisoned(err)) => err.into_inner(),
+        // the panic happened while setting the game
+        Err(TryLockError::WouldBlock) => return,
+    };
+    if let Some(save) = slot.take() {
+        let _ = save_game(&save);
+    }
+}
+
+/// Write a save file, compressed with its checksum.
+///
+/// The save is written to a temporary file next to it first and then renamed
+/// over it, so a crash or full disk never leaves a half-written save behind.
+pub fn write_save(path: &Path, save: &GameSave) -> Result<(), SaveError> {
+    let json = serde_json::to_vec(save)?;
+    let mut crc = flate2::Crc::new();
+    crc.update(&json);
+
+    let mut contents = COMPRESSED_MAGIC.to_vec();
+    contents.extend_from_slice(&crc.sum().to_le_bytes());
+    let mut encoder = GzEncoder::new(contents, Compression::default());
+    encoder.write_all(&json)?;
+    let contents = encoder.finish()?;
+
+    let mut tmp_name = path.file_name().unwrap_or_default().to_os_string();
+    tmp_name.push(".tmp");
+    let tmp = path.with_file_name(tmp_name);
+    let written = std::fs::write(&tmp, contents).and_then(|_| std::fs::rename(&tmp, path));
+    if written.is_err() {
+        let _ = std::fs::remove_file(&tmp);
+    }
+    written?;
+    Ok(())
+}
+
+/// Read the JSON of a save file, decompressing it and checking its checksum if
+/// it is compressed. Other JSON files are read as they are.
+pub fn read_save_json(path: &Path) -> Result<String, SaveError> {
+    let bytes = std::fs::read(path)?;
+    let Some(rest) = bytes.strip_prefix(COMPRESSED_MAGIC) else {
+        return String::from_utf8(bytes)
+            .map_err(|_| SaveError::InvalidSave("not a save file".to_string()));
+    };
+
+    let corrupted = || SaveError::InvalidSave("the file is corrupted".to_string());
+    let (checksum, compressed) = rest.split_first_chunk::<4>().ok_or_else(corrupted)?;
+    let mut json = String::new();
+    GzDecoder::new(compressed)
+        .read_to_string(&mut json)
+        .map_err(|_| corrupted())?;
+
+    let mut crc = flate2::Crc::new();
+    crc.update(json.as_bytes());
+    if crc.sum() != u32::from_le_bytes(*checksum) {
+        return Err(SaveError::InvalidSave(
+            "checksum mismatch, the file is corrupted".to_string(),
+        ));
+    }
+    Ok(json)
+}
+
+/// Load a game from a save file, upgrading it if it is from an older version.
 pub fn load_game(path: &Path) -> Result<GameSave, SaveError> {
-    let contents = std::fs::read_to_string(path)?;
-    let save: GameSave = serde_json::from_str(&contents)?;
+    let contents = read_save_json(path)?;
+    let mut value: serde_json::Value = serde_json::from_str(&contents)?;
 
     // Version check for future compatibility
-    if save.version != 1 {
+    let version = value
+        .get("version")
+        .and_then(|v| v.as_u64())
+        .and_then(|v| u8::try_from(v).ok())
+        .unwrap_or(0);
+    if version == 0 || version > SAVE_VERSION {
         return Err(SaveError::InvalidSave(format!(
             "Unsupported save version: {}",
-            save.version
+            version
         )));
     }
 
+    let context = MigrationContext { path };
+    for migration in MIGRATIONS.iter().filter(|m| m.to > version) {
+        (migration.migrate)(&mut value, &context)?;
+        value["version"] = migration.to.into();
+    }
+    let mut save: GameSave = serde_json::from_value(value)?;
+
+    // A missing or ragged solution grid can't be played, so reject it here
+    // rather than panic while building the grid
+    let width = save
+        .puzzle
+        .grid
+        .solution
+        .first()
+        .map(|row| row.chars().count());
+    if width.is_none_or(|width| {
+        width == 0
+            || save
+                .puzzle
+                .grid
+                .solution
+                .iter()
+                .any(|row| row.chars().count() != width)
+    }) {
+        return Err(SaveError::InvalidSave(
+            "puzzle solution grid is empty or not rectangular".to_string(),
+        ));
+    }
+
+    if version < SAVE_VERSION {
+        // The upgraded save is still usable if it can't be written back
+        let _ = upgrade(path, &contents, version, &mut save);
+    }
+
     Ok(save)
 }
 
+/// What a [`Migration`] can look at besides the save itself.
+struct MigrationContext<'a> {
+    /// Path of the save file being upgraded.
+    path: &'a Path,
+}
+
+/// One step of upgrading a save, from version `to - 1` to `to`.
+///
+/// Migrations work on the JSON before it is read into a [`GameSave`], so fields
+/// can be renamed, restructured or filled in before they are required.
+struct Migration {
+    to: u8,
+    migrate: fn(&mut serde_json::Value, &MigrationContext) -> Result<(), SaveError>,
+}
+
+/// All migrations in version order, ending at [`SAVE_VERSION`].
+const MIGRATIONS: [Migration; 1] = [Migration {
+    to: 2,
+    migrate: migrate_v2_saved_at,
+}];
+
+/// `saved_at` was added later and is 0 (or missing) in early saves, so their
+/// auto-saves were never cleaned up; it is set from the file's modification time.
+fn migrate_v2_saved_at(
+    value: &mut serde_json::Value,
+    context: &MigrationContext,
+) -> Result<(), SaveError> {
+    if value.get("saved_at").and_then(|v| v.as_u64()).unwrap_or(0) == 0 {
+        let saved_at = std::fs::metadata(context.path)?
+            .modified()?
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        value["saved_at"] = saved_at.into();
+    }
+    Ok(())
+}
+
+/// Rewrite a save that was upgraded by the [`MIGRATIONS`] from `version`.
+///
+/// The original file is first copied to `{name}.json.v{version}.bak`, once, so
+/// a bad upgrade can be undone by hand. The game state is also checked against
+/// the puzzle ([`GameSave::repair`]), as older versions didn't always keep them
+/// in step. Saves are upgraded wherever they are read, without the preferences
+/// at hand, so letters are checked with the default `accept_unaccented`; the
+/// completion state is checked again with the player's when the game is restored.
+fn upgrade(path: &Path, contents: &str, version: u8, save: &mut GameSave) -> Result<(), SaveError> {
+    let backup = path.with_extension(format!("json.v{}.bak", version));
+    if !backup.exists() {
+        std::fs::write(&backup, contents)?;
+    }
+
+    save.repair(crate::preferences::Preferences::default().accept_unaccented);
+    write_save(path, save)
+}
+
+impl GameSave {
+    /// Fill in the published solution of a puzzle that came without one, from
+    /// `solved`, and re-score the letters against it (see [`GameSave::repair`]).
+    /// Returns how many letters of the solution were filled in.
+    pub fn backfill_solution(
+        &mut self,
+        solved: &puz_parse::Puzzle,
+        accept_unaccented: bool,
+    ) -> Result<usize, SaveError> {
+        let filled = cruciverbal_providers::entries::merge_solution(&mut self.puzzle, solved)
+            .map_err(|e| SaveError::InvalidSave(e.to_string()))?;
+        self.puzzle.info.notes = solved.info.notes.clone();
+        self.repair(accept_unaccented);
+        Ok(filled)
+    }
+
+    /// Fix game state that doesn't match the puzzle, so the game can be played.
+    ///
+    /// The letter grid is resized to the puzzle, letters on black squares are
+    /// dropped, the selection is moved to a letter cell if it isn't on one, and the
+    /// completion state is recomputed from the letters (by fill only if the puzzle
+    /// has no solution to check against, and with `accept_unaccented` as in the
+    /// preference of that name). Returns a description of
+    /// each repair (empty if the save was consistent).
+    pub fn repair(&mut self, accept_unaccented: bool) -> Vec<String> {
+        let solution: Vec<Vec<char>> = self
+            .puzzle
+            .grid
+            .solution
+            .iter()
+            .map(|row| row.chars().collect())
+            .collect();
+        let height = solution.len();
+        let width = solution.first().map_or(0, Vec::len);
+        let is_letter =
+            |row: usize, col: usize| solution.get(row).and_then(|r| r.get(col)) != Some(&'.');
+        let mut repairs = Vec::new();
+
+        // Letter grid dimensions
+        let letters_height = self.user_letters.len();
+        let letters_width = self.user_letters.iter().map(Vec::len).max().unwrap_or(0);
+        if letters_height != height || self.user_letters.iter().any(|row| row.len() != width) {
+            repairs.push(format!(
+                "letter grid was {}x{}, the puzzle is {}x{}",
+                letters_width, letters_height, width, height
+            ));
+            self.user_letters.resize(height, Vec::new());
+            for row in &mut self.user_letters {
+                row.resize(width, None);
+            }
+        }
+
+        // Letters on black squares, or that aren't letters
+        let mut dropped = 0;
+        for (row, letters) in self.user_letters.iter_mut().enumerate() {
+            for (col, letter) in letters.iter_mut().enumerate() {
+                let valid = letter.is_none_or(|c| c.is_alphabetic() && is_letter(row, col));
+                if !valid {
+                    *letter = None;
+                    dropped += 1;
+                }
+            }
+        }
+        if dropped > 0 {
+            repairs.push(format!("dropped {} misplaced letter(s)", dropped));
+        }
+
+        // Selection
+        let (row, col) = self.sel;
+        if row >= height || col >= width || !is_letter(row, col) {
+            let first = (0..height)
+                .flat_map(|row| (0..width).map(move |col| (row, col)))
+                .find(|&(row, col)| is_letter(row, col))
+                .unwrap_or((0, 0));
+            repairs.push(format!(
+                "selection ({}, {}) was not on a letter cell",
+                row, col
+            ));
+            self.sel = first;
+        }
+
+        // Completion state
+        let cells = solution
+            .iter()
+            .zip(&self.user_letters)
+            .flat_map(|(s, u)| s.iter().zip(u));
+        let (mut filled, mut correct, mut total) = (0, 0, 0);
+        for (&answer, letter) in cells.filter(|(answer, _)| **answer != '.') {
+            total += 1;
+            if let Some(letter) = letter {
+                filled += 1;
+                if cruciverbal_providers::entries::letters_match(*letter, answer, accept_unaccented)
+                {
+                    correct += 1;
+                }
+            }
+        }
+        let completion_state = if filled < total {
+            CompletionState::InProgress
+        } else if correct == total || unchecked_reason(&self.puzzle).is_some() {
+            CompletionState::Correct
+        } else {
+            CompletionState::IncorrectFill
+        };
+        if completion_state != self.completion_state {
+            repairs.push(format!(
+                "completion state was {:?}, the letters say {:?}",
+                self.completion_state, completion_state
+            ));
+            self.completion_state = completion_state;
+        }
+
+        // Replay events outside the grid
+        let events = self.replay.len();
+        self.replay
+            .retain(|event| event.row < height && event.col < width);
+        if self.replay.len() < events {
+            repairs.push(format!(
+                "dropped {} replay event(s) outside the grid",
+                events - self.replay.len()
+            ));
+        }
+
+        repairs
+    }
+}
+
 /// Information about a saved game for display in the load menu.
 #[derive(Debug, Clone)]
 pub struct SaveInfo {
     /// Full path to the save file.
     pub path: PathBuf,
+    /// File name without the extension.
+    pub name: String,
     /// Puzzle date string.
     pub date: String,
     /// Provider name.
     pub provider: String,
     /// Completion percentage (0-100).
     pub completion_pct: u8,
+    /// When the save was last written.
+    pub last_played: std::time::SystemTime,
+}
+
+impl SaveInfo {
+    /// Name to list the save under: its file name if it was renamed, otherwise
+    /// the puzzle date and provider.
+    pub fn label(&self) -> String {
+        let default_name = generate_filename(&self.date, &self.provider);
+        if default_name.trim_end_matches(".json") == self.name {
+            format!("{} - {}", self.date, self.provider)
+        } else {
+            self.name.clone()
+        }
+    }
+
+    /// How long ago the game was last played (e.g., "3h ago").
+    pub fn last_played_label(&self) -> String {
+        let elapsed = self.last_played.elapsed().unwrap_or_default();
+        format_age(elapsed.as_secs())
+    }
+}
+
+/// Order of the saves in the save browser.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SaveSort {
+    /// Most recently played first.
+    #[default]
+    LastPlayed,
+    /// Newest puzzle first.
+    Date,
+    /// By provider name, then newest puzzle first.
+    Provider,
+    /// Most complete first.
+    Completion,
+}
+
+impl SaveSort {
+    pub const ALL: [SaveSort; 4] = [
+        SaveSort::LastPlayed,
+        SaveSort::Date,
+        SaveSort::Provider,
+        SaveSort::Completion,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SaveSort::LastPlayed => "Last played",
+            SaveSort::Date => "Date",
+            SaveSort::Provider => "Provider",
+            SaveSort::Completion => "Completion",
+        }
+    }
+
+    /// The next sort order, wrapping around.
+    pub fn next(self) -> Self {
+        let idx = Self::ALL.iter().position(|s| *s == self).unwrap_or(0);
+        Self::ALL[(idx + 1) % Self::ALL.len()]
+    }
+
+    /// Sort saves in this order.
+    pub fn sort(self, saves: &mut [SaveInfo]) {
+        match self {
+            SaveSort::LastPlayed => saves.sort_by_key(|s| std::cmp::Reverse(s.last_played)),
+            SaveSort::Date => saves.sort_by(|a, b| b.date.cmp(&a.date)),
+            SaveSort::Provider => {
+                saves.sort_by(|a, b| a.provider.cmp(&b.provider).then(b.date.cmp(&a.date)))
+            }
+            SaveSort::Completion => saves.sort_by_key(|s| std::cmp::Reverse(s.completion_pct)),
+        }
+    }
+}
+
+/// Format an age in seconds as a short relative label.
+fn format_age(secs: u64) -> String {
+    match secs {
+        0..60 => "just now".to_string(),
+        60..3600 => format!("{}m ago", secs / 60),
+        3600..86400 => format!("{}h ago", secs / 3600),
+        _ => format!("{}d ago", secs / 86400),
+    }
 }
 
 /// List all saved games (explicit saves only).
@@ -136,41 +553,76 @@ pub fn list_autosaves() -> Result<Vec<SaveInfo>, SaveError> {
     list_saves_in_dir(&autosaves_dir()?)
 }
 
+/// The fields of a save needed to list and clean up saves.
+///
+/// Reading only these skips building the puzzle, which is most of a save file.
+#[derive(Deserialize)]
+struct SaveHeader {
+    version: u8,
+    puzzle_date: String,
+    provider_name: String,
+    user_letters: Vec<Vec<Option<char>>>,
+    #[serde(default)]
+    saved_at: u64,
+}
+
+/// Read the [`SaveHeader`] of a save file, if it is a supported save.
+fn load_header(path: &Path) -> Option<SaveHeader> {
+    let header: SaveHeader = serde_json::from_str(&read_save_json(path).ok()?).ok()?;
+    (header.version > 0 && header.version <= SAVE_VERSION).then_some(header)
+}
+
+/// When a save was written, from its `saved_at` or else the file's modification
+/// time. None if the file isn't a readable save.
+pub(crate) fn saved_at(path: &Path) -> Option<u64> {
+    let header = load_header(path)?;
+    if header.saved_at > 0 {
+        return Some(header.saved_at);
+    }
+    let modified = std::fs::metadata(path).and_then(|m| m.modified()).ok()?;
+    modified
+        .duration_since(std::time::UNIX_EPOCH)
+        .ok()
+        .map(|d| d.as_secs())
+}
+
 /// List all saves in a directory.
 fn list_saves_in_dir(dir: &Path) -> Result<Vec<SaveInfo>, SaveError> {
     if !dir.exists() {
         return Ok(Vec::new());
     }
 
-    let mut saves: Vec<(SaveInfo, std::time::SystemTime)> = Vec::new();
+    let mut saves: Vec<SaveInfo> = Vec::new();
 
     for entry in std::fs::read_dir(dir)? {
         let entry = entry?;
         let path = entry.path();
 
         if path.extension().map_or(false, |e| e == "json") {
-            // Try to load the save to get metadata
-            if let Ok(save) = load_game(&path) {
+            // Read just enough of the save to get metadata
+            if let Some(save) = load_header(&path) {
                 let completion_pct = calculate_completion_pct(&save.user_letters);
                 let mtime = entry.metadata()?.modified()?;
 
-                saves.push((
-                    SaveInfo {
-                        path,
-                        date: save.puzzle_date,
-                        provider: save.provider_name,
-                        completion_pct,
-                    },
-                    mtime,
-                ));
+                saves.push(SaveInfo {
+                    name: path
+                        .file_stem()
+                        .map(|stem| stem.to_string_lossy().to_string())
+                        .unwrap_or_default(),
+                    path,
+                    date: save.puzzle_date,
+                    provider: save.provider_name,
+                    completion_pct,
+                    last_played: mtime,
+                });
             }
         }
     }
 
     // Sort by modification time (newest first)
-    saves.sort_by(|a, b| b.1.cmp(&a.1));
+    SaveSort::LastPlayed.sort(&mut saves);
 
-    Ok(saves.into_iter().map(|(info, _)| info).collect())
+    Ok(saves)
 }
 
 /// Calculate completion percentage from user letters grid.
@@ -187,6 +639,27 @@ fn calculate_completion_pct(user_letters: &[Vec<Option<char>>]) -> u8 {
     ((filled * 100) / total) as u8
 }
 
+/// Rename a save file within its directory, returning the new path.
+///
+/// The name may only use letters, digits, spaces, `-` and `_`.
+pub fn rename_save(path: &Path, name: &str) -> Result<PathBuf, SaveError> {
+    let name = name.trim();
+    let valid = |c: char| c.is_alphanumeric() || matches!(c, ' ' | '-' | '_');
+    if name.is_empty() || !name.chars().all(valid) {
+        return Err(SaveError::InvalidName(name.to_string()));
+    }
+
+    let new_path = path.with_file_name(format!("{}.json", name));
+    if new_path == path {
+        return Ok(new_path);
+    }
+    if new_path.exists() {
+        return Err(SaveError::InvalidName(format!("{} already exists", name)));
+    }
+    std::fs::rename(path, &new_path)?;
+    Ok(new_path)
+}
+
 /// Delete a save file.
 pub fn delete_save(path: &Path) -> Result<(), SaveError> {
     std::fs::remove_file(path)?;
@@ -216,9 +689,18 @@ pub fn cleanup_old_auto_saves() -> Result<usize, SaveError> {
         let path = entry.path();
 
         if path.extension().map_or(false, |e| e == "json") {
-            if let Ok(save) = load_game(&path) {
+            if let Some(save) = load_header(&path) {
+                // Early saves have no saved_at, use the file time for those
+                let saved_at = match save.saved_at {
+                    0 => entry
+                        .metadata()?
+                        .modified()?
+                        .duration_since(std::time::UNIX_EPOCH)
+                        .map_or(0, |d| d.as_secs()),
+                    saved_at => saved_at,
+                };
                 // Delete auto-saves older than 7 days
-                if save.saved_at > 0 && save.saved_at < cutoff {
+                if saved_at > 0 && saved_at < cutoff {
                     if delete_save(&path).is_ok() {
                         deleted += 1;
                     }
@@ -229,3 +711,174 @@ pub fn cleanup_old_auto_saves() -> Result<usize, SaveError> {
 
     Ok(deleted)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cruciverbal_providers::samples::SAMPLES;
+
+    fn sample_save() -> GameSave {
+        let puzzle = SAMPLES[0].puzzle().unwrap();
+        let user_letters = puzzle
+            .grid
+            .solution
+            .iter()
+            .map(|row| vec![None; row.chars().count()])
+            .collect();
+        GameSave {
+            version: SAVE_VERSION,
+            puzzle_date: "2025-01-30".to_string(),
+            provider_name: "Sample".to_string(),
+            provider_idx: 0,
+            puzzle,
+            user_letters,
+            elapsed_secs: 0,
+            sel: (0, 0),
+            active_direction: Direction::Across,
+            completion_state: CompletionState::InProgress,
+            is_auto_save: false,
+            saved_at: 0,
+            source: None,
+            target_secs: None,
+            replay: Vec::new(),
+            assists: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_repair() {
+        let mut save = sample_save();
+        let (row, col) = save
+            .puzzle
+            .grid
+            .solution
+            .iter()
+            .enumerate()
+            .find_map(|(row, r)| r.chars().position(|c| c != '.').map(|col| (row, col)))
+            .unwrap();
+        save.sel = (row, col);
+        assert!(save.repair(true).is_empty());
+
+        // too few rows, a stray row, the selection out of bounds and a wrong state
+        let height = save.user_letters.len();
+        save.user_letters.truncate(height - 1);
+        save.user_letters[0].push(Some('X'));
+        save.sel = (99, 99);
+        save.completion_state = CompletionState::Correct;
+
+        let repairs = save.repair(true);
+        assert_eq!(repairs.len(), 3, "{:?}", repairs);
+        assert_eq!(save.user_letters.len(), height);
+        assert_eq!(save.sel, (row, col));
+        assert_eq!(save.completion_state, CompletionState::InProgress);
+        assert!(save.repair(true).is_empty());
+    }
+
+    #[test]
+    fn test_backfill_solution() {
+        let mut save = sample_save();
+        let solved = save.puzzle.clone();
+        // a full fill with one wrong letter, of a puzzle without its solution
+        let mut letters = 0;
+        for (row, solution) in save.puzzle.grid.solution.iter_mut().enumerate() {
+            for (col, c) in solution.chars().enumerate() {
+                if c != '.' {
+                    let wrong = if c == 'A' { 'B' } else { 'A' };
+                    save.user_letters[row][col] = Some(if letters == 0 { wrong } else { c });
+                    letters += 1;
+                }
+            }
+            *solution = solution.replace(|c| c != '.', "-");
+        }
+        save.repair(true);
+        assert_eq!(save.completion_state, CompletionState::Correct);
+
+        assert_eq!(save.backfill_solution(&solved, true).unwrap(), letters);
+        assert_eq!(save.puzzle.grid.solution, solved.grid.solution);
+        assert_eq!(save.completion_state, CompletionState::IncorrectFill);
+    }
+
+    #[test]
+    fn test_format_age() {
+        assert_eq!(format_age(5), "just now");
+        assert_eq!(format_age(125), "2m ago");
+        assert_eq!(format_age(3 * 3600 + 59), "3h ago");
+        assert_eq!(format_age(86400 * 2), "2d ago");
+    }
+
+    #[test]
+    fn test_compressed_save() {
+        let dir = std::env::temp_dir().join(format!("cruciverbal-gz-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("2025-01-30_sample.json");
+
+        let save = sample_save();
+        write_save(&path, &save).unwrap();
+        let loaded = load_game(&path).unwrap();
+        assert_eq!(loaded.puzzle.grid.solution, save.puzzle.grid.solution);
+        assert!(load_header(&path).is_some());
+
+        // a changed checksum or truncated data is reported as corruption
+        let mut bytes = std::fs::read(&path).unwrap();
+        bytes[COMPRESSED_MAGIC.len()] ^= 1;
+        std::fs::write(&path, &bytes).unwrap();
+        assert!(matches!(load_game(&path), Err(SaveError::InvalidSave(_))));
+        std::fs::write(&path, &bytes[..bytes.len() / 2]).unwrap();
+        assert!(matches!(load_game(&path), Err(SaveError::InvalidSave(_))));
+
+        // plain JSON saves still load
+        std::fs::write(&path, serde_json::to_string(&save).unwrap()).unwrap();
+        assert!(load_game(&path).is_ok());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_rename_save() {
+        let dir = std::env::temp_dir().join(format!("cruciverbal-rename-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("2025-01-30_sample.json");
+        std::fs::write(&path, "{}").unwrap();
+        std::fs::write(dir.join("taken.json"), "{}").unwrap();
+
+        assert!(rename_save(&path, "../escape").is_err());
+        assert!(rename_save(&path, "  ").is_err());
+        assert!(rename_save(&path, "taken").is_err());
+
+        let renamed = rename_save(&path, "Sunday special").unwrap();
+        assert_eq!(renamed, dir.join("Sunday special.json"));
+        assert!(renamed.exists() && !path.exists());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_migrations_are_in_order() {
+        let versions: Vec<u8> = MIGRATIONS.iter().map(|m| m.to).collect();
+        let expected: Vec<u8> = (2..=SAVE_VERSION).collect();
+        assert_eq!(versions, expected);
+    }
+
+    #[test]
+    fn test_upgrade_v1_save() {
+        let dir = std::env::temp_dir().join(format!("cruciverbal-upgrade-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("2025-01-30_sample.json");
+
+        let mut save = sample_save();
+        save.version = 1;
+        let v1 = serde_json::to_string(&save).unwrap();
+        std::fs::write(&path, &v1).unwrap();
+
+        let loaded = load_game(&path).unwrap();
+        assert_eq!(loaded.version, SAVE_VERSION);
+        assert!(loaded.saved_at > 0);
+
+        // the file is rewritten, with the original kept once
+        let backup = dir.join("2025-01-30_sample.json.v1.bak");
+        assert_eq!(std::fs::read_to_string(&backup).unwrap(), v1);
+        assert_eq!(load_game(&path).unwrap().saved_at, loaded.saved_at);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
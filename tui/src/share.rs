@@ -0,0 +1,66 @@
+//! Sharing the current fill, so friends can help with a stuck corner.
+//!
+//! The fill is copied to the clipboard with the OSC 52 escape sequence and also
+//! written to `shared/` in the data directory, for terminals that don't support
+//! it.
+//! Printable PostScript exports are written to the same directory.
+
+use std::path::PathBuf;
+use thiserror::Error;
+
+/// Error type for sharing operations.
+#[derive(Error, Debug)]
+pub enum ShareError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Could not determine home directory")]
+    NoHomeDir,
+}
+
+/// Get the shared fills directory path (`shared/` in the data directory).
+pub fn shared_dir() -> Result<PathBuf, ShareError> {
+    let dir = crate::paths::data_dir().ok_or(ShareError::NoHomeDir)?;
+    Ok(dir.join("shared"))
+}
+
+/// Write a fill to `{date}_{provider-slug}.txt` in the shared directory.
+///
+/// Returns the path where the fill was written.
+pub fn write_fill(date: &str, provider_name: &str, text: &str) -> Result<PathBuf, ShareError> {
+    write_shared(date, provider_name, "txt", &format!("{}\n", text))
+}
+
+/// Write a PostScript export to `{date}_{provider-slug}.ps` in the shared directory.
+///
+/// Returns the path where the file was written.
+pub fn write_print(
+    date: &str,
+    provider_name: &str,
+    postscript: &str,
+) -> Result<PathBuf, ShareError> {
+    write_shared(date, provider_name, "ps", postscript)
+}
+
+fn write_shared(
+    date: &str,
+    provider_name: &str,
+    extension: &str,
+    contents: &str,
+) -> Result<PathBuf, ShareError> {
+    let dir = shared_dir()?;
+    std::fs::create_dir_all(&dir)?;
+
+    let path = dir
+        .join(crate::save::generate_filename(date, provider_name))
+        .with_extension(extension);
+    std::fs::write(&path, contents)?;
+    Ok(path)
+}
+
+/// Copy text to the system clipboard through the terminal.
+pub fn copy_to_clipboard(text: &str) -> std::io::Result<()> {
+    crossterm::execute!(
+        std::io::stdout(),
+        crossterm::clipboard::CopyToClipboard::to_clipboard_from(text)
+    )
+}
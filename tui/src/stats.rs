@@ -0,0 +1,294 @@
+//! Solve history and streak tracking.
+//!
+//! Every correctly completed puzzle is recorded in `stats.json` in the data
+//! directory. A streak is the number of consecutive days with at least one solve.
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use thiserror::Error;
+
+/// Error type for stats operations.
+#[derive(Error, Debug)]
+pub enum StatsError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("JSON error: {0}")]
+    Serde(#[from] serde_json::Error),
+    #[error("Could not determine home directory")]
+    NoHomeDir,
+}
+
+/// Checks and reveals used on a puzzle.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Assists {
+    /// Letters filled in by reveals.
+    #[serde(default)]
+    pub revealed: u32,
+    /// Checks of a letter, word or the whole puzzle.
+    #[serde(default)]
+    pub checks: u32,
+}
+
+impl Assists {
+    /// Whether the puzzle was solved without any help.
+    pub fn is_none(&self) -> bool {
+        *self == Assists::default()
+    }
+}
+
+/// A single completed puzzle.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SolveRecord {
+    /// Provider display name (e.g., "Guardian Cryptic").
+    pub provider_name: String,
+    /// Puzzle date string (e.g., "2025-01-30").
+    pub puzzle_date: String,
+    /// Local date the puzzle was solved on (e.g., "2025-01-30").
+    pub solved_on: String,
+    /// Solve time in seconds.
+    pub elapsed_secs: u64,
+    /// Target time in seconds, if one was set while solving.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_secs: Option<u64>,
+    /// Checks and reveals used while solving.
+    #[serde(default, skip_serializing_if = "Assists::is_none")]
+    pub assists: Assists,
+}
+
+/// Persisted solve history.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Stats {
+    /// All recorded solves, oldest first.
+    #[serde(default)]
+    pub solves: Vec<SolveRecord>,
+}
+
+impl Stats {
+    /// Record a solve, ignoring repeat solves of the same puzzle.
+    pub fn record(&mut self, record: SolveRecord) {
+        let is_repeat = self.solves.iter().any(|r| {
+            r.provider_name == record.provider_name && r.puzzle_date == record.puzzle_date
+        });
+        if !is_repeat {
+            self.solves.push(record);
+        }
+    }
+
+    /// Add the solves from `other` that aren't recorded yet, returning how many
+    /// were added.
+    pub fn merge(&mut self, other: &Stats) -> usize {
+        let before = self.solves.len();
+        for record in &other.solves {
+            self.record(record.clone());
+        }
+        self.solves.len() - before
+    }
+
+    /// Average solve time for a provider's puzzles in seconds, if any were solved.
+    pub fn average_secs(&self, provider_name: &str) -> Option<u64> {
+        let times: Vec<u64> = self
+            .solves
+            .iter()
+            .filter(|r| r.provider_name == provider_name && r.elapsed_secs > 0)
+            .map(|r| r.elapsed_secs)
+            .collect();
+        (!times.is_empty()).then(|| times.iter().sum::<u64>() / times.len() as u64)
+    }
+
+    /// Whether any puzzle was solved on `day`, optionally from a specific provider.
+    pub fn solved_on(&self, day: NaiveDate, provider_name: Option<&str>) -> bool {
+        let day = day.format("%Y-%m-%d").to_string();
+        self.solves
+            .iter()
+            .any(|r| r.solved_on == day && provider_name.is_none_or(|name| r.provider_name == name))
+    }
+
+    /// Number of consecutive days with a solve, ending today.
+    ///
+    /// A streak that ended yesterday is still alive (today can extend it).
+    pub fn current_streak(&self, today: NaiveDate) -> u32 {
+        let mut day = if self.solved_on(today, None) {
+            today
+        } else {
+            match today.pred_opt() {
+                Some(yesterday) => yesterday,
+                None => return 0,
+            }
+        };
+
+        let mut streak = 0;
+        while self.solved_on(day, None) {
+            streak += 1;
+            match day.pred_opt() {
+                Some(prev) => day = prev,
+                None => break,
+            }
+        }
+        streak
+    }
+}
+
+/// A warning that today's daily puzzle is unsolved and the streak would be lost.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StreakReminder {
+    /// Provider of the configured daily puzzle.
+    pub provider_name: String,
+    /// Length of the streak at risk, in days.
+    pub streak: u32,
+}
+
+impl StreakReminder {
+    /// Human-readable reminder message.
+    pub fn message(&self) -> String {
+        format!(
+            "Today's {} puzzle is unsolved, your {}-day streak is at risk!",
+            self.provider_name, self.streak
+        )
+    }
+}
+
+/// Check whether today's daily puzzle from `provider_name` still needs solving
+/// to keep the streak alive.
+pub fn streak_reminder(
+    stats: &Stats,
+    provider_name: &str,
+    today: NaiveDate,
+) -> Option<StreakReminder> {
+    if stats.solved_on(today, Some(provider_name)) {
+        return None;
+    }
+
+    let streak = stats.current_streak(today);
+    if streak == 0 || stats.solved_on(today, None) {
+        return None;
+    }
+
+    Some(StreakReminder {
+        provider_name: provider_name.to_string(),
+        streak,
+    })
+}
+
+/// Check the streak reminder for the daily provider configured in preferences.
+pub fn check_reminder() -> Option<StreakReminder> {
+    let provider_name = crate::preferences::load_preferences().daily_provider?;
+    let today = chrono::Local::now().date_naive();
+    streak_reminder(&load_stats(), &provider_name, today)
+}
+
+/// Get the stats file path (`stats.json` in the data directory).
+pub fn stats_path() -> Result<PathBuf, StatsError> {
+    let dir = crate::paths::data_dir().ok_or(StatsError::NoHomeDir)?;
+    Ok(dir.join("stats.json"))
+}
+
+/// Load stats from disk.
+///
+/// Returns empty stats if the file doesn't exist or can't be read.
+pub fn load_stats() -> Stats {
+    let Ok(path) = stats_path() else {
+        return Stats::default();
+    };
+
+    match std::fs::read_to_string(&path) {
+        Ok(contents) => serde_json::from_str(&contents).unwrap_or_default(),
+        Err(_) => Stats::default(),
+    }
+}
+
+/// Save stats to disk.
+pub fn save_stats(stats: &Stats) -> Result<(), StatsError> {
+    let path = stats_path()?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let json = serde_json::to_string_pretty(stats)?;
+    std::fs::write(&path, json)?;
+    Ok(())
+}
+
+/// Record a solve in the persisted stats.
+pub fn record_solve(record: SolveRecord) -> Result<(), StatsError> {
+    let mut stats = load_stats();
+    stats.record(record);
+    save_stats(&stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn solve(provider_name: &str, solved_on: &str) -> SolveRecord {
+        SolveRecord {
+            provider_name: provider_name.to_string(),
+            puzzle_date: solved_on.to_string(),
+            solved_on: solved_on.to_string(),
+            elapsed_secs: 600,
+            target_secs: None,
+            assists: Assists::default(),
+        }
+    }
+
+    fn stats(solves: &[(&str, &str)]) -> Stats {
+        let mut stats = Stats::default();
+        for (provider_name, solved_on) in solves {
+            stats.record(solve(provider_name, solved_on));
+        }
+        stats
+    }
+
+    #[test]
+    fn test_current_streak() {
+        let today = day("2025-03-10");
+        assert_eq!(Stats::default().current_streak(today), 0);
+
+        // an unbroken run ending today
+        let run = stats(&[
+            ("Daily", "2025-03-08"),
+            ("Daily", "2025-03-09"),
+            ("Daily", "2025-03-10"),
+        ]);
+        assert_eq!(run.current_streak(today), 3);
+
+        // a gap ends the streak
+        let gap = stats(&[
+            ("Daily", "2025-03-06"),
+            ("Daily", "2025-03-08"),
+            ("Daily", "2025-03-10"),
+        ]);
+        assert_eq!(gap.current_streak(today), 1);
+
+        // a streak ending yesterday is still alive, but not one ending before
+        let yesterday = stats(&[("Daily", "2025-03-08"), ("Other", "2025-03-09")]);
+        assert_eq!(yesterday.current_streak(today), 2);
+        assert_eq!(yesterday.current_streak(day("2025-03-11")), 0);
+    }
+
+    #[test]
+    fn test_streak_reminder() {
+        let today = day("2025-03-10");
+        let mut stats = stats(&[("Daily", "2025-03-08"), ("Daily", "2025-03-09")]);
+
+        // no streak to lose
+        assert_eq!(streak_reminder(&stats, "Daily", day("2025-03-12")), None);
+
+        let reminder = streak_reminder(&stats, "Daily", today).unwrap();
+        assert_eq!(reminder.provider_name, "Daily");
+        assert_eq!(reminder.streak, 2);
+
+        // solving today's daily clears the reminder
+        let mut solved = stats.clone();
+        solved.record(solve("Daily", "2025-03-10"));
+        assert_eq!(streak_reminder(&solved, "Daily", today), None);
+
+        // as does any other solve keeping the streak alive
+        stats.record(solve("Other", "2025-03-10"));
+        assert_eq!(streak_reminder(&stats, "Daily", today), None);
+    }
+}
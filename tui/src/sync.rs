@@ -0,0 +1,424 @@
+//! Syncing saves and stats between machines.
+//!
+//! Sync goes through a remote copy of the library: a git repository, cloned into
+//! `sync/` in the data directory and pulled and pushed with the `git` command,
+//! a WebDAV folder, downloaded into `webdav/` in the data directory and uploaded
+//! again, or any directory (e.g. a folder synced by another tool).
+//! Saves are exchanged per file, keeping the copy saved last; solves from both
+//! sides are merged into the stats.
+
+use crate::preferences::SyncSettings;
+use crate::stats::{self, Stats};
+use quick_xml::events::Event;
+use reqwest::{StatusCode, Url};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use thiserror::Error;
+
+/// Error type for sync operations.
+#[derive(Error, Debug)]
+pub enum SyncError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("JSON error: {0}")]
+    Serde(#[from] serde_json::Error),
+    #[error("Stats error: {0}")]
+    Stats(#[from] stats::StatsError),
+    #[error("Could not determine home directory")]
+    NoHomeDir,
+    #[error(
+        "Sync is not set up, set sync.git_remote, sync.webdav_url or sync.dir in the preferences"
+    )]
+    NotConfigured,
+    #[error("git {0} failed: {1}")]
+    Git(String, String),
+    #[error("Invalid WebDAV URL: {0}")]
+    InvalidUrl(String),
+    #[error("HTTP error: {0}")]
+    Http(#[from] reqwest::Error),
+    #[error("WebDAV {0} {1} failed: {2}")]
+    WebDav(String, String, StatusCode),
+}
+
+/// Summary of a sync run.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SyncReport {
+    /// Saves copied from the remote.
+    pub pulled: usize,
+    /// Saves copied to the remote.
+    pub pushed: usize,
+    /// Solves from the remote added to the local stats.
+    pub solves_added: usize,
+}
+
+/// Get the checkout directory for git sync (`sync/` in the data directory).
+pub fn checkout_dir() -> Result<PathBuf, SyncError> {
+    let dir = crate::paths::data_dir().ok_or(SyncError::NoHomeDir)?;
+    Ok(dir.join("sync"))
+}
+
+/// Get the staging directory for WebDAV sync (`webdav/` in the data directory).
+pub fn webdav_dir() -> Result<PathBuf, SyncError> {
+    let dir = crate::paths::data_dir().ok_or(SyncError::NoHomeDir)?;
+    Ok(dir.join("webdav"))
+}
+
+/// Sync saves and stats with the configured remote.
+pub async fn sync(settings: &SyncSettings) -> Result<SyncReport, SyncError> {
+    let local_saves = crate::save::saves_dir().map_err(|_| SyncError::NoHomeDir)?;
+
+    if let Some(url) = &settings.git_remote {
+        let dir = checkout_dir()?;
+        if dir.join(".git").exists() {
+            git(&dir, &["pull", "--ff-only"])?;
+        } else {
+            std::fs::create_dir_all(&dir)?;
+            git(&dir, &["clone", url, "."])?;
+        }
+
+        let report = sync_with(&dir, &local_saves)?;
+        git(&dir, &["add", "-A"])?;
+        let status = git(&dir, &["status", "--porcelain"])?;
+        if !status.trim().is_empty() {
+            git(&dir, &["commit", "-m", "Sync saves and stats"])?;
+            git(&dir, &["push"])?;
+        }
+        Ok(report)
+    } else if let Some(url) = &settings.webdav_url {
+        sync_webdav(url, &local_saves).await
+    } else if let Some(dir) = &settings.dir {
+        sync_with(dir, &local_saves)
+    } else {
+        Err(SyncError::NotConfigured)
+    }
+}
+
+/// Exchange saves and stats with a remote directory.
+fn sync_with(remote: &Path, local_saves: &Path) -> Result<SyncReport, SyncError> {
+    let (pulled, pushed) = merge_saves(local_saves, &remote.join("saves"))?;
+
+    let remote_stats = remote.join("stats.json");
+    let mut local = stats::load_stats();
+    let theirs: Stats = match std::fs::read_to_string(&remote_stats) {
+        Ok(contents) => serde_json::from_str(&contents)?,
+        Err(_) => Stats::default(),
+    };
+    let solves_added = local.merge(&theirs);
+    if solves_added > 0 {
+        stats::save_stats(&local)?;
+    }
+    std::fs::write(&remote_stats, serde_json::to_string_pretty(&local)?)?;
+
+    Ok(SyncReport {
+        pulled,
+        pushed,
+        solves_added,
+    })
+}
+
+/// Copy saves both ways between two directories, so each ends up with the
+/// latest copy of every save. Returns the number of files copied into `local`
+/// and into `remote`.
+fn merge_saves(local: &Path, remote: &Path) -> Result<(usize, usize), SyncError> {
+    std::fs::create_dir_all(local)?;
+    std::fs::create_dir_all(remote)?;
+
+    let mut names = std::collections::BTreeSet::new();
+    for dir in [local, remote] {
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            match path.file_name() {
+                Some(name) if path.extension().is_some_and(|e| e == "json") => {
+                    names.insert(name.to_os_string());
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let (mut pulled, mut pushed) = (0, 0);
+    for name in names {
+        let (ours, theirs) = (local.join(&name), remote.join(&name));
+        match (crate::save::saved_at(&ours), crate::save::saved_at(&theirs)) {
+            (Some(a), Some(b)) if a == b => {}
+            (Some(a), Some(b)) if a > b => {
+                std::fs::copy(&ours, &theirs)?;
+                pushed += 1;
+            }
+            (Some(_), None) => {
+                std::fs::copy(&ours, &theirs)?;
+                pushed += 1;
+            }
+            (_, Some(_)) => {
+                std::fs::copy(&theirs, &ours)?;
+                pulled += 1;
+            }
+            (None, None) => {}
+        }
+    }
+    Ok((pulled, pushed))
+}
+
+/// Exchange saves and stats with a WebDAV folder.
+///
+/// The remote files are downloaded into a staging directory, merged with the
+/// local ones as with a directory remote, and those that changed are uploaded.
+async fn sync_webdav(url: &str, local_saves: &Path) -> Result<SyncReport, SyncError> {
+    let mut base = Url::parse(url).map_err(|e| SyncError::InvalidUrl(e.to_string()))?;
+    if base.cannot_be_a_base() {
+        return Err(SyncError::InvalidUrl(url.to_string()));
+    }
+    // make sure `base` is a folder, so files resolve inside it
+    if !base.path().ends_with('/') {
+        base.set_path(&format!("{}/", base.path()));
+    }
+    let saves_url = base.join("saves/").expect("relative path");
+
+    let client = reqwest::Client::builder()
+        .user_agent(concat!("cruciverbal/", env!("CARGO_PKG_VERSION")))
+        .build()?;
+    let staging = webdav_dir()?;
+    if staging.exists() {
+        std::fs::remove_dir_all(&staging)?;
+    }
+    std::fs::create_dir_all(staging.join("saves"))?;
+
+    // download the remote copy, remembering what it was
+    let mut downloaded: HashMap<PathBuf, Vec<u8>> = HashMap::new();
+    let depth = [("Depth", "1"), ("Content-Type", "application/xml")];
+    match webdav(
+        &client,
+        "PROPFIND",
+        &saves_url,
+        &depth,
+        Some(PROPFIND_BODY.into()),
+    )
+    .await?
+    {
+        Some(listing) => {
+            for name in propfind_names(&String::from_utf8_lossy(&listing)) {
+                if !name.ends_with(".json") {
+                    continue;
+                }
+                if let Some(contents) =
+                    webdav(&client, "GET", &file_url(&saves_url, &name), &[], None).await?
+                {
+                    let path = Path::new("saves").join(&name);
+                    std::fs::write(staging.join(&path), &contents)?;
+                    downloaded.insert(path, contents);
+                }
+            }
+        }
+        None => {
+            webdav(&client, "MKCOL", &saves_url, &[], None).await?;
+        }
+    }
+    if let Some(contents) =
+        webdav(&client, "GET", &file_url(&base, "stats.json"), &[], None).await?
+    {
+        std::fs::write(staging.join("stats.json"), &contents)?;
+        downloaded.insert(PathBuf::from("stats.json"), contents);
+    }
+
+    let report = sync_with(&staging, local_saves)?;
+
+    // upload new and changed files
+    let mut files = vec![PathBuf::from("stats.json")];
+    for entry in std::fs::read_dir(staging.join("saves"))? {
+        files.push(Path::new("saves").join(entry?.file_name()));
+    }
+    for path in files {
+        let contents = std::fs::read(staging.join(&path))?;
+        if downloaded.get(&path) == Some(&contents) {
+            continue;
+        }
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        let folder = if path.starts_with("saves") {
+            &saves_url
+        } else {
+            &base
+        };
+        webdav(
+            &client,
+            "PUT",
+            &file_url(folder, &name),
+            &[],
+            Some(contents),
+        )
+        .await?;
+    }
+    Ok(report)
+}
+
+/// Request body of a PROPFIND listing a folder.
+const PROPFIND_BODY: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<propfind xmlns="DAV:"><prop><resourcetype/></prop></propfind>"#;
+
+/// URL of the file `name` in the WebDAV folder `folder`.
+fn file_url(folder: &Url, name: &str) -> Url {
+    let mut url = folder.clone();
+    if let Ok(mut segments) = url.path_segments_mut() {
+        segments.pop_if_empty().push(name);
+    }
+    url
+}
+
+/// Send a WebDAV request, returning the response body, or `None` if there is
+/// nothing at `url`.
+///
+/// The user's own server doesn't need the delays and retries puzzle downloads
+/// go through, so this uses a plain client.
+async fn webdav(
+    client: &reqwest::Client,
+    method: &str,
+    url: &Url,
+    headers: &[(&str, &str)],
+    body: Option<Vec<u8>>,
+) -> Result<Option<Vec<u8>>, SyncError> {
+    let verb = reqwest::Method::from_bytes(method.as_bytes()).expect("valid method");
+    let mut request = client.request(verb, url.clone());
+    for (name, value) in headers {
+        request = request.header(*name, *value);
+    }
+    if let Some(body) = body {
+        request = request.body(body);
+    }
+    let response = request.send().await?;
+
+    let status = response.status();
+    if status == StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+    if !status.is_success() {
+        // leave credentials out of the error
+        let mut url = url.clone();
+        let _ = url.set_password(None);
+        let _ = url.set_username("");
+        return Err(SyncError::WebDav(
+            method.to_string(),
+            url.to_string(),
+            status,
+        ));
+    }
+    Ok(Some(response.bytes().await?.to_vec()))
+}
+
+/// Whether `name` is a bare file name, so joining it to a directory can't
+/// reach outside of it.
+fn is_plain_file_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name.contains(['/', '\\'])
+        && !name.contains("..")
+        && Path::new(name).file_name() == Some(std::ffi::OsStr::new(name))
+}
+
+/// Names of the files in a PROPFIND response, leaving out folders (whose
+/// `href` ends with a `/`), including the listed folder itself, and names that
+/// aren't plain file names (see [`is_plain_file_name`]).
+fn propfind_names(xml: &str) -> Vec<String> {
+    let mut reader = quick_xml::Reader::from_str(xml);
+    let mut names = Vec::new();
+    let mut in_href = false;
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(tag)) => in_href = tag.local_name().as_ref() == b"href",
+            Ok(Event::Text(text)) if in_href => {
+                let Ok(href) = text.unescape() else {
+                    continue;
+                };
+                let name = href.trim().rsplit('/').next().unwrap_or_default();
+                // a `+` in a path is a plus sign, not an encoded space
+                let name = cruciverbal_providers::util::url_decode(&name.replace('+', "%2B"));
+                if is_plain_file_name(&name) {
+                    names.push(name);
+                }
+            }
+            Ok(Event::End(_)) => in_href = false,
+            Ok(Event::Eof) | Err(_) => break,
+            _ => {}
+        }
+    }
+    names
+}
+
+/// Run a git command in `dir`, returning its output.
+fn git(dir: &Path, args: &[&str]) -> Result<String, SyncError> {
+    let output = Command::new("git").args(args).current_dir(dir).output()?;
+    if !output.status.success() {
+        return Err(SyncError::Git(
+            args.first().unwrap_or(&"").to_string(),
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_saves() {
+        let dir = std::env::temp_dir().join(format!("cruciverbal-sync-{}", std::process::id()));
+        let (local, remote) = (dir.join("local"), dir.join("remote"));
+        std::fs::create_dir_all(&local).unwrap();
+        std::fs::create_dir_all(&remote).unwrap();
+
+        let save = |saved_at: u64| {
+            format!(
+                r#"{{"version":2,"puzzle_date":"2025-01-30","provider_name":"Sample","user_letters":[],"saved_at":{}}}"#,
+                saved_at
+            )
+        };
+        std::fs::write(local.join("newer-here.json"), save(200)).unwrap();
+        std::fs::write(remote.join("newer-here.json"), save(100)).unwrap();
+        std::fs::write(local.join("newer-there.json"), save(100)).unwrap();
+        std::fs::write(remote.join("newer-there.json"), save(200)).unwrap();
+        std::fs::write(local.join("only-here.json"), save(100)).unwrap();
+        std::fs::write(remote.join("only-there.json"), save(100)).unwrap();
+
+        assert_eq!(merge_saves(&local, &remote).unwrap(), (2, 2));
+        for name in ["newer-here.json", "newer-there.json"] {
+            let read = |dir: &Path| std::fs::read_to_string(dir.join(name)).unwrap();
+            assert_eq!(read(&local), save(200));
+            assert_eq!(read(&remote), save(200));
+        }
+        assert!(local.join("only-there.json").exists());
+        assert!(remote.join("only-here.json").exists());
+        assert_eq!(merge_saves(&local, &remote).unwrap(), (0, 0));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_propfind_names() {
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<D:multistatus xmlns:D="DAV:">
+  <D:response><D:href>/dav/crosswords/saves/</D:href></D:response>
+  <D:response><D:href>/dav/crosswords/saves/2025-01-30_guardian-cryptic.json</D:href></D:response>
+  <D:response><D:href>https://example.com/dav/crosswords/saves/Sunday%20special+1.json</D:href></D:response>
+  <D:response><D:href>/dav/crosswords/saves/old/</D:href></D:response>
+</D:multistatus>"#;
+        assert_eq!(
+            propfind_names(xml),
+            ["2025-01-30_guardian-cryptic.json", "Sunday special+1.json"]
+        );
+
+        // names that could reach outside the saves directory are dropped
+        let hostile = r#"<D:multistatus xmlns:D="DAV:">
+  <D:response><D:href>/dav/saves/..%2F..%2Fpreferences.json</D:href></D:response>
+  <D:response><D:href>/dav/saves/..</D:href></D:response>
+  <D:response><D:href>/dav/saves/..%5Cstats.json</D:href></D:response>
+  <D:response><D:href>/dav/saves/%2Fetc%2Fpasswd.json</D:href></D:response>
+  <D:response><D:href>/dav/saves/ok.json</D:href></D:response>
+</D:multistatus>"#;
+        assert_eq!(propfind_names(hostile), ["ok.json"]);
+
+        let base = Url::parse("https://example.com/dav/saves/").unwrap();
+        assert_eq!(
+            file_url(&base, "Sunday special#2.json").as_str(),
+            "https://example.com/dav/saves/Sunday%20special%232.json"
+        );
+    }
+}
@@ -0,0 +1,129 @@
+//! Terminal capability detection.
+//!
+//! Some terminals can't draw box drawing characters (the Linux console, serial
+//! consoles, non-UTF-8 locales) or RGB colors (the legacy Windows console). The
+//! grid then falls back to ASCII borders and the themes to the 16 basic colors.
+
+use crate::preferences::TerminalMode;
+
+/// What the terminal can draw.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Capabilities {
+    /// Unicode box drawing characters.
+    pub box_drawing: bool,
+    /// RGB (or at least 256) colors.
+    pub rgb_colors: bool,
+}
+
+impl Default for Capabilities {
+    fn default() -> Self {
+        Self {
+            box_drawing: true,
+            rgb_colors: true,
+        }
+    }
+}
+
+/// `TERM` values of terminals with neither box drawing nor RGB colors.
+const BASIC_TERMS: [&str; 5] = ["linux", "dumb", "ansi", "cons25", "vt100"];
+
+/// Capabilities for the given mode, detecting them in [`TerminalMode::Auto`].
+pub fn capabilities(mode: TerminalMode) -> Capabilities {
+    match mode {
+        TerminalMode::Auto => detect(),
+        TerminalMode::Full => Capabilities::default(),
+        TerminalMode::Basic => Capabilities {
+            box_drawing: false,
+            rgb_colors: false,
+        },
+    }
+}
+
+/// Detect the terminal's capabilities from the environment.
+pub fn detect() -> Capabilities {
+    detect_from(|name| std::env::var(name).ok(), cfg!(windows))
+}
+
+/// Detect capabilities from environment variables looked up with `var`.
+fn detect_from(var: impl Fn(&str) -> Option<String>, windows: bool) -> Capabilities {
+    let var = |name: &str| var(name).filter(|value| !value.is_empty());
+    let term = var("TERM").unwrap_or_default().to_lowercase();
+
+    if windows {
+        // Windows Terminal, ConEmu and editor terminals support both; the legacy
+        // console host supports neither
+        let modern = var("WT_SESSION").is_some()
+            || var("TERM_PROGRAM").is_some()
+            || var("ConEmuANSI").is_some_and(|v| v.eq_ignore_ascii_case("on"))
+            || !term.is_empty();
+        return Capabilities {
+            box_drawing: modern,
+            rgb_colors: modern,
+        };
+    }
+
+    if BASIC_TERMS.contains(&term.as_str()) || term.starts_with("vt") {
+        return Capabilities {
+            box_drawing: false,
+            rgb_colors: false,
+        };
+    }
+
+    // The first locale variable that is set decides the character set
+    let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+        .into_iter()
+        .find_map(var)
+        .map(|locale| locale.to_lowercase());
+    let box_drawing =
+        locale.is_none_or(|locale| locale.contains("utf-8") || locale.contains("utf8"));
+
+    let rgb_colors = var("COLORTERM").is_some_and(|v| v == "truecolor" || v == "24bit")
+        || term.contains("256color")
+        || term.contains("direct")
+        || var("TERM_PROGRAM").is_some()
+        || term.is_empty();
+
+    Capabilities {
+        box_drawing,
+        rgb_colors,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detect_with(vars: &[(&str, &str)], windows: bool) -> Capabilities {
+        detect_from(
+            |name| {
+                vars.iter()
+                    .find(|(n, _)| *n == name)
+                    .map(|(_, v)| v.to_string())
+            },
+            windows,
+        )
+    }
+
+    #[test]
+    fn test_detect() {
+        let full = Capabilities::default();
+        let basic = capabilities(TerminalMode::Basic);
+
+        let xterm = [("TERM", "xterm-256color"), ("LANG", "en_US.UTF-8")];
+        assert_eq!(detect_with(&xterm, false), full);
+        assert_eq!(detect_with(&[("TERM", "linux")], false), basic);
+        assert_eq!(detect_with(&[("TERM", "vt220")], false), basic);
+
+        // LC_ALL takes precedence over LANG
+        let c_locale = [
+            ("TERM", "xterm-256color"),
+            ("LANG", "en_US.UTF-8"),
+            ("LC_ALL", "C"),
+        ];
+        assert!(!detect_with(&c_locale, false).box_drawing);
+        assert!(!detect_with(&[("TERM", "xterm")], false).rgb_colors);
+
+        assert_eq!(detect_with(&[], true), basic);
+        assert_eq!(detect_with(&[("WT_SESSION", "1")], true), full);
+    }
+}
@@ -1,8 +1,19 @@
 //! Theme system for Cruciverbal.
 //!
-//! Provides preset color schemes that can be selected by the user.
+//! Provides preset color schemes that can be selected by the user, along with
+//! user themes from JSON files in `themes/` in the config directory. A theme file
+//! sets any of the colors by name (`"cyan"`), hex (`"#5f87af"`) or palette
+//! index (`"67"`); missing colors are taken from the default theme:
+//!
+//! ```json
+//! { "name": "Solarized", "primary": "#b58900", "secondary": "#268bd2" }
+//! ```
 
 use ratatui::style::Color;
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::{OnceLock, RwLock};
 
 /// A color theme for the application.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -33,6 +44,14 @@ pub struct Theme {
     pub filled_cell_bg: Color,
     /// Foreground color for filled cell rendering.
     pub filled_cell_fg: Color,
+    /// Cursor cell marker and clue number.
+    pub selected_cell: Color,
+    /// Markers and clue number of the rest of the selected word.
+    pub selected_word: Color,
+    /// Letters found correct by auto-check.
+    pub correct: Color,
+    /// Letters found wrong by auto-check.
+    pub wrong: Color,
 }
 
 /// Default theme - the original Cruciverbal colors.
@@ -48,79 +67,371 @@ pub const DEFAULT: Theme = Theme {
     grid_border: Color::White,
     filled_cell_bg: Color::Black,
     filled_cell_fg: Color::White,
+    selected_cell: Color::Yellow,
+    selected_word: Color::Cyan,
+    correct: Color::Green,
+    wrong: Color::Red,
 };
 
 /// Dark theme - warm gold and cool blue for high contrast.
 pub const DARK: Theme = Theme {
     id: "dark",
     name: "Dark",
-    primary: Color::Rgb(255, 215, 0),    // Gold
-    secondary: Color::Rgb(100, 149, 237), // Cornflower blue
-    text: Color::Rgb(220, 220, 220),      // Light gray
-    dimmed: Color::Rgb(128, 128, 128),    // Gray
-    success: Color::Rgb(50, 205, 50),     // Lime green
-    error: Color::Rgb(255, 99, 71),       // Tomato
+    primary: Color::Rgb(255, 215, 0),       // Gold
+    secondary: Color::Rgb(100, 149, 237),   // Cornflower blue
+    text: Color::Rgb(220, 220, 220),        // Light gray
+    dimmed: Color::Rgb(128, 128, 128),      // Gray
+    success: Color::Rgb(50, 205, 50),       // Lime green
+    error: Color::Rgb(255, 99, 71),         // Tomato
     grid_border: Color::Rgb(192, 192, 192), // Silver
     filled_cell_bg: Color::Rgb(32, 32, 32), // Dark gray
     filled_cell_fg: Color::Rgb(64, 64, 64), // Darker gray
+    selected_cell: Color::Rgb(255, 215, 0),
+    selected_word: Color::Rgb(100, 149, 237),
+    correct: Color::Rgb(50, 205, 50),
+    wrong: Color::Rgb(255, 99, 71),
 };
 
 /// Light theme - darker tones for light terminal backgrounds.
 pub const LIGHT: Theme = Theme {
     id: "light",
     name: "Light",
-    primary: Color::Rgb(184, 134, 11),   // Dark goldenrod
-    secondary: Color::Rgb(0, 139, 139),  // Dark cyan
-    text: Color::Rgb(33, 33, 33),        // Near black
-    dimmed: Color::Rgb(105, 105, 105),   // Dim gray
-    success: Color::Rgb(34, 139, 34),    // Forest green
-    error: Color::Rgb(178, 34, 34),      // Firebrick
-    grid_border: Color::Rgb(64, 64, 64), // Dark gray
+    primary: Color::Rgb(184, 134, 11),      // Dark goldenrod
+    secondary: Color::Rgb(0, 139, 139),     // Dark cyan
+    text: Color::Rgb(33, 33, 33),           // Near black
+    dimmed: Color::Rgb(105, 105, 105),      // Dim gray
+    success: Color::Rgb(34, 139, 34),       // Forest green
+    error: Color::Rgb(178, 34, 34),         // Firebrick
+    grid_border: Color::Rgb(64, 64, 64),    // Dark gray
     filled_cell_bg: Color::Rgb(48, 48, 48), // Charcoal
     filled_cell_fg: Color::Rgb(96, 96, 96), // Gray
+    selected_cell: Color::Rgb(184, 134, 11),
+    selected_word: Color::Rgb(0, 139, 139),
+    correct: Color::Rgb(34, 139, 34),
+    wrong: Color::Rgb(178, 34, 34),
 };
 
 /// Ocean theme - sandy gold and ocean blue palette.
 pub const OCEAN: Theme = Theme {
     id: "ocean",
     name: "Ocean",
-    primary: Color::Rgb(244, 208, 111),  // Sandy gold
-    secondary: Color::Rgb(70, 130, 180), // Steel blue
-    text: Color::Rgb(240, 248, 255),     // Alice blue
-    dimmed: Color::Rgb(119, 136, 153),   // Light slate gray
-    success: Color::Rgb(32, 178, 170),   // Light sea green
-    error: Color::Rgb(205, 92, 92),      // Indian red
-    grid_border: Color::Rgb(176, 196, 222), // Light steel blue
-    filled_cell_bg: Color::Rgb(25, 25, 112), // Midnight blue
+    primary: Color::Rgb(244, 208, 111),       // Sandy gold
+    secondary: Color::Rgb(70, 130, 180),      // Steel blue
+    text: Color::Rgb(240, 248, 255),          // Alice blue
+    dimmed: Color::Rgb(119, 136, 153),        // Light slate gray
+    success: Color::Rgb(32, 178, 170),        // Light sea green
+    error: Color::Rgb(205, 92, 92),           // Indian red
+    grid_border: Color::Rgb(176, 196, 222),   // Light steel blue
+    filled_cell_bg: Color::Rgb(25, 25, 112),  // Midnight blue
     filled_cell_fg: Color::Rgb(65, 105, 225), // Royal blue
+    selected_cell: Color::Rgb(244, 208, 111),
+    selected_word: Color::Rgb(70, 130, 180),
+    correct: Color::Rgb(32, 178, 170),
+    wrong: Color::Rgb(205, 92, 92),
 };
 
 /// Forest theme - sunlight gold and leaf green palette.
 pub const FOREST: Theme = Theme {
     id: "forest",
     name: "Forest",
-    primary: Color::Rgb(255, 223, 128),  // Soft gold (sunlight)
-    secondary: Color::Rgb(107, 142, 35), // Olive drab (leaves)
-    text: Color::Rgb(245, 245, 220),     // Beige
-    dimmed: Color::Rgb(143, 143, 123),   // Dark khaki-ish
-    success: Color::Rgb(60, 179, 113),   // Medium sea green
-    error: Color::Rgb(210, 105, 30),     // Chocolate
+    primary: Color::Rgb(255, 223, 128),     // Soft gold (sunlight)
+    secondary: Color::Rgb(107, 142, 35),    // Olive drab (leaves)
+    text: Color::Rgb(245, 245, 220),        // Beige
+    dimmed: Color::Rgb(143, 143, 123),      // Dark khaki-ish
+    success: Color::Rgb(60, 179, 113),      // Medium sea green
+    error: Color::Rgb(210, 105, 30),        // Chocolate
     grid_border: Color::Rgb(189, 183, 107), // Dark khaki
     filled_cell_bg: Color::Rgb(34, 49, 34), // Very dark green
     filled_cell_fg: Color::Rgb(85, 107, 47), // Dark olive green
+    selected_cell: Color::Rgb(255, 223, 128),
+    selected_word: Color::Rgb(107, 142, 35),
+    correct: Color::Rgb(60, 179, 113),
+    wrong: Color::Rgb(210, 105, 30),
+};
+
+/// High contrast theme - pure colors at full brightness on black.
+pub const HIGH_CONTRAST: Theme = Theme {
+    id: "high-contrast",
+    name: "High Contrast",
+    primary: Color::Rgb(255, 255, 0),          // Yellow
+    secondary: Color::Rgb(0, 255, 255),        // Cyan
+    text: Color::Rgb(255, 255, 255),           // White
+    dimmed: Color::Rgb(192, 192, 192),         // Silver, still readable
+    success: Color::Rgb(0, 255, 0),            // Green
+    error: Color::Rgb(255, 0, 255),            // Magenta, unlike green in brightness too
+    grid_border: Color::Rgb(255, 255, 255),    // White
+    filled_cell_bg: Color::Rgb(0, 0, 0),       // Black
+    filled_cell_fg: Color::Rgb(255, 255, 255), // White
+    selected_cell: Color::Rgb(255, 255, 0),
+    selected_word: Color::Rgb(0, 255, 255),
+    correct: Color::Rgb(0, 255, 0),
+    wrong: Color::Rgb(255, 0, 255),
 };
 
+/// Colorblind theme - the Okabe-Ito palette, telling correct and wrong apart by
+/// blue and orange instead of green and red (deuteranopia and protanopia safe).
+pub const COLORBLIND: Theme = Theme {
+    id: "colorblind",
+    name: "Colorblind Safe",
+    primary: Color::Rgb(240, 228, 66),      // Yellow
+    secondary: Color::Rgb(204, 121, 167),   // Reddish purple
+    text: Color::Rgb(240, 240, 240),        // Off white
+    dimmed: Color::Rgb(150, 150, 150),      // Gray
+    success: Color::Rgb(86, 180, 233),      // Sky blue
+    error: Color::Rgb(230, 159, 0),         // Orange
+    grid_border: Color::Rgb(190, 190, 190), // Light gray
+    filled_cell_bg: Color::Rgb(20, 20, 20), // Near black
+    filled_cell_fg: Color::Rgb(70, 70, 70), // Dark gray
+    selected_cell: Color::Rgb(240, 228, 66),
+    selected_word: Color::Rgb(204, 121, 167),
+    correct: Color::Rgb(86, 180, 233),
+    wrong: Color::Rgb(230, 159, 0),
+};
+
+/// User themes loaded from theme files, each with its basic-color version.
+static USER_THEMES: RwLock<Vec<(&'static Theme, &'static Theme)>> = RwLock::new(Vec::new());
+
 impl Theme {
-    /// All available themes.
-    pub const ALL: [Theme; 5] = [DEFAULT, DARK, LIGHT, OCEAN, FOREST];
+    /// All built-in themes.
+    pub const ALL: [Theme; 7] = [
+        DEFAULT,
+        DARK,
+        LIGHT,
+        OCEAN,
+        FOREST,
+        HIGH_CONTRAST,
+        COLORBLIND,
+    ];
+
+    /// Built-in themes followed by the loaded user themes.
+    pub fn available() -> Vec<&'static Theme> {
+        let user = USER_THEMES.read().unwrap_or_else(|e| e.into_inner());
+        Theme::ALL
+            .iter()
+            .chain(user.iter().map(|(theme, _)| *theme))
+            .collect()
+    }
 
     /// Look up a theme by its ID.
     ///
     /// Returns the DEFAULT theme if the ID is not found.
     pub fn by_id(id: &str) -> &'static Theme {
-        Theme::ALL
-            .iter()
+        Theme::available()
+            .into_iter()
             .find(|t| t.id == id)
             .unwrap_or(&DEFAULT)
     }
+
+    /// Look up a theme by its ID, in basic colors unless the terminal supports RGB.
+    pub fn for_terminal(id: &str, rgb_colors: bool) -> &'static Theme {
+        static BASIC: OnceLock<Vec<Theme>> = OnceLock::new();
+
+        let theme = Theme::by_id(id);
+        if rgb_colors {
+            return theme;
+        }
+        let user = USER_THEMES.read().unwrap_or_else(|e| e.into_inner());
+        if let Some((_, basic)) = user.iter().find(|(t, _)| t.id == theme.id) {
+            return basic;
+        }
+        BASIC
+            .get_or_init(|| Theme::ALL.iter().map(Theme::basic).collect())
+            .iter()
+            .find(|t| t.id == theme.id)
+            .unwrap_or(theme)
+    }
+
+    /// This theme with each RGB color replaced by the nearest basic color.
+    pub fn basic(&self) -> Theme {
+        Theme {
+            primary: basic_color(self.primary),
+            secondary: basic_color(self.secondary),
+            text: basic_color(self.text),
+            dimmed: basic_color(self.dimmed),
+            success: basic_color(self.success),
+            error: basic_color(self.error),
+            grid_border: basic_color(self.grid_border),
+            filled_cell_bg: basic_color(self.filled_cell_bg),
+            filled_cell_fg: basic_color(self.filled_cell_fg),
+            selected_cell: basic_color(self.selected_cell),
+            selected_word: basic_color(self.selected_word),
+            correct: basic_color(self.correct),
+            wrong: basic_color(self.wrong),
+            ..*self
+        }
+    }
+}
+
+/// A user theme file. Colors that are left out come from [`DEFAULT`], except the
+/// grid state colors, which follow the file's own primary, secondary, success and
+/// error colors.
+#[derive(Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+struct ThemeFile {
+    /// Defaults to the file name.
+    id: Option<String>,
+    /// Defaults to the ID.
+    name: Option<String>,
+    primary: Option<String>,
+    secondary: Option<String>,
+    text: Option<String>,
+    dimmed: Option<String>,
+    success: Option<String>,
+    error: Option<String>,
+    grid_border: Option<String>,
+    filled_cell_bg: Option<String>,
+    filled_cell_fg: Option<String>,
+    selected_cell: Option<String>,
+    selected_word: Option<String>,
+    correct: Option<String>,
+    wrong: Option<String>,
+}
+
+/// Get the user themes directory path (`themes/` in the config directory).
+pub fn themes_dir() -> Option<PathBuf> {
+    crate::paths::config_dir().map(|dir| dir.join("themes"))
+}
+
+/// Parse a user theme file, with `stem` as the ID if the file has none.
+fn parse_theme(stem: &str, contents: &str) -> Result<Theme, String> {
+    let file: ThemeFile = serde_json::from_str(contents).map_err(|e| e.to_string())?;
+    let color = |value: &Option<String>, fallback: Color| match value {
+        Some(value) => Color::from_str(value).map_err(|_| format!("invalid color {:?}", value)),
+        None => Ok(fallback),
+    };
+
+    let id = file.id.clone().unwrap_or_else(|| stem.to_string());
+    if Theme::ALL.iter().any(|t| t.id == id) {
+        return Err(format!("theme ID {:?} is taken by a built-in theme", id));
+    }
+    let name = file.name.clone().unwrap_or_else(|| id.clone());
+    let primary = color(&file.primary, DEFAULT.primary)?;
+    let secondary = color(&file.secondary, DEFAULT.secondary)?;
+    let success = color(&file.success, DEFAULT.success)?;
+    let error = color(&file.error, DEFAULT.error)?;
+    Ok(Theme {
+        // themes live until the app exits; reloading leaks the old ones, which
+        // is fine for a handful of small structs
+        id: Box::leak(id.into_boxed_str()),
+        name: Box::leak(name.into_boxed_str()),
+        primary,
+        secondary,
+        text: color(&file.text, DEFAULT.text)?,
+        dimmed: color(&file.dimmed, DEFAULT.dimmed)?,
+        success,
+        error,
+        grid_border: color(&file.grid_border, DEFAULT.grid_border)?,
+        filled_cell_bg: color(&file.filled_cell_bg, DEFAULT.filled_cell_bg)?,
+        filled_cell_fg: color(&file.filled_cell_fg, DEFAULT.filled_cell_fg)?,
+        selected_cell: color(&file.selected_cell, primary)?,
+        selected_word: color(&file.selected_word, secondary)?,
+        correct: color(&file.correct, success)?,
+        wrong: color(&file.wrong, error)?,
+    })
+}
+
+/// Read every `*.json` theme file in a directory, sorted by file name.
+fn read_themes(dir: &Path) -> (Vec<Theme>, Vec<String>) {
+    let mut paths: Vec<PathBuf> = std::fs::read_dir(dir)
+        .map(|entries| entries.filter_map(|e| e.ok()).map(|e| e.path()).collect())
+        .unwrap_or_default();
+    paths.retain(|p| p.extension().is_some_and(|e| e == "json"));
+    paths.sort();
+
+    let (mut themes, mut errors) = (Vec::<Theme>::new(), Vec::new());
+    for path in paths {
+        let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+        let parsed = std::fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|contents| parse_theme(&stem, &contents));
+        match parsed {
+            Ok(theme) if themes.iter().any(|t| t.id == theme.id) => {
+                errors.push(format!("{}: duplicate theme ID {:?}", stem, theme.id));
+            }
+            Ok(theme) => themes.push(theme),
+            Err(e) => errors.push(format!("{}: {}", stem, e)),
+        }
+    }
+    (themes, errors)
+}
+
+/// (Re)load the user themes from the themes directory, returning the number
+/// loaded and an error message for each file that couldn't be.
+pub fn load_user_themes() -> (usize, Vec<String>) {
+    let (themes, errors) = themes_dir()
+        .map(|dir| read_themes(&dir))
+        .unwrap_or_default();
+    let count = themes.len();
+    let themes = themes
+        .into_iter()
+        .map(|theme| {
+            let basic: &'static Theme = Box::leak(Box::new(theme.basic()));
+            (&*Box::leak(Box::new(theme)), basic)
+        })
+        .collect();
+    *USER_THEMES.write().unwrap_or_else(|e| e.into_inner()) = themes;
+    (count, errors)
+}
+
+/// The 16 basic colors, with their xterm RGB values.
+const BASIC_COLORS: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (205, 0, 0)),
+    (Color::Green, (0, 205, 0)),
+    (Color::Yellow, (205, 205, 0)),
+    (Color::Blue, (0, 0, 238)),
+    (Color::Magenta, (205, 0, 205)),
+    (Color::Cyan, (0, 205, 205)),
+    (Color::Gray, (229, 229, 229)),
+    (Color::DarkGray, (127, 127, 127)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (92, 92, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+/// The basic color closest to an RGB color; other colors are kept.
+fn basic_color(color: Color) -> Color {
+    let Color::Rgb(r, g, b) = color else {
+        return color;
+    };
+    let distance = |(br, bg, bb): (u8, u8, u8)| {
+        [(r, br), (g, bg), (b, bb)]
+            .iter()
+            .map(|&(a, b)| (a as i32 - b as i32).pow(2))
+            .sum::<i32>()
+    };
+    BASIC_COLORS
+        .iter()
+        .min_by_key(|(_, rgb)| distance(*rgb))
+        .map(|(basic, _)| *basic)
+        .unwrap_or(color)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_theme() {
+        let theme = parse_theme(
+            "sol",
+            r##"{ "name": "Solarized", "primary": "#b58900", "error": "light-red" }"##,
+        )
+        .unwrap();
+        assert_eq!((theme.id, theme.name), ("sol", "Solarized"));
+        assert_eq!(theme.primary, Color::Rgb(0xb5, 0x89, 0x00));
+        assert_eq!(theme.error, Color::LightRed);
+        assert_eq!(theme.text, DEFAULT.text);
+        // state colors follow the theme's own colors
+        assert_eq!(theme.selected_cell, theme.primary);
+        assert_eq!(theme.wrong, Color::LightRed);
+
+        assert!(parse_theme("x", r#"{ "primary": "not a color" }"#).is_err());
+        assert!(parse_theme("x", r#"{ "primry": "red" }"#).is_err());
+        assert!(parse_theme("dark", "{}").is_err());
+    }
 }
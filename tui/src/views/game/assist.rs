@@ -0,0 +1,301 @@
+//! Check and reveal menu.
+//!
+//! Checking marks the wrong letters of the current letter, word or the whole
+//! puzzle until they are changed; revealing fills in the answer. Both are counted
+//! in the game's [`Assists`](crate::stats::Assists) and end up in the stats.
+//! Neither is possible without a solution, for locked `.puz` files and prize
+//! puzzles.
+
+use super::{Direction, GameView, PuzzleGrid, unchecked_reason};
+use crate::App;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Flex, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+use std::time::{Duration, Instant};
+
+/// Which cells a check or reveal applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssistScope {
+    /// The selected cell.
+    Letter,
+    /// The selected word, in the active direction if the cell has one.
+    Word,
+    /// Every letter cell.
+    Puzzle,
+}
+
+/// An entry of the check and reveal menu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssistAction {
+    Check(AssistScope),
+    Reveal(AssistScope),
+}
+
+impl AssistAction {
+    pub const ALL: [AssistAction; 6] = [
+        AssistAction::Check(AssistScope::Letter),
+        AssistAction::Check(AssistScope::Word),
+        AssistAction::Check(AssistScope::Puzzle),
+        AssistAction::Reveal(AssistScope::Letter),
+        AssistAction::Reveal(AssistScope::Word),
+        AssistAction::Reveal(AssistScope::Puzzle),
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            AssistAction::Check(AssistScope::Letter) => "Check letter",
+            AssistAction::Check(AssistScope::Word) => "Check word",
+            AssistAction::Check(AssistScope::Puzzle) => "Check puzzle",
+            AssistAction::Reveal(AssistScope::Letter) => "Reveal letter",
+            AssistAction::Reveal(AssistScope::Word) => "Reveal word",
+            AssistAction::Reveal(AssistScope::Puzzle) => "Reveal puzzle",
+        }
+    }
+
+    /// Shortcut for the action while playing, if it has one.
+    fn shortcut(&self) -> &'static str {
+        match self {
+            AssistAction::Check(_) => "",
+            AssistAction::Reveal(AssistScope::Letter) => "Ctrl+R",
+            AssistAction::Reveal(AssistScope::Word) => "Shift+Ctrl+R",
+            AssistAction::Reveal(AssistScope::Puzzle) => "Alt+Ctrl+R",
+        }
+    }
+}
+
+/// Positions of the letter cells in `scope`, around the selected cell `sel`.
+fn scope_cells(
+    grid: &PuzzleGrid,
+    scope: AssistScope,
+    sel: (usize, usize),
+    direction: Direction,
+) -> Vec<(usize, usize)> {
+    match scope {
+        AssistScope::Letter => vec![sel],
+        AssistScope::Word => {
+            let word = grid.get(sel.0, sel.1).and_then(|cell| {
+                [direction, direction.toggle()]
+                    .into_iter()
+                    .find_map(|dir| cell.clue_no_for_direction(dir).map(|n| (n, dir)))
+            });
+            word.map(|(clue_no, dir)| grid.word_cells(clue_no, dir))
+                .unwrap_or_default()
+        }
+        AssistScope::Puzzle => (0..grid.height() as usize)
+            .flat_map(|row| (0..grid.width() as usize).map(move |col| (row, col)))
+            .filter(|&(row, col)| grid.get(row, col).is_some_and(|cell| !cell.is_filled()))
+            .collect(),
+    }
+}
+
+impl App {
+    /// Open the check and reveal menu.
+    pub(super) fn open_assist_menu(&mut self) {
+        if self.refuse_unchecked() {
+            return;
+        }
+        self.state.game.assist_selection = 0;
+        self.view = crate::AppView::Game(GameView::Assist);
+    }
+
+    /// Draw the check and reveal menu over the game.
+    pub(super) fn draw_game_assist(&mut self, frame: &mut Frame) {
+        self.draw_game_playing(frame, false);
+
+        let area = frame.area();
+        let theme = self.state.theme;
+
+        let popup_width: u16 = 34;
+        // border (2) + actions + blank (1) + footer (1)
+        let popup_height: u16 = AssistAction::ALL.len() as u16 + 4;
+
+        let [centered_area] = Layout::horizontal([Constraint::Length(popup_width)])
+            .flex(Flex::Center)
+            .areas(area);
+
+        let [centered_area] = Layout::vertical([Constraint::Length(popup_height)])
+            .flex(Flex::Center)
+            .areas(centered_area);
+
+        frame.render_widget(Clear, centered_area);
+
+        let block = Block::default()
+            .title(" Check & Reveal ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.secondary));
+        let inner = block.inner(centered_area);
+        frame.render_widget(block, centered_area);
+
+        let selected_style = Style::default()
+            .fg(Color::Black)
+            .bg(theme.primary)
+            .add_modifier(Modifier::BOLD);
+        let mut lines: Vec<Line> = AssistAction::ALL
+            .iter()
+            .enumerate()
+            .map(|(i, action)| {
+                let (prefix, style) = if i == self.state.game.assist_selection {
+                    ("> ", selected_style)
+                } else {
+                    ("  ", Style::default().fg(theme.text))
+                };
+                let label = format!("{}{}", prefix, action.name());
+                let shortcut = action.shortcut();
+                let pad = (inner.width as usize).saturating_sub(label.len() + shortcut.len() + 1);
+                Line::from(vec![
+                    Span::styled(label, style),
+                    Span::raw(" ".repeat(pad)),
+                    Span::styled(shortcut, Style::default().fg(theme.dimmed)),
+                ])
+            })
+            .collect();
+
+        lines.push(Line::from(""));
+        lines.push(
+            Line::from(vec![
+                Span::styled("Enter", Style::default().fg(theme.primary)),
+                Span::styled(" apply • ", Style::default().fg(theme.dimmed)),
+                Span::styled("ESC", Style::default().fg(theme.primary)),
+                Span::styled(" close", Style::default().fg(theme.dimmed)),
+            ])
+            .centered(),
+        );
+
+        frame.render_widget(Paragraph::new(lines), inner);
+    }
+
+    pub(super) fn handle_assist_input(&mut self, key: KeyEvent) {
+        let selection = &mut self.state.game.assist_selection;
+        match key.code {
+            KeyCode::Esc => self.view = crate::AppView::Game(GameView::Playing),
+            KeyCode::Up => *selection = selection.saturating_sub(1),
+            KeyCode::Down => *selection = (*selection + 1).min(AssistAction::ALL.len() - 1),
+            KeyCode::Enter => {
+                self.view = crate::AppView::Game(GameView::Playing);
+                match AssistAction::ALL[*selection] {
+                    AssistAction::Check(scope) => self.check(scope),
+                    AssistAction::Reveal(scope) => self.reveal(scope),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Tell the user when there is no solution to check against, returning
+    /// whether that's the case.
+    fn refuse_unchecked(&mut self) -> bool {
+        let reason = self.state.game.puzzle.as_ref().and_then(unchecked_reason);
+        if let Some(reason) = reason {
+            self.state.game.notification = Some((
+                format!("{}, nothing to check against", reason),
+                Instant::now() + Duration::from_secs(3),
+            ));
+        }
+        reason.is_some()
+    }
+
+    /// Reveal the letters in `scope`, counting the ones that weren't already right.
+    pub(super) fn reveal(&mut self, scope: AssistScope) {
+        if self.refuse_unchecked() {
+            return;
+        }
+        let sel = self.state.game.sel;
+        let direction = self.state.game.active_direction;
+        let Some(grid) = self.state.game.grid.as_mut() else {
+            return;
+        };
+
+        let accept_unaccented = grid.accepts_unaccented();
+        let mut revealed = 0;
+        for (row, col) in scope_cells(grid, scope, sel, direction) {
+            if let Some(cell) = grid
+                .get_mut(row, col)
+                .filter(|c| c.is_correct(accept_unaccented) != Some(true))
+            {
+                cell.reveal();
+                revealed += 1;
+            }
+        }
+        self.state.game.assists.revealed += revealed;
+
+        if scope == AssistScope::Puzzle {
+            if let (Some(hot_seat), Some(grid)) = (
+                self.state.game.hot_seat.as_mut(),
+                self.state.game.grid.as_ref(),
+            ) {
+                hot_seat.settle_all(grid);
+            }
+        } else {
+            self.settle_hot_seat(false);
+            self.advance_to_next_cell();
+        }
+        self.check_completion();
+    }
+
+    /// Mark the wrong letters in `scope` and report how many there are.
+    fn check(&mut self, scope: AssistScope) {
+        let sel = self.state.game.sel;
+        let direction = self.state.game.active_direction;
+        let Some(grid) = self.state.game.grid.as_mut() else {
+            return;
+        };
+
+        let accept_unaccented = grid.accepts_unaccented();
+        let (mut checked, mut wrong) = (0, 0);
+        for (row, col) in scope_cells(grid, scope, sel, direction) {
+            let Some(cell) = grid.get_mut(row, col).filter(|c| !c.is_empty()) else {
+                continue;
+            };
+            checked += 1;
+            if cell.is_correct(accept_unaccented) == Some(false) {
+                cell.is_checked_wrong = true;
+                wrong += 1;
+            }
+        }
+        self.state.game.assists.checks += 1;
+
+        let message = match (checked, wrong) {
+            (0, _) => "Nothing to check".to_string(),
+            (_, 0) => "✓ No mistakes".to_string(),
+            (_, 1) => "✗ 1 wrong letter".to_string(),
+            (_, n) => format!("✗ {} wrong letters", n),
+        };
+        self.state.game.notification = Some((message, Instant::now() + Duration::from_secs(2)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scope_cells() {
+        // A B
+        // C .
+        let grid = PuzzleGrid::from_solution(&["AB".to_string(), "C.".to_string()]);
+        let cells = |scope, sel, direction| scope_cells(&grid, scope, sel, direction);
+
+        assert_eq!(
+            cells(AssistScope::Letter, (0, 1), Direction::Down),
+            [(0, 1)]
+        );
+        assert_eq!(
+            cells(AssistScope::Word, (0, 0), Direction::Down),
+            [(0, 0), (1, 0)]
+        );
+        // B has no down word, so its across word is used
+        assert_eq!(
+            cells(AssistScope::Word, (0, 1), Direction::Down),
+            [(0, 0), (0, 1)]
+        );
+        assert_eq!(
+            cells(AssistScope::Puzzle, (0, 0), Direction::Across),
+            [(0, 0), (0, 1), (1, 0)]
+        );
+    }
+}
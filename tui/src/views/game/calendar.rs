@@ -0,0 +1,186 @@
+//! Month calendar date picker for the new game screen.
+//!
+//! Days on which the selected provider publishes are highlighted. Arrow keys move
+//! by day or week, PgUp/PgDn by month, and `t`/`y` jump to today or yesterday.
+
+use super::SelectionField;
+use crate::App;
+use chrono::{Datelike, Days, Months, NaiveDate};
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Constraint, Flex, Layout},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+const CALENDAR_WIDTH: u16 = 30;
+const CALENDAR_HEIGHT: u16 = 14;
+
+impl App {
+    /// Open the date picker on the currently entered date (or today).
+    pub(super) fn open_calendar(&mut self) {
+        let selection = &mut self.state.game.selection;
+        let provider = selection.provider();
+        if !provider.supports_dates() {
+            selection.use_latest = true;
+            selection.error = Some(format!("{} only offers its latest puzzle", provider.name()));
+            return;
+        }
+        let date = NaiveDate::parse_from_str(&selection.date, "%Y-%m-%d")
+            .unwrap_or_else(|_| selection.provider().today());
+        selection.calendar = Some(date);
+    }
+
+    /// Use `date` for the new game and close the picker.
+    pub(super) fn pick_date(&mut self, date: NaiveDate) {
+        let selection = &mut self.state.game.selection;
+        let provider = selection.provider();
+
+        selection.date = date.format("%Y-%m-%d").to_string();
+        selection.use_latest = false;
+        selection.calendar = None;
+        selection.active_field = SelectionField::Date;
+        selection.error = if provider.is_available(date, provider.today()) {
+            None
+        } else {
+            Some(format!(
+                "No {} puzzle expected on this date",
+                provider.name()
+            ))
+        };
+    }
+
+    pub(super) fn handle_calendar_input(&mut self, key: KeyEvent) {
+        let Some(cursor) = self.state.game.selection.calendar else {
+            return;
+        };
+
+        let moved = match key.code {
+            KeyCode::Esc => {
+                self.state.game.selection.calendar = None;
+                return;
+            }
+            KeyCode::Enter => {
+                self.pick_date(cursor);
+                return;
+            }
+            KeyCode::Char('t') => {
+                self.pick_date(self.state.game.selection.provider().today());
+                return;
+            }
+            KeyCode::Char('y') => {
+                self.pick_date(self.state.game.selection.provider().today() - Days::new(1));
+                return;
+            }
+            KeyCode::Char('l') => {
+                self.state.game.selection.use_latest = true;
+                self.state.game.selection.calendar = None;
+                self.state.game.selection.error = None;
+                return;
+            }
+            KeyCode::Left => cursor.checked_sub_days(Days::new(1)),
+            KeyCode::Right => cursor.checked_add_days(Days::new(1)),
+            KeyCode::Up => cursor.checked_sub_days(Days::new(7)),
+            KeyCode::Down => cursor.checked_add_days(Days::new(7)),
+            KeyCode::PageUp => cursor.checked_sub_months(Months::new(1)),
+            KeyCode::PageDown => cursor.checked_add_months(Months::new(1)),
+            _ => None,
+        };
+
+        if let Some(date) = moved {
+            self.state.game.selection.calendar = Some(date);
+        }
+    }
+
+    /// Draw the date picker as a popup over the selection form.
+    pub(super) fn draw_calendar(&self, frame: &mut ratatui::Frame) {
+        let Some(cursor) = self.state.game.selection.calendar else {
+            return;
+        };
+        let theme = self.state.theme;
+        let provider = self.state.game.selection.provider();
+        let today = provider.today();
+
+        let [popup_area] = Layout::horizontal([Constraint::Length(CALENDAR_WIDTH)])
+            .flex(Flex::Center)
+            .areas(frame.area());
+        let [popup_area] = Layout::vertical([Constraint::Length(CALENDAR_HEIGHT)])
+            .flex(Flex::Center)
+            .areas(popup_area);
+        frame.render_widget(Clear, popup_area);
+
+        let block = Block::default()
+            .title(Span::styled(
+                format!(" {} ", cursor.format("%B %Y")),
+                Style::default().fg(theme.secondary),
+            ))
+            .title_alignment(ratatui::layout::Alignment::Center)
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.primary));
+        let inner = block.inner(popup_area);
+        frame.render_widget(block, popup_area);
+
+        let mut lines = vec![Line::from(Span::styled(
+            "Mo Tu We Th Fr Sa Su",
+            Style::default().fg(theme.dimmed),
+        ))];
+
+        // Weeks of the month, starting on Monday
+        let first = cursor.with_day(1).unwrap_or(cursor);
+        let mut day = first - Days::new(first.weekday().num_days_from_monday() as u64);
+        while day.month() == first.month() || day < first {
+            let mut spans = Vec::new();
+            for _ in 0..7 {
+                let text = if day.month() == first.month() {
+                    format!("{:>2}", day.day())
+                } else {
+                    "  ".to_string()
+                };
+                let mut style = if provider.is_available(day, today) {
+                    Style::default().fg(theme.text).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(theme.dimmed)
+                };
+                if day == today {
+                    style = style.add_modifier(Modifier::UNDERLINED);
+                }
+                if day == cursor {
+                    style = style.fg(theme.primary).add_modifier(Modifier::REVERSED);
+                }
+                if !spans.is_empty() {
+                    spans.push(Span::raw(" "));
+                }
+                spans.push(Span::styled(text, style));
+                day = day + Days::new(1);
+            }
+            lines.push(Line::from(spans));
+        }
+
+        // pad to six weeks so the popup does not jump around
+        while lines.len() < 7 {
+            lines.push(Line::from(""));
+        }
+
+        let schedule = if provider.supports_dates() {
+            format!("{}: {}", provider.name(), provider.schedule().name())
+        } else {
+            format!("{}: latest only", provider.name())
+        };
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            schedule,
+            Style::default().fg(theme.secondary),
+        )));
+        lines.push(Line::from(Span::styled(
+            "PgUp/PgDn month · t today",
+            Style::default().fg(theme.dimmed),
+        )));
+        lines.push(Line::from(Span::styled(
+            "y yesterday · l latest",
+            Style::default().fg(theme.dimmed),
+        )));
+
+        frame.render_widget(Paragraph::new(lines).centered(), inner);
+    }
+}
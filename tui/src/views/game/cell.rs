@@ -6,7 +6,9 @@ use ratatui::{
 };
 
 /// The active direction for navigation and clue display.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Default, serde::Serialize, serde::Deserialize,
+)]
 pub enum Direction {
     #[default]
     Across,
@@ -21,6 +23,22 @@ impl Direction {
             Direction::Down => Direction::Across,
         }
     }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Direction::Across => "Across",
+            Direction::Down => "Down",
+        }
+    }
+}
+
+impl From<cruciverbal_providers::entries::EntryDirection> for Direction {
+    fn from(direction: cruciverbal_providers::entries::EntryDirection) -> Self {
+        match direction {
+            cruciverbal_providers::entries::EntryDirection::Across => Direction::Across,
+            cruciverbal_providers::entries::EntryDirection::Down => Direction::Down,
+        }
+    }
 }
 
 /// A cell in the puzzle grid.
@@ -51,6 +69,8 @@ pub struct PuzzleCell {
     pub is_selected_cell: bool,
     /// Whether this cell is part of the currently selected word (but not the cursor).
     pub is_selected_word: bool,
+    /// Whether a check found the user letter wrong (cleared when the letter changes).
+    pub is_checked_wrong: bool,
 }
 
 /// The clue number(s) for the word(s) that pass through this cell.
@@ -131,6 +151,18 @@ pub enum PuzzleCellValue {
     },
 }
 
+/// A letter as drawn in a one-column cell. Letters that don't take exactly one
+/// column (wide or combining characters) are drawn without their accent, or as
+/// `?`, so they can't push the grid out of line.
+fn cell_letter(c: char) -> String {
+    let width = |c: char| Span::raw(c.to_string()).width();
+    [c, cruciverbal_providers::entries::base_letter(c)]
+        .into_iter()
+        .find(|&c| width(c) == 1)
+        .unwrap_or('?')
+        .to_string()
+}
+
 impl PuzzleCell {
     /// Check if the cell is empty.
     ///
@@ -150,6 +182,7 @@ impl PuzzleCell {
             val: PuzzleCellValue::Filled,
             is_selected_cell: false,
             is_selected_word: false,
+            is_checked_wrong: false,
         }
     }
 
@@ -164,6 +197,7 @@ impl PuzzleCell {
             },
             is_selected_cell: false,
             is_selected_word: false,
+            is_checked_wrong: false,
         }
     }
 
@@ -189,6 +223,7 @@ impl PuzzleCell {
     pub fn set_user_letter(&mut self, letter: Option<char>) {
         if let PuzzleCellValue::Letter { user_letter, .. } = &mut self.val {
             *user_letter = letter;
+            self.is_checked_wrong = false;
         }
     }
 
@@ -212,37 +247,51 @@ impl PuzzleCell {
         } = &mut self.val
         {
             *user_letter = Some(*clue_letter);
+            self.is_checked_wrong = false;
         }
     }
 
-    pub fn to_val_span(&self, theme: &Theme) -> Span {
+    /// Returns the span for the cell's value.
+    ///
+    /// With `auto_check`, user letters are colored by the theme's correct and wrong
+    /// colors; letters a check found wrong are always shown in the wrong color.
+    pub fn to_val_span(
+        &self,
+        theme: &Theme,
+        auto_check: bool,
+        accept_unaccented: bool,
+        chars: &BoxChars,
+    ) -> Span {
         match &self.val {
             PuzzleCellValue::Filled => {
-                Span::styled(BOX_FILLED.to_string(), Style::default().bg(theme.filled_cell_bg))
+                Span::styled(chars.filled.to_string(), Style::default().bg(theme.filled_cell_bg))
             }
             PuzzleCellValue::Letter { user_letter, .. } => match user_letter {
-                Some(c) => Span::raw(c.to_string()),
-                None => Span::raw(BOX_EMPTY.to_string()),
+                Some(c)
+                    if self.is_checked_wrong
+                        || (auto_check && self.is_correct(accept_unaccented) == Some(false)) =>
+                {
+                    Span::styled(cell_letter(*c), Style::default().fg(theme.wrong).bold())
+                }
+                Some(c) if auto_check && self.is_correct(accept_unaccented) == Some(true) => {
+                    Span::styled(cell_letter(*c), Style::default().fg(theme.correct))
+                }
+                Some(c) => Span::raw(cell_letter(*c)),
+                None => Span::raw(chars.empty.to_string()),
             },
         }
     }
 
-    /// Returns spans for the clue number area (three characters).
-    ///
-    /// A clue number is displayed only at the start of a word (word index 0).
-    /// Supports 1-3 digit numbers, filling unused positions with horizontal border.
-    pub fn to_no_spans(&self, border_style: Style, theme: &Theme) -> (Span, Span, Span) {
-        let h_span = || Span::styled(BOX_H.to_string(), border_style);
-
+    /// The clue number shown in this cell, if a word starts here.
+    pub fn clue_number(&self) -> Option<usize> {
         let PuzzleCellValue::Letter {
             clue_no, word_idx, ..
         } = &self.val
         else {
-            return (h_span(), h_span(), h_span());
+            return None;
         };
 
-        // Determine the clue number to display (only at word start, index 0)
-        let clue_number = match (clue_no, word_idx) {
+        match (clue_no, word_idx) {
             (
                 ClueNoDirection::Across(n),
                 WordIdxDirection::Across(0) | WordIdxDirection::Cross(0, _),
@@ -258,16 +307,29 @@ impl PuzzleCell {
             (ClueNoDirection::Cross(a, _), WordIdxDirection::Cross(0, _)) => Some(*a),
             (ClueNoDirection::Cross(_, d), WordIdxDirection::Cross(_, 0)) => Some(*d),
             _ => None,
-        };
+        }
+    }
+
+    /// Returns spans for the clue number area (three characters).
+    ///
+    /// A clue number is displayed only at the start of a word (word index 0).
+    /// Supports 1-3 digit numbers, filling unused positions with horizontal border.
+    pub fn to_no_spans(
+        &self,
+        border_style: Style,
+        theme: &Theme,
+        chars: &BoxChars,
+    ) -> (Span, Span, Span) {
+        let h_span = || Span::styled(chars.h.to_string(), border_style);
 
-        let Some(n) = clue_number else {
+        let Some(n) = self.clue_number() else {
             return (h_span(), h_span(), h_span());
         };
 
         let no_style = if self.is_selected_cell {
-            Style::default().fg(theme.primary).bold()
+            Style::default().fg(theme.selected_cell).bold()
         } else if self.is_selected_word {
-            Style::default().fg(theme.secondary).bold()
+            Style::default().fg(theme.selected_word).bold()
         } else {
             border_style
         };
@@ -294,9 +356,9 @@ impl PuzzleCell {
 
     pub fn to_selection_span(&self, theme: &Theme) -> Span {
         if self.is_selected_cell {
-            Span::raw("^").style(Style::default().fg(theme.primary).bold().underlined())
+            Span::raw("^").style(Style::default().fg(theme.selected_cell).bold().underlined())
         } else if self.is_selected_word {
-            Span::raw("_").style(Style::default().fg(theme.secondary))
+            Span::raw("_").style(Style::default().fg(theme.selected_word))
         } else {
             Span::raw(" ")
         }
@@ -338,16 +400,19 @@ impl PuzzleCell {
     /// Check if the user's letter matches the clue letter.
     ///
     /// Returns `None` if the cell is filled or has no user letter.
-    /// Returns `Some(true)` if user_letter matches clue_letter.
+    /// Returns `Some(true)` if user_letter matches clue_letter (see
+    /// [`letters_match`](cruciverbal_providers::entries::letters_match) for accents).
     /// Returns `Some(false)` if they don't match.
-    pub fn is_correct(&self) -> Option<bool> {
+    pub fn is_correct(&self, accept_unaccented: bool) -> Option<bool> {
         match &self.val {
             PuzzleCellValue::Filled => None,
             PuzzleCellValue::Letter {
                 clue_letter,
                 user_letter,
                 ..
-            } => user_letter.map(|u| u == *clue_letter),
+            } => user_letter.map(|u| {
+                cruciverbal_providers::entries::letters_match(u, *clue_letter, accept_unaccented)
+            }),
         }
     }
 }
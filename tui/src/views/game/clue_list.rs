@@ -0,0 +1,222 @@
+//! Across/Down clue list panels shown next to the grid.
+//!
+//! Each panel scrolls to keep the active clue visible, highlights it, and dims
+//! clues whose entries are completely filled.
+
+use super::{Direction, GameView};
+use crate::App;
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+};
+
+/// Width of the clue panel column, including borders.
+pub const CLUE_PANEL_WIDTH: u16 = 40;
+
+/// Render and scroll state for one clue list panel.
+#[derive(Debug, Default, Clone)]
+pub struct CluePanel {
+    /// Screen area the panel was last rendered in (for mouse hit-testing).
+    pub area: Rect,
+    /// Current scroll offset in lines.
+    pub scroll: u16,
+    /// Rendered line range `[start, end)` of each clue, by clue number.
+    pub rows: Vec<(u16, u16, usize)>,
+    /// Clue that was active on the previous draw; scrolling follows it when it changes.
+    pub last_active: Option<usize>,
+}
+
+impl CluePanel {
+    /// Clue number rendered at a screen row inside the panel, if any.
+    pub fn clue_at(&self, y: u16) -> Option<usize> {
+        let inner_top = self.area.y + 1;
+        if y < inner_top || y >= self.area.y + self.area.height.saturating_sub(1) {
+            return None;
+        }
+        let line = y - inner_top + self.scroll;
+        self.rows
+            .iter()
+            .find(|(start, end, _)| line >= *start && line < *end)
+            .map(|(_, _, clue_no)| *clue_no)
+    }
+
+    /// Scroll by `delta` lines, clamped to the content.
+    pub fn scroll_by(&mut self, delta: i32) {
+        let content = self.rows.last().map_or(0, |(_, end, _)| *end);
+        let visible = self.area.height.saturating_sub(2);
+        let max = content.saturating_sub(visible);
+        self.scroll = (self.scroll as i32 + delta).clamp(0, max as i32) as u16;
+    }
+
+    /// Scroll by a page, keeping one line of the previous page in view.
+    pub fn page(&mut self, forward: bool) {
+        let page = self.area.height.saturating_sub(3).max(1) as i32;
+        self.scroll_by(if forward { page } else { -page });
+    }
+}
+
+fn panel_index(direction: Direction) -> usize {
+    match direction {
+        Direction::Across => 0,
+        Direction::Down => 1,
+    }
+}
+
+impl App {
+    /// Draw the Across and Down clue panels stacked in `area`.
+    pub(super) fn draw_clue_panels(&mut self, frame: &mut ratatui::Frame, area: Rect) {
+        let [across_area, down_area] =
+            Layout::vertical([Constraint::Percentage(50), Constraint::Percentage(50)]).areas(area);
+        self.draw_clue_panel(frame, across_area, Direction::Across);
+        self.draw_clue_panel(frame, down_area, Direction::Down);
+    }
+
+    fn draw_clue_panel(&mut self, frame: &mut ratatui::Frame, area: Rect, direction: Direction) {
+        let (Some(puzzle), Some(grid)) = (
+            self.state.game.puzzle.as_ref(),
+            self.state.game.grid.as_ref(),
+        ) else {
+            return;
+        };
+        let theme = self.state.theme;
+        let clues = match direction {
+            Direction::Across => &puzzle.clues.across,
+            Direction::Down => &puzzle.clues.down,
+        };
+
+        // The clue under the cursor in this direction; the active direction's clue is highlighted
+        let (row, col) = self.state.game.sel;
+        let current = grid
+            .get(row, col)
+            .and_then(|cell| cell.clue_no_for_direction(direction));
+        let is_active_direction = self.state.game.active_direction == direction;
+        // Solved clues are only marked when that gives nothing away, and only
+        // when there is a solution to check against
+        let is_completed = matches!(
+            self.view,
+            crate::AppView::Game(GameView::Completed | GameView::CompletedPlaying)
+        );
+        let show_solved = self.render_options().auto_check || (is_completed && self.can_check());
+        let chars = self.render_options().box_chars();
+
+        let is_active_panel = is_active_direction && current.is_some();
+        let block = Block::default()
+            .title(Span::styled(
+                format!(" {} ", direction.name()),
+                Style::default().fg(if is_active_panel {
+                    theme.primary
+                } else {
+                    theme.secondary
+                }),
+            ))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.dimmed));
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let mut numbers: Vec<u16> = clues.keys().copied().collect();
+        numbers.sort_unstable();
+
+        let mut lines: Vec<Line> = Vec::new();
+        let mut rows: Vec<(u16, u16, usize)> = Vec::new();
+        let mut line_no: u16 = 0;
+        for number in numbers {
+            let clue_no = number as usize;
+            let is_current = current == Some(clue_no);
+            let style = if is_current && is_active_direction {
+                Style::default()
+                    .fg(theme.primary)
+                    .add_modifier(Modifier::BOLD)
+            } else if is_current {
+                Style::default().fg(theme.secondary)
+            } else if grid.is_word_filled(clue_no, direction) {
+                Style::default().fg(theme.dimmed)
+            } else {
+                Style::default().fg(theme.text)
+            };
+
+            let mut spans = vec![Span::styled(
+                format!("{:>3} ", number),
+                style.add_modifier(Modifier::BOLD),
+            )];
+            if show_solved && grid.word_progress(clue_no, direction).is_solved() {
+                spans.push(Span::styled(
+                    format!("{} ", chars.check),
+                    Style::default().fg(theme.correct),
+                ));
+            }
+            spans.push(Span::styled(clues[&number].clone(), style));
+            let line = Line::from(spans);
+            let height = Paragraph::new(line.clone())
+                .wrap(Wrap { trim: true })
+                .line_count(inner.width)
+                .max(1) as u16;
+            rows.push((line_no, line_no + height, clue_no));
+            line_no += height;
+            lines.push(line);
+        }
+
+        let panel = &mut self.state.game.clue_panels[panel_index(direction)];
+        panel.area = area;
+        panel.rows = rows;
+
+        // Follow the current clue when it changes, leaving manual scrolling alone otherwise
+        if current != panel.last_active {
+            panel.last_active = current;
+            if let Some(&(start, end, _)) = panel
+                .rows
+                .iter()
+                .find(|(_, _, clue_no)| Some(*clue_no) == current)
+            {
+                if start < panel.scroll {
+                    panel.scroll = start;
+                } else if end > panel.scroll + inner.height {
+                    panel.scroll = end.saturating_sub(inner.height);
+                }
+            }
+        }
+        panel.scroll_by(0);
+
+        frame.render_widget(
+            Paragraph::new(lines)
+                .wrap(Wrap { trim: true })
+                .scroll((panel.scroll, 0)),
+            inner,
+        );
+    }
+
+    /// Page through the clue panel of the active direction (PageUp/PageDown).
+    pub(super) fn page_clue_panel(&mut self, forward: bool) {
+        let direction = self.state.game.active_direction;
+        self.state.game.clue_panels[panel_index(direction)].page(forward);
+    }
+
+    /// Jump to the first empty cell of a clue (or its first cell if it is filled).
+    pub(super) fn select_clue(&mut self, clue_no: usize, direction: Direction) {
+        let Some(grid) = self.state.game.grid.as_ref() else {
+            return;
+        };
+        let word = grid.word_cells(clue_no, direction);
+        let target = word
+            .iter()
+            .find(|&&(r, c)| grid.get(r, c).is_some_and(|cell| cell.is_empty()))
+            .or(word.first())
+            .copied();
+
+        if let Some((row, col)) = target {
+            self.state.game.active_direction = direction;
+            let selected = self
+                .state
+                .game
+                .grid
+                .as_mut()
+                .is_some_and(|grid| grid.set_selection(row, col, direction));
+            if selected {
+                self.state.game.sel = (row, col);
+            }
+            self.ensure_selection_visible();
+        }
+    }
+}
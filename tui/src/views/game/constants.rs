@@ -1,14 +1,66 @@
+/// Characters used to draw the grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoxChars {
+    pub h: char,
+    pub v: char,
+    pub tl: char,
+    pub tr: char,
+    pub bl: char,
+    pub br: char,
+    pub t: char,
+    pub b: char,
+    pub l: char,
+    pub r: char,
+    pub x: char,
+    pub empty: char,
+    pub filled: char,
+    /// Empty cell in compact rendering (no borders).
+    pub compact_empty: char,
+    /// Marks a correctly solved clue.
+    pub check: char,
+    /// Filled and empty parts of a progress gauge.
+    pub gauge_full: char,
+    pub gauge_empty: char,
+}
+
 // box drawing characters
-pub const BOX_H: char = '═';
-pub const BOX_V: char = '║';
-pub const BOX_TL: char = '╔';
-pub const BOX_TR: char = '╗';
-pub const BOX_BL: char = '╚';
-pub const BOX_BR: char = '╝';
-pub const BOX_T: char = '╦';
-pub const BOX_B: char = '╩';
-pub const BOX_L: char = '╠';
-pub const BOX_R: char = '╣';
-pub const BOX_X: char = '╬';
-pub const BOX_EMPTY: char = ' ';
-pub const BOX_FILLED: char = '█';
+pub const BOX: BoxChars = BoxChars {
+    h: '═',
+    v: '║',
+    tl: '╔',
+    tr: '╗',
+    bl: '╚',
+    br: '╝',
+    t: '╦',
+    b: '╩',
+    l: '╠',
+    r: '╣',
+    x: '╬',
+    empty: ' ',
+    filled: '█',
+    compact_empty: '·',
+    check: '✓',
+    gauge_full: '█',
+    gauge_empty: '░',
+};
+
+// plain ASCII fallback, for terminals without box drawing characters
+pub const ASCII_BOX: BoxChars = BoxChars {
+    h: '-',
+    v: '|',
+    tl: '+',
+    tr: '+',
+    bl: '+',
+    br: '+',
+    t: '+',
+    b: '+',
+    l: '+',
+    r: '+',
+    x: '+',
+    empty: ' ',
+    filled: '#',
+    compact_empty: '.',
+    check: '*',
+    gauge_full: '#',
+    gauge_empty: '-',
+};
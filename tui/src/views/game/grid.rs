@@ -1,5 +1,5 @@
 use ratatui::{
-    style::Style,
+    style::{Modifier, Style, Stylize},
     text::{Line, Span},
     widgets::Paragraph,
 };
@@ -8,13 +8,128 @@ use super::constants::*;
 use super::{ClueNoDirection, Direction, PuzzleCell, WordIdxDirection};
 use crate::theme::Theme;
 
+/// How much of a word (or the whole grid) is filled in, and filled in correctly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Progress {
+    /// Cells with a user letter.
+    pub filled: usize,
+    /// Cells with the correct user letter.
+    pub correct: usize,
+    /// Letter cells.
+    pub total: usize,
+}
+
+impl Progress {
+    fn of<'a>(cells: impl Iterator<Item = &'a PuzzleCell>, accept_unaccented: bool) -> Self {
+        cells
+            .filter(|cell| !cell.is_filled())
+            .fold(Progress::default(), |progress, cell| Progress {
+                filled: progress.filled + !cell.is_empty() as usize,
+                correct: progress.correct
+                    + (cell.is_correct(accept_unaccented) == Some(true)) as usize,
+                total: progress.total + 1,
+            })
+    }
+
+    /// Filled cells as a percentage (0-100), 100 when there are no cells.
+    pub fn percentage(&self) -> u8 {
+        if self.total == 0 {
+            return 100;
+        }
+        ((self.filled * 100) / self.total) as u8
+    }
+
+    /// Whether every cell has the correct letter.
+    pub fn is_solved(&self) -> bool {
+        self.total > 0 && self.correct == self.total
+    }
+}
+
+/// Options controlling how the grid is rendered.
+#[derive(Debug, Clone, Copy)]
+pub struct RenderOptions {
+    /// Color user letters by whether they are correct.
+    pub auto_check: bool,
+    /// Draw clue numbers in the cell borders.
+    pub show_numbers: bool,
+    /// Large-print cells with bold letters.
+    pub zoom: bool,
+    /// Borderless cells, one line per row (takes precedence over `zoom`).
+    pub compact: bool,
+    /// Draw with plain ASCII characters instead of box drawing characters.
+    pub ascii: bool,
+}
+
+impl RenderOptions {
+    /// Size of a rendered cell in (columns, lines), including its shared left and
+    /// top borders.
+    pub fn cell_size(&self) -> (u16, u16) {
+        match (self.compact, self.zoom) {
+            (true, _) => (2, 1),
+            (false, true) => (8, 5),
+            (false, false) => (4, 4),
+        }
+    }
+
+    /// Characters to draw the grid with.
+    pub fn box_chars(&self) -> &'static BoxChars {
+        if self.ascii { &ASCII_BOX } else { &BOX }
+    }
+
+    /// Width of the closing right/bottom border of the grid (none when compact).
+    pub fn border_size(&self) -> u16 {
+        if self.compact { 0 } else { 1 }
+    }
+
+    /// Size of a rendered `width` x `height` grid in (columns, lines).
+    pub fn grid_size(&self, width: u8, height: u8) -> (u16, u16) {
+        let (cell_width, cell_height) = self.cell_size();
+        (
+            width as u16 * cell_width + self.border_size(),
+            height as u16 * cell_height + self.border_size(),
+        )
+    }
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        Self {
+            auto_check: false,
+            show_numbers: true,
+            zoom: false,
+            compact: false,
+            ascii: false,
+        }
+    }
+}
+
 /// A grid of cells.
 #[derive(Debug)]
 pub struct PuzzleGrid {
     cells: Vec<Vec<PuzzleCell>>,
+    /// Whether an unaccented letter is right for an accented answer letter.
+    accept_unaccented: bool,
 }
 
 impl PuzzleGrid {
+    /// Set whether an unaccented letter is right for an accented answer letter
+    /// (`E` for `É`). On by default.
+    pub fn set_accept_unaccented(&mut self, accept: bool) {
+        self.accept_unaccented = accept;
+    }
+
+    /// Whether an unaccented letter is right for an accented answer letter.
+    pub fn accepts_unaccented(&self) -> bool {
+        self.accept_unaccented
+    }
+
+    /// Whether a cell has the right letter, `None` if it has no letter.
+    ///
+    /// See [`PuzzleCell::is_correct`].
+    pub fn is_cell_correct(&self, cell: &PuzzleCell) -> Option<bool> {
+        cell.is_correct(self.accept_unaccented)
+    }
+
     pub fn cells(&self) -> &[Vec<PuzzleCell>] {
         &self.cells
     }
@@ -101,6 +216,104 @@ impl PuzzleGrid {
             .for_each(|cell| cell.reveal());
     }
 
+    /// Positions of all cells in a word, in reading order.
+    pub fn word_cells(&self, clue_no: usize, direction: Direction) -> Vec<(usize, usize)> {
+        let mut cells = Vec::new();
+        for (row_idx, row) in self.cells.iter().enumerate() {
+            for (col_idx, cell) in row.iter().enumerate() {
+                if cell.clue_no_for_direction(direction) == Some(clue_no) {
+                    cells.push((row_idx, col_idx));
+                }
+            }
+        }
+        cells
+    }
+
+    /// Check if every cell of a word has a user letter.
+    pub fn is_word_filled(&self, clue_no: usize, direction: Direction) -> bool {
+        self.word_cells(clue_no, direction)
+            .iter()
+            .all(|&(row, col)| self.get(row, col).is_some_and(|cell| !cell.is_empty()))
+    }
+
+    /// Check if every cell of a word has the correct user letter.
+    pub fn is_word_correct(&self, clue_no: usize, direction: Direction) -> bool {
+        self.word_cells(clue_no, direction)
+            .iter()
+            .all(|&(row, col)| {
+                self.get(row, col)
+                    .and_then(|cell| self.is_cell_correct(cell))
+                    == Some(true)
+            })
+    }
+
+    /// Fill and correctness of a word.
+    pub fn word_progress(&self, clue_no: usize, direction: Direction) -> Progress {
+        Progress::of(
+            self.word_cells(clue_no, direction)
+                .into_iter()
+                .filter_map(|(row, col)| self.get(row, col)),
+            self.accept_unaccented,
+        )
+    }
+
+    /// Fill and correctness of the whole grid.
+    pub fn progress(&self) -> Progress {
+        Progress::of(self.cells.iter().flatten(), self.accept_unaccented)
+    }
+
+    /// Sorted clue numbers of all words in the given direction.
+    pub fn clue_numbers(&self, direction: Direction) -> Vec<usize> {
+        let mut numbers: Vec<usize> = self
+            .cells
+            .iter()
+            .flat_map(|row| row.iter())
+            .filter_map(|cell| cell.clue_no_for_direction(direction))
+            .collect();
+        numbers.sort_unstable();
+        numbers.dedup();
+        numbers
+    }
+
+    /// The unfinished word that looks easiest to work on next, as (clue number, direction).
+    ///
+    /// Words with the largest share of their letters already in place come first,
+    /// then words with more crossings (more chances to get help from other clues),
+    /// then longer words. Returns `None` once every word is filled.
+    pub fn suggested_word(&self) -> Option<(usize, Direction)> {
+        // (filled, length, crossings, word) of the best word so far
+        let mut best: Option<(usize, usize, usize, (usize, Direction))> = None;
+        for direction in [Direction::Across, Direction::Down] {
+            for clue_no in self.clue_numbers(direction) {
+                let cells: Vec<&PuzzleCell> = self
+                    .word_cells(clue_no, direction)
+                    .into_iter()
+                    .filter_map(|(row, col)| self.get(row, col))
+                    .collect();
+                let len = cells.len();
+                let filled = cells.iter().filter(|cell| !cell.is_empty()).count();
+                if filled == len {
+                    continue;
+                }
+                let crossings = cells
+                    .iter()
+                    .filter(|cell| cell.has_direction(direction.toggle()))
+                    .count();
+
+                let better = best.is_none_or(|(best_filled, best_len, best_crossings, _)| {
+                    // compare filled/len fractions without dividing
+                    let (share, best_share) = (filled * best_len, best_filled * len);
+                    share > best_share
+                        || (share == best_share && (crossings, len) > (best_crossings, best_len))
+                });
+                if better {
+                    best = Some((filled, len, crossings, (clue_no, direction)));
+                }
+            }
+        }
+        best.map(|(_, _, _, word)| word)
+    }
+
     /// Reveal all cells in the grid.
     pub fn reveal_all(&mut self) {
         self.cells
@@ -121,22 +334,17 @@ impl PuzzleGrid {
         None
     }
 
-    /// Count the total number of letter cells (excluding filled/black cells).
-    pub fn count_total_letters(&self) -> usize {
-        self.cells
-            .iter()
-            .flat_map(|row| row.iter())
-            .filter(|cell| !cell.is_filled())
-            .count()
-    }
-
-    /// Count the number of letter cells that have a user-entered letter.
-    pub fn count_filled_letters(&self) -> usize {
+    /// Find the last non-filled cell in the grid (scanning rows bottom to top).
+    pub fn find_last_letter_cell(&self) -> Option<(usize, usize)> {
         self.cells
             .iter()
-            .flat_map(|row| row.iter())
-            .filter(|cell| cell.get_user_letter().is_some())
-            .count()
+            .enumerate()
+            .rev()
+            .find_map(|(row_idx, row)| {
+                row.iter()
+                    .rposition(|cell| !cell.is_filled())
+                    .map(|col_idx| (row_idx, col_idx))
+            })
     }
 
     /// Check if all letter cells have been filled correctly.
@@ -147,17 +355,32 @@ impl PuzzleGrid {
             .iter()
             .flat_map(|row| row.iter())
             .filter(|cell| !cell.is_filled())
-            .all(|cell| cell.is_correct() == Some(true))
+            .all(|cell| self.is_cell_correct(cell) == Some(true))
     }
 
     /// Get completion percentage (0-100).
     pub fn completion_percentage(&self) -> u8 {
-        let total = self.count_total_letters();
-        if total == 0 {
-            return 100;
-        }
-        let filled = self.count_filled_letters();
-        ((filled * 100) / total) as u8
+        self.progress().percentage()
+    }
+
+    /// The current fill as plain text, one line per row: entered letters, `.` for
+    /// black cells and `_` for unsolved cells.
+    pub fn to_fill_text(&self) -> String {
+        self.cells
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|cell| {
+                        if cell.is_filled() {
+                            '.'
+                        } else {
+                            cell.get_user_letter().unwrap_or('_')
+                        }
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
     }
 
     /// Create a new [`PuzzleGrid`] from a 2D vector of [`PuzzleCell`]s.
@@ -170,7 +393,10 @@ impl PuzzleGrid {
             assert!(row.len() == row_len, "All rows must have the same length");
         }
 
-        Self { cells }
+        Self {
+            cells,
+            accept_unaccented: true,
+        }
     }
 
     /// Width of the grid in number of cells, as `u8` for `.puz` compatibility.
@@ -308,17 +534,32 @@ impl PuzzleGrid {
 
     /// Convert a [`PuzzleCell`] grid to a [`Paragraph`] for rendering.
     ///
-    /// Each cell is 4 characters wide by 4 lines tall. Adjacent cells share borders,
-    /// so we only draw the left and top borders for each cell, plus the right and
-    /// bottom borders for the last column/row.
-    pub fn to_par(&self, theme: &Theme) -> Paragraph {
+    /// Each cell is 4 characters wide by 4 lines tall (8 by 5 when zoomed, see
+    /// [`RenderOptions::cell_size`]). Adjacent cells share borders, so we only draw
+    /// the left and top borders for each cell, plus the right and bottom borders for
+    /// the last column/row.
+    pub fn to_par(&self, theme: &Theme, options: RenderOptions) -> Paragraph {
+        if options.compact {
+            return self.to_compact_par(theme, options);
+        }
+
         let num_rows = self.cells.len();
         let num_cols = self.cells[0].len();
         let border_style = Style::default().fg(theme.grid_border);
+        let chars = options.box_chars();
+
+        // Interior size of a cell, excluding its shared left/top borders
+        let (cell_width, cell_height) = options.cell_size();
+        let inner_width = cell_width as usize - 1;
+        let inner_height = cell_height as usize - 1;
+        // Letter sits in the middle column, on the second interior line, with the
+        // selection marker right below it
+        let mid_col = inner_width / 2;
+        let val_line = 2;
 
         // Helper closures for common spans
-        let h_span = || Span::styled(BOX_H.to_string(), border_style);
-        let v_span = || Span::styled(BOX_V.to_string(), border_style);
+        let h_span = || Span::styled(chars.h.to_string(), border_style);
+        let v_span = || Span::styled(chars.v.to_string(), border_style);
         let empty = || Span::raw(" ");
         let corner = |c: char| Span::styled(c.to_string(), border_style);
 
@@ -328,61 +569,89 @@ impl PuzzleGrid {
             let is_first_row = row_idx == 0;
             let is_last_row = row_idx == num_rows - 1;
 
-            // Each cell row produces 4 lines (5 for last row to include bottom border)
-            let num_lines = if is_last_row { 5 } else { 4 };
+            // Each cell row produces `cell_height` lines (+1 for last row to include bottom border)
+            let num_lines = if is_last_row {
+                cell_height as usize + 1
+            } else {
+                cell_height as usize
+            };
             let mut span_groups: Vec<Vec<Span>> = vec![Vec::new(); num_lines];
 
             for (col_idx, cell) in cell_row.iter().enumerate() {
                 let is_first_col = col_idx == 0;
                 let is_last_col = col_idx == num_cols - 1;
 
-                let val_span = cell.to_val_span(theme);
+                let mut val_span =
+                    cell.to_val_span(theme, options.auto_check, self.accept_unaccented, chars);
+                if options.zoom {
+                    val_span = val_span.bold();
+                }
                 let selection_span = cell.to_selection_span(theme);
-                let (no_span_1, no_span_2, no_span_3) = cell.to_no_spans(border_style, theme);
+                let (no_span_1, no_span_2, no_span_3) = if options.show_numbers {
+                    cell.to_no_spans(border_style, theme, chars)
+                } else {
+                    (h_span(), h_span(), h_span())
+                };
 
                 // Top-left corner: depends on position in grid
                 let tl_corner = match (is_first_row, is_first_col) {
-                    (true, true) => BOX_TL,
-                    (true, false) => BOX_T,
-                    (false, true) => BOX_L,
-                    (false, false) => BOX_X,
+                    (true, true) => chars.tl,
+                    (true, false) => chars.t,
+                    (false, true) => chars.l,
+                    (false, false) => chars.x,
                 };
                 span_groups[0].extend([corner(tl_corner), no_span_1, no_span_2, no_span_3]);
+                span_groups[0].extend((3..inner_width).map(|_| h_span()));
 
                 // Top-right corner for last column
                 if is_last_col {
-                    let tr_corner = if is_first_row { BOX_TR } else { BOX_R };
+                    let tr_corner = if is_first_row { chars.tr } else { chars.r };
                     span_groups[0].push(corner(tr_corner));
                 }
 
-                // Content lines (3 lines with left border)
-                if cell.is_filled() {
-                    // Filled cells: solid block across all 3 interior positions
-                    let filled = || Span::styled(BOX_FILLED.to_string(), Style::default().fg(theme.filled_cell_fg));
-                    span_groups[1].extend([v_span(), filled(), filled(), filled()]);
-                    span_groups[2].extend([v_span(), filled(), filled(), filled()]);
-                    span_groups[3].extend([v_span(), filled(), filled(), filled()]);
-                } else {
-                    // Letter cells: normal layout with value in center
-                    span_groups[1].extend([v_span(), empty(), empty(), empty()]);
-                    span_groups[2].extend([v_span(), empty(), val_span, empty()]);
-                    span_groups[3].extend([v_span(), empty(), selection_span, empty()]);
-                }
+                // Content lines (with left border)
+                for (line, spans) in span_groups
+                    .iter_mut()
+                    .enumerate()
+                    .take(inner_height + 1)
+                    .skip(1)
+                {
+                    spans.push(v_span());
+                    for col in 0..inner_width {
+                        let span = if cell.is_filled() {
+                            // Filled cells: solid block across all interior positions
+                            Span::styled(
+                                chars.filled.to_string(),
+                                Style::default().fg(theme.filled_cell_fg),
+                            )
+                        } else if col != mid_col {
+                            empty()
+                        } else if line == val_line {
+                            // Letter cells: value in center, selection marker below
+                            val_span.clone()
+                        } else if line == val_line + 1 {
+                            selection_span.clone()
+                        } else {
+                            empty()
+                        };
+                        spans.push(span);
+                    }
 
-                // Right border for last column
-                if is_last_col {
-                    span_groups[1].push(v_span());
-                    span_groups[2].push(v_span());
-                    span_groups[3].push(v_span());
+                    // Right border for last column
+                    if is_last_col {
+                        spans.push(v_span());
+                    }
                 }
 
                 // Bottom border for last row
                 if is_last_row {
-                    let bl_corner = if is_first_col { BOX_BL } else { BOX_B };
-                    span_groups[4].extend([corner(bl_corner), h_span(), h_span(), h_span()]);
+                    let bl_corner = if is_first_col { chars.bl } else { chars.b };
+                    let bottom = &mut span_groups[cell_height as usize];
+                    bottom.push(corner(bl_corner));
+                    bottom.extend((0..inner_width).map(|_| h_span()));
 
                     if is_last_col {
-                        span_groups[4].push(corner(BOX_BR));
+                        bottom.push(corner(chars.br));
                     }
                 }
             }
@@ -394,6 +663,53 @@ impl PuzzleGrid {
 
         Paragraph::new(all_lines)
     }
+
+    /// Convert the grid to a compact [`Paragraph`]: one line per row, two characters
+    /// per cell and no borders or clue numbers.
+    ///
+    /// The selected cell is shown reversed and the selected word underlined.
+    fn to_compact_par(&self, theme: &Theme, options: RenderOptions) -> Paragraph<'_> {
+        let chars = options.box_chars();
+        let lines: Vec<Line> = self
+            .cells
+            .iter()
+            .map(|cell_row| {
+                let spans = cell_row.iter().map(|cell| {
+                    if cell.is_filled() {
+                        return Span::styled(
+                            chars.filled.to_string().repeat(2),
+                            Style::default().fg(theme.filled_cell_fg),
+                        );
+                    }
+
+                    let val_span =
+                        cell.to_val_span(theme, options.auto_check, self.accept_unaccented, chars);
+                    let content = if cell.is_empty() {
+                        chars.compact_empty.to_string()
+                    } else {
+                        val_span.content.to_string()
+                    };
+                    let style = if cell.is_selected_cell {
+                        val_span
+                            .style
+                            .fg(theme.selected_cell)
+                            .add_modifier(Modifier::BOLD | Modifier::REVERSED)
+                    } else if cell.is_selected_word {
+                        val_span
+                            .style
+                            .fg(theme.selected_word)
+                            .add_modifier(Modifier::UNDERLINED)
+                    } else {
+                        val_span.style
+                    };
+                    Span::styled(format!("{} ", content), style)
+                });
+                Line::from_iter(spans)
+            })
+            .collect();
+
+        Paragraph::new(lines)
+    }
 }
 
 #[cfg(test)]
@@ -419,10 +735,66 @@ mod tests {
                 },
                 is_selected_cell: false,
                 is_selected_word: false,
+                is_checked_wrong: false,
             }
         }
     }
 
+    #[test]
+    fn test_suggested_word() {
+        // A B C
+        // D . E
+        // F G H
+        #[rustfmt::skip]
+        let solution = vec![
+          "ABC".to_string(),
+          "D.E".to_string(),
+          "FGH".to_string()
+        ];
+        let mut grid = PuzzleGrid::from_solution(&solution);
+
+        // nothing filled: the first of the equally long, equally crossed words
+        assert_eq!(grid.suggested_word(), Some((1, Direction::Across)));
+
+        // two of three letters of 2 Down are in place
+        grid.get_mut(0, 2).unwrap().set_user_letter(Some('C'));
+        grid.get_mut(2, 2).unwrap().set_user_letter(Some('H'));
+        assert_eq!(grid.suggested_word(), Some((2, Direction::Down)));
+
+        grid.reveal_all();
+        assert_eq!(grid.suggested_word(), None);
+    }
+
+    #[test]
+    fn test_progress() {
+        let mut grid = PuzzleGrid::from_solution(&["AB".to_string(), "C.".to_string()]);
+        assert_eq!(grid.progress().percentage(), 0);
+
+        grid.get_mut(0, 0).unwrap().set_user_letter(Some('A'));
+        grid.get_mut(0, 1).unwrap().set_user_letter(Some('X'));
+        let across = grid.word_progress(1, Direction::Across);
+        assert_eq!(
+            across,
+            Progress {
+                filled: 2,
+                correct: 1,
+                total: 2
+            }
+        );
+        assert!(!across.is_solved());
+        assert_eq!(grid.progress().percentage(), 66);
+
+        grid.get_mut(1, 0).unwrap().set_user_letter(Some('C'));
+        assert!(grid.word_progress(1, Direction::Down).is_solved());
+    }
+
+    #[test]
+    fn test_find_letter_cells() {
+        let grid = PuzzleGrid::from_solution(&[".AB".to_string(), "CD.".to_string()]);
+        assert_eq!(grid.find_first_letter_cell(), Some((0, 1)));
+        assert_eq!(grid.find_last_letter_cell(), Some((1, 1)));
+    }
+
     #[test]
     fn test_from_solution() {
         // . . B     <- B starts down word (clue 1)
@@ -536,7 +908,7 @@ mod tests {
         ];
 
         let grid = PuzzleGrid::new(cells);
-        let par = grid.to_par(&crate::theme::DEFAULT);
+        let par = grid.to_par(&crate::theme::DEFAULT, RenderOptions::default());
 
         // create a dummy area for rendering
         let (width, height) = (35, 15);
@@ -564,4 +936,74 @@ mod tests {
 
         println!("{out}");
     }
+
+    #[test]
+    fn test_to_par_hides_numbers() {
+        let grid = PuzzleGrid::from_solution(&["AB".to_string(), "CD".to_string()]);
+        let render = |options: RenderOptions| {
+            let area = ratatui::layout::Rect::new(0, 0, 9, 9);
+            let mut buffer = ratatui::buffer::Buffer::empty(area);
+            grid.to_par(&crate::theme::DEFAULT, options)
+                .render(area, &mut buffer);
+            buffer
+                .content()
+                .iter()
+                .map(|cell| cell.symbol())
+                .collect::<String>()
+        };
+
+        let shown = render(RenderOptions::default());
+        assert!(shown.contains('1') && shown.contains('3'));
+
+        let hidden = render(RenderOptions {
+            show_numbers: false,
+            ..RenderOptions::default()
+        });
+        assert!(!hidden.contains('1') && !hidden.contains('3'));
+    }
+
+    #[test]
+    fn test_to_par_zoom_size() {
+        let grid = PuzzleGrid::from_solution(&["AB".to_string(), "CD".to_string()]);
+        for options in [
+            RenderOptions::default(),
+            RenderOptions {
+                zoom: true,
+                ..RenderOptions::default()
+            },
+        ] {
+            let (cell_width, cell_height) = options.cell_size();
+            let par = grid.to_par(&crate::theme::DEFAULT, options);
+            assert_eq!(par.line_count(u16::MAX), (2 * cell_height + 1) as usize);
+            assert_eq!(par.line_width(), (2 * cell_width + 1) as usize);
+        }
+    }
+
+    #[test]
+    fn test_to_compact_par() {
+        let mut grid = PuzzleGrid::from_solution(&["AB".to_string(), ".C".to_string()]);
+        grid.get_mut(0, 0).unwrap().set_user_letter(Some('A'));
+        let options = RenderOptions {
+            compact: true,
+            ..RenderOptions::default()
+        };
+        assert_eq!(options.grid_size(2, 2), (4, 2));
+
+        let area = ratatui::layout::Rect::new(0, 0, 4, 2);
+        let mut buffer = ratatui::buffer::Buffer::empty(area);
+        grid.to_par(&crate::theme::DEFAULT, options)
+            .render(area, &mut buffer);
+        let rendered: Vec<String> = (0..2)
+            .map(|y| (0..4).map(|x| buffer[(x, y)].symbol()).collect())
+            .collect();
+        assert_eq!(rendered, vec!["A · ", "██· "]);
+    }
+
+    #[test]
+    fn test_to_fill_text() {
+        let mut grid = PuzzleGrid::from_solution(&["AB.".to_string(), ".CD".to_string()]);
+        grid.get_mut(0, 0).unwrap().set_user_letter(Some('A'));
+        grid.get_mut(1, 2).unwrap().set_user_letter(Some('X'));
+        assert_eq!(grid.to_fill_text(), "A_.\n._X");
+    }
 }
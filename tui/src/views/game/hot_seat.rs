@@ -0,0 +1,153 @@
+//! Two-player hot-seat mode.
+//!
+//! Players alternate turns on the same puzzle. A player scores a point for every
+//! word they complete correctly; words finished by a reveal score for nobody.
+
+use super::{Direction, PuzzleGrid};
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+
+/// How the game is played, chosen on the new game screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PlayMode {
+    /// A single player.
+    #[default]
+    Solo,
+    /// Two players, the turn passes after each filled word.
+    HotSeatPerClue,
+    /// Two players, the turn passes after the given number of seconds.
+    HotSeatTimed(u64),
+}
+
+impl PlayMode {
+    pub const ALL: [PlayMode; 5] = [
+        PlayMode::Solo,
+        PlayMode::HotSeatPerClue,
+        PlayMode::HotSeatTimed(30),
+        PlayMode::HotSeatTimed(60),
+        PlayMode::HotSeatTimed(120),
+    ];
+
+    pub fn name(&self) -> String {
+        match self {
+            PlayMode::Solo => "Solo".to_string(),
+            PlayMode::HotSeatPerClue => "2 players, word per turn".to_string(),
+            PlayMode::HotSeatTimed(secs) => format!("2 players, {}s turns", secs),
+        }
+    }
+
+    /// Build the hot-seat session for this mode, if it is a two-player mode.
+    pub fn hot_seat(&self) -> Option<HotSeat> {
+        match self {
+            PlayMode::Solo => None,
+            PlayMode::HotSeatPerClue => Some(HotSeat::new(None)),
+            PlayMode::HotSeatTimed(secs) => Some(HotSeat::new(Some(Duration::from_secs(*secs)))),
+        }
+    }
+}
+
+/// Turn and score tracking for a two-player session.
+#[derive(Debug, Clone)]
+pub struct HotSeat {
+    /// Turn length, or `None` to pass the turn after each filled word.
+    pub turn_length: Option<Duration>,
+    /// Index of the player whose turn it is (0 or 1).
+    pub current: usize,
+    /// Correct words per player.
+    pub scores: [u32; 2],
+    /// When the current turn started.
+    pub turn_started: Instant,
+    /// Words that have already been scored (or forfeited by a reveal).
+    settled: HashSet<(Direction, usize)>,
+}
+
+impl HotSeat {
+    pub fn new(turn_length: Option<Duration>) -> Self {
+        Self {
+            turn_length,
+            current: 0,
+            scores: [0; 2],
+            turn_started: Instant::now(),
+            settled: HashSet::new(),
+        }
+    }
+
+    /// Pass the turn to the other player.
+    pub fn end_turn(&mut self) {
+        self.current = 1 - self.current;
+        self.turn_started = Instant::now();
+    }
+
+    /// Time left in the current turn, for timed turns.
+    pub fn turn_remaining(&self) -> Option<Duration> {
+        self.turn_length
+            .map(|length| length.saturating_sub(self.turn_started.elapsed()))
+    }
+
+    /// End the turn if its time is up. Returns `true` if the turn changed.
+    pub fn tick(&mut self) -> bool {
+        if self.turn_remaining() == Some(Duration::ZERO) {
+            self.end_turn();
+            return true;
+        }
+        false
+    }
+
+    /// Settle the words crossing a cell after it changed.
+    ///
+    /// Newly correct words score for the current player when `award` is set, and
+    /// are forfeited otherwise (e.g. when revealed). In word-per-turn mode, filling
+    /// the word in `direction` ends the turn.
+    pub fn settle_cell(
+        &mut self,
+        grid: &PuzzleGrid,
+        (row, col): (usize, usize),
+        direction: Direction,
+        award: bool,
+    ) {
+        let Some(cell) = grid.get(row, col) else {
+            return;
+        };
+
+        for dir in [Direction::Across, Direction::Down] {
+            let Some(clue_no) = cell.clue_no_for_direction(dir) else {
+                continue;
+            };
+            if self.settled.contains(&(dir, clue_no)) || !grid.is_word_correct(clue_no, dir) {
+                continue;
+            }
+            self.settled.insert((dir, clue_no));
+            if award {
+                self.scores[self.current] += 1;
+            }
+        }
+
+        let filled_word = cell
+            .clue_no_for_direction(direction)
+            .is_some_and(|clue_no| grid.is_word_filled(clue_no, direction));
+        if self.turn_length.is_none() && filled_word {
+            self.end_turn();
+        }
+    }
+
+    /// Forfeit every correct word in the grid (e.g. after revealing the puzzle).
+    pub fn settle_all(&mut self, grid: &PuzzleGrid) {
+        for dir in [Direction::Across, Direction::Down] {
+            for clue_no in grid.clue_numbers(dir) {
+                if grid.is_word_correct(clue_no, dir) {
+                    self.settled.insert((dir, clue_no));
+                }
+            }
+        }
+    }
+
+    /// Summary of the final scores, e.g. "Player 1 wins 5 to 3".
+    pub fn result(&self) -> String {
+        let [p1, p2] = self.scores;
+        match p1.cmp(&p2) {
+            std::cmp::Ordering::Greater => format!("Player 1 wins {} to {}", p1, p2),
+            std::cmp::Ordering::Less => format!("Player 2 wins {} to {}", p2, p1),
+            std::cmp::Ordering::Equal => format!("Tied at {} each", p1),
+        }
+    }
+}
@@ -0,0 +1,268 @@
+//! Loading screen for puzzle downloads.
+//!
+//! Downloads run on a background task so the UI keeps drawing: a spinner is
+//! shown while the request is in flight, ESC cancels it, and provider errors are
+//! shown in a panel that can be dismissed or retried.
+
+use super::GameView;
+use crate::{App, AppView};
+use crossterm::event::{KeyCode, KeyEvent};
+use cruciverbal_providers::util::Validators;
+use cruciverbal_providers::{ProviderError, PuzzleProvider, Source};
+use puz_parse::Puzzle;
+use ratatui::{
+    layout::{Constraint, Flex, Layout},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+};
+use std::time::Instant;
+use tokio::task::JoinHandle;
+
+/// Spinner animation frames.
+const SPINNER: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+/// How long each spinner frame is shown, in milliseconds.
+const SPINNER_FRAME_MS: u128 = 80;
+
+const ERROR_PANEL_WIDTH: u16 = 60;
+const ERROR_PANEL_HEIGHT: u16 = 8;
+
+/// Date to file a provider's latest puzzle under: its latest publication date,
+/// or today for providers that only serve their latest puzzle.
+fn latest_date(provider: PuzzleProvider) -> String {
+    let today = provider.today();
+    provider
+        .latest_available_date(today)
+        .unwrap_or(today)
+        .format("%Y-%m-%d")
+        .to_string()
+}
+
+/// A downloaded puzzle with its publication date, if known, and the validators of
+/// its response.
+type Downloaded = (Puzzle, Option<chrono::NaiveDate>, Option<Validators>);
+
+/// A puzzle download started from the selection screen.
+#[derive(Debug)]
+pub enum Download {
+    /// Request is running on a background task.
+    InFlight {
+        handle: JoinHandle<Result<Downloaded, ProviderError>>,
+        started: Instant,
+    },
+    /// Request failed, showing the error until dismissed.
+    Failed(String),
+}
+
+impl App {
+    /// Drive the download for the loading screen: start it on the first call,
+    /// and hand the puzzle over once the background task has finished.
+    pub async fn poll_download(&mut self) {
+        let finished = match &self.state.game.download {
+            None => {
+                self.start_download();
+                return;
+            }
+            Some(Download::InFlight { handle, .. }) => handle.is_finished(),
+            Some(Download::Failed(_)) => false,
+        };
+        if !finished {
+            return;
+        }
+
+        if let Some(Download::InFlight { handle, .. }) = self.state.game.download.take() {
+            match handle.await {
+                Ok(result) => self.finish_download(result),
+                Err(e) => {
+                    self.state.game.download = Some(Download::Failed(e.to_string()));
+                }
+            }
+        }
+    }
+
+    /// Start downloading the puzzle chosen on the selection screen, or open it
+    /// straight from the cache.
+    fn start_download(&mut self) {
+        let date = self.state.game.selection.date.clone();
+        let use_latest = self.state.game.selection.use_latest;
+        let provider = PuzzleProvider::ALL
+            .get(self.state.game.selection.provider_idx)
+            .copied()
+            .unwrap_or_default();
+
+        // Date-specific puzzles don't change, so serve them from the cache when possible
+        let cached = if use_latest {
+            None
+        } else {
+            crate::cache::load(provider.name(), &date)
+        };
+        if let Some(entry) = cached {
+            self.start_downloaded_puzzle(entry.puzzle, entry.source, date);
+            return;
+        }
+
+        self.state.game.download = Some(Download::InFlight {
+            handle: tokio::spawn(async move {
+                let date = (!use_latest).then_some(date);
+                crate::fetch_log::download(provider, date.as_deref()).await
+            }),
+            started: Instant::now(),
+        });
+    }
+
+    /// Cache and start a downloaded puzzle, or show the error panel.
+    fn finish_download(&mut self, result: Result<Downloaded, ProviderError>) {
+        let date = self.state.game.selection.date.clone();
+        let use_latest = self.state.game.selection.use_latest;
+        let provider = PuzzleProvider::ALL
+            .get(self.state.game.selection.provider_idx)
+            .copied()
+            .unwrap_or_default();
+
+        match result {
+            Ok((puzzle, published, validators)) => {
+                let source = Source::fetched(provider, &puzzle);
+                // file the latest puzzle under the date it was found on, which is
+                // earlier than expected if today's isn't out yet
+                let puzzle_date = match published {
+                    Some(published) => published.format("%Y-%m-%d").to_string(),
+                    None if use_latest => provider.today().format("%Y-%m-%d").to_string(),
+                    None => date,
+                };
+
+                let entry = crate::cache::CacheEntry {
+                    puzzle,
+                    source: Some(source),
+                    validators,
+                };
+                let _ = crate::cache::store(provider.name(), &puzzle_date, &entry);
+
+                self.start_downloaded_puzzle(entry.puzzle, entry.source, puzzle_date);
+            }
+            Err(e) => {
+                // offline: fall back to the latest puzzle if it was fetched earlier (e.g. by fetch-daily)
+                let latest = latest_date(provider);
+                let cached = if use_latest {
+                    crate::cache::load(provider.name(), &latest)
+                } else {
+                    None
+                };
+                match cached {
+                    Some(entry) => self.start_downloaded_puzzle(entry.puzzle, entry.source, latest),
+                    None => self.state.game.download = Some(Download::Failed(e.to_string())),
+                }
+            }
+        }
+    }
+
+    pub(super) fn handle_loading_input(&mut self, key: KeyEvent) {
+        match (&self.state.game.download, key.code) {
+            // ESC cancels the request and goes back to the selection screen
+            (Some(Download::InFlight { handle, .. }), KeyCode::Esc) => {
+                handle.abort();
+                self.state.game.download = None;
+                self.view = AppView::Game(GameView::Selecting);
+            }
+            (Some(Download::Failed(_)), KeyCode::Esc) => {
+                self.state.game.download = None;
+                self.view = AppView::Game(GameView::Selecting);
+            }
+            // retry; the next poll starts a fresh download
+            (Some(Download::Failed(_)), KeyCode::Enter | KeyCode::Char('r')) => {
+                self.state.game.download = None;
+            }
+            _ => {}
+        }
+    }
+
+    pub(super) fn draw_game_loading(&mut self, frame: &mut ratatui::Frame) {
+        let area = frame.area();
+        let theme = self.state.theme;
+
+        let provider_name = PuzzleProvider::ALL
+            .get(self.state.game.selection.provider_idx)
+            .map(|p| p.name())
+            .unwrap_or("Unknown");
+        let loading_text = if self.state.game.selection.use_latest {
+            format!("Loading latest {} puzzle...", provider_name)
+        } else {
+            format!(
+                "Loading {} puzzle for {}...",
+                provider_name, self.state.game.selection.date
+            )
+        };
+
+        if let Some(Download::Failed(error)) = &self.state.game.download {
+            let [popup_area] = Layout::horizontal([Constraint::Length(ERROR_PANEL_WIDTH)])
+                .flex(Flex::Center)
+                .areas(area);
+            let [popup_area] = Layout::vertical([Constraint::Length(ERROR_PANEL_HEIGHT)])
+                .flex(Flex::Center)
+                .areas(popup_area);
+            frame.render_widget(Clear, popup_area);
+
+            let block = Block::default()
+                .title(Span::styled(
+                    " Download failed ",
+                    Style::default()
+                        .fg(theme.error)
+                        .add_modifier(Modifier::BOLD),
+                ))
+                .title_alignment(ratatui::layout::Alignment::Center)
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.error));
+            let inner = block.inner(popup_area);
+            frame.render_widget(block, popup_area);
+
+            let [message_area, hint_area] =
+                Layout::vertical([Constraint::Min(1), Constraint::Length(1)]).areas(inner);
+            let message = vec![
+                Line::from(Span::styled(
+                    provider_name,
+                    Style::default().fg(theme.secondary),
+                )),
+                Line::from(Span::styled(
+                    error.as_str(),
+                    Style::default().fg(theme.text),
+                )),
+            ];
+            frame.render_widget(
+                Paragraph::new(message).wrap(Wrap { trim: true }).centered(),
+                message_area,
+            );
+            let hint = Line::from(vec![
+                Span::styled("Enter", Style::default().fg(theme.primary)),
+                Span::styled(" retry · ", Style::default().fg(theme.dimmed)),
+                Span::styled("ESC", Style::default().fg(theme.primary)),
+                Span::styled(" back", Style::default().fg(theme.dimmed)),
+            ]);
+            frame.render_widget(Paragraph::new(hint).centered(), hint_area);
+            return;
+        }
+
+        let [_, content_area, _] = Layout::vertical([
+            Constraint::Min(1),
+            Constraint::Length(3),
+            Constraint::Min(1),
+        ])
+        .areas(area);
+
+        let elapsed = match &self.state.game.download {
+            Some(Download::InFlight { started, .. }) => started.elapsed(),
+            _ => Default::default(),
+        };
+        let spinner = SPINNER[(elapsed.as_millis() / SPINNER_FRAME_MS) as usize % SPINNER.len()];
+        let lines = vec![
+            Line::from(vec![
+                Span::styled(format!("{} ", spinner), Style::default().fg(theme.primary)),
+                Span::styled(loading_text, Style::default().fg(theme.secondary)),
+            ]),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("ESC", Style::default().fg(theme.primary)),
+                Span::styled(" cancel", Style::default().fg(theme.dimmed)),
+            ]),
+        ];
+        frame.render_widget(Paragraph::new(lines).centered(), content_area);
+    }
+}
@@ -1,7 +1,9 @@
 use crate::App;
-use crate::save::{self, SaveInfo};
+use crate::preferences::{ClueKeys, EndOfWord, TargetTime};
+use crate::save::{self, SaveInfo, SaveSort};
+use crate::stats;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
-use cruciverbal_providers::PuzzleProvider;
+use cruciverbal_providers::{Language, PuzzleProvider, Source, samples::SAMPLES};
 use ratatui::style::Stylize;
 use ratatui::{
     layout::{Alignment, Constraint, Flex, Layout, Rect},
@@ -21,6 +23,20 @@ fn format_duration(duration: Option<Duration>) -> String {
     }
 }
 
+/// Why the fill of `puzzle` can't be checked, or `None` if it has a solution.
+///
+/// Locked `.puz` files and prize puzzles are still played, and completed by
+/// filling every cell.
+pub(crate) fn unchecked_reason(puzzle: &puz_parse::Puzzle) -> Option<&'static str> {
+    if puzzle.info.is_scrambled {
+        Some("Solution is locked")
+    } else if !cruciverbal_providers::entries::has_solution(puzzle) {
+        Some("No solution published yet")
+    } else {
+        None
+    }
+}
+
 mod constants;
 
 mod grid;
@@ -29,6 +45,29 @@ use grid::*;
 mod cell;
 pub use cell::*;
 
+mod hot_seat;
+pub use hot_seat::{HotSeat, PlayMode};
+
+mod mouse;
+
+mod clue_list;
+use clue_list::{CLUE_PANEL_WIDTH, CluePanel};
+
+mod calendar;
+
+mod loading;
+use loading::Download;
+
+mod print;
+
+mod wordplay;
+
+mod assist;
+use assist::AssistScope;
+
+mod replay;
+pub use replay::{ReplayEvent, ReplayPlayer};
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub enum GameView {
     /// User is playing the puzzle, loaded within [`GameState::puzzle`].
@@ -44,10 +83,34 @@ pub enum GameView {
     Loading,
     /// User is saving the current puzzle to file.
     Saving,
+    /// Showing the puzzle info popup (metadata and source attribution).
+    Info,
     /// Puzzle is completed correctly, showing congratulations popup.
     Completed,
     /// User continues playing after completion (timer stopped, no validation).
     CompletedPlaying,
+    /// Playing back the recorded solve.
+    Replay,
+    /// Showing the keyboard controls over the game.
+    Help,
+    /// Choosing what to check or reveal.
+    Assist,
+}
+
+impl GameView {
+    /// Whether the view shows the game being played, possibly under a popup.
+    pub fn is_in_game(&self) -> bool {
+        matches!(
+            self,
+            GameView::Playing
+                | GameView::CompletedPlaying
+                | GameView::Completed
+                | GameView::Saving
+                | GameView::Info
+                | GameView::Help
+                | GameView::Assist
+        )
+    }
 }
 
 /// Completion state for the puzzle.
@@ -68,6 +131,7 @@ pub enum SelectionField {
     #[default]
     Date,
     Provider,
+    Mode,
     Start,
 }
 
@@ -76,7 +140,8 @@ impl SelectionField {
     pub fn next(&self) -> Self {
         match self {
             SelectionField::Date => SelectionField::Provider,
-            SelectionField::Provider => SelectionField::Start,
+            SelectionField::Provider => SelectionField::Mode,
+            SelectionField::Mode => SelectionField::Start,
             SelectionField::Start => SelectionField::Date,
         }
     }
@@ -86,7 +151,8 @@ impl SelectionField {
         match self {
             SelectionField::Date => SelectionField::Start,
             SelectionField::Provider => SelectionField::Date,
-            SelectionField::Start => SelectionField::Provider,
+            SelectionField::Mode => SelectionField::Provider,
+            SelectionField::Start => SelectionField::Mode,
         }
     }
 }
@@ -100,8 +166,12 @@ pub struct SelectionState {
     pub use_latest: bool,
     /// Currently selected provider index.
     pub provider_idx: usize,
+    /// Solo or two-player hot-seat play.
+    pub mode: PlayMode,
     /// Which field is currently active.
     pub active_field: SelectionField,
+    /// Cursor date of the calendar picker, while it is open.
+    pub calendar: Option<chrono::NaiveDate>,
     /// Error message to display, if any.
     pub error: Option<String>,
 }
@@ -119,6 +189,12 @@ pub struct LoadSelectState {
     pub loaded: bool,
     /// Delete notification: (filename, hide_time).
     pub delete_notification: Option<(String, Instant)>,
+    /// Order of the saves.
+    pub sort: SaveSort,
+    /// Whether the selected save is waiting for delete confirmation.
+    pub confirm_delete: bool,
+    /// New name being typed for the selected save, if renaming.
+    pub rename: Option<String>,
 }
 
 impl Default for LoadSelectState {
@@ -129,6 +205,9 @@ impl Default for LoadSelectState {
             error: None,
             loaded: false,
             delete_notification: None,
+            sort: SaveSort::default(),
+            confirm_delete: false,
+            rename: None,
         }
     }
 }
@@ -144,22 +223,57 @@ pub struct RecentSelectState {
     pub error: Option<String>,
     /// Whether the list has been loaded.
     pub loaded: bool,
+    /// Thumbnail of the selected game's grid, with the save it was made from.
+    pub preview: Option<(std::path::PathBuf, Vec<String>)>,
 }
 
 impl Default for SelectionState {
     fn default() -> Self {
         // Default to "latest" mode
-        let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+        let provider_idx = PuzzleProvider::ALL
+            .iter()
+            .position(|p| p.is_enabled())
+            .unwrap_or(0);
+        let today = PuzzleProvider::ALL[provider_idx].today();
         Self {
-            date: today,
+            date: today.format("%Y-%m-%d").to_string(),
             use_latest: true,
-            provider_idx: 0,
+            provider_idx,
+            mode: PlayMode::default(),
             active_field: SelectionField::Date,
+            calendar: None,
             error: None,
         }
     }
 }
 
+impl SelectionState {
+    /// The selected provider.
+    fn provider(&self) -> PuzzleProvider {
+        PuzzleProvider::ALL
+            .get(self.provider_idx)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Select the next (or previous) enabled provider, wrapping around.
+    fn step_provider(&mut self, forward: bool) {
+        let len = PuzzleProvider::ALL.len();
+        let mut idx = self.provider_idx;
+        for _ in 0..len {
+            idx = if forward {
+                (idx + 1) % len
+            } else {
+                (idx + len - 1) % len
+            };
+            if PuzzleProvider::ALL[idx].is_enabled() {
+                self.provider_idx = idx;
+                return;
+            }
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct GameState {
     /// Loaded puzzle, if any.
@@ -178,12 +292,37 @@ pub struct GameState {
     /// Visible area dimensions (width, height) in terminal cells.
     pub visible_area: (u16, u16),
 
+    /// Screen area the grid was last rendered in (for mouse hit-testing).
+    pub grid_area: Rect,
+
+    /// Whether the full grid did not fit the terminal on the last draw, so the
+    /// compact rendering is used instead.
+    pub auto_compact: bool,
+
+    /// Across and Down clue list panels.
+    pub clue_panels: [CluePanel; 2],
+
+    /// Show hidden clue numbers until this time (None = not peeking).
+    pub numbers_peek_until: Option<Instant>,
+
     /// Puzzle date string (e.g., "2025-12-08").
     pub puzzle_date: Option<String>,
 
+    /// Attribution for the loaded puzzle, if known.
+    pub source: Option<Source>,
+
     /// Time when the puzzle was started (for timer display).
     pub start_time: Option<Instant>,
 
+    /// Target solve time, shown as a countdown next to the timer.
+    pub target_time: Option<Duration>,
+
+    /// Letter changes so far, for the replay viewer.
+    pub replay: Vec<ReplayEvent>,
+
+    /// Playback state of the replay viewer.
+    pub player: ReplayPlayer,
+
     /// State for the puzzle selection screen.
     pub selection: SelectionState,
 
@@ -199,7 +338,7 @@ pub struct GameState {
     /// Final completion time (set when puzzle is completed correctly).
     pub completion_time: Option<Duration>,
 
-    /// Selected option in the completion popup (0 = Continue, 1 = Menu).
+    /// Selected option in the completion popup (0 = Continue, 1 = Replay, 2 = Menu).
     pub completed_popup_selection: usize,
 
     /// Provider index (for saving).
@@ -208,8 +347,26 @@ pub struct GameState {
     /// Elapsed time when game was paused (e.g., when viewing help).
     pub paused_elapsed: Option<Duration>,
 
-    /// When to hide the save notification (None = not showing).
-    pub save_notification_until: Option<Instant>,
+    /// Notification shown in the top-right corner: (message, hide_time).
+    pub notification: Option<(String, Instant)>,
+
+    /// Time of the last periodic auto-save (None = not yet tracked).
+    pub last_auto_save: Option<Instant>,
+
+    /// Letter changes since the game was last saved.
+    pub edits_since_save: u32,
+
+    /// Checks and reveals used on this puzzle.
+    pub assists: stats::Assists,
+
+    /// Selected entry of the check and reveal menu.
+    pub assist_selection: usize,
+
+    /// Turns and scores for a two-player hot-seat session, if any.
+    pub hot_seat: Option<HotSeat>,
+
+    /// Puzzle download for the loading screen, if one was started.
+    pub download: Option<Download>,
 
     /* scrollbar stuff */
     /// Current scroll position (vertical, horizontal).
@@ -229,8 +386,16 @@ impl Default for GameState {
             sel: (0, 0),
             active_direction: Direction::Across,
             visible_area: (0, 0),
+            grid_area: Rect::default(),
+            auto_compact: false,
+            clue_panels: Default::default(),
+            numbers_peek_until: None,
             puzzle_date: None,
+            source: None,
             start_time: None,
+            target_time: None,
+            replay: Vec::new(),
+            player: ReplayPlayer::default(),
             selection: SelectionState::default(),
             load_select: LoadSelectState::default(),
             recent_select: RecentSelectState::default(),
@@ -239,7 +404,13 @@ impl Default for GameState {
             completed_popup_selection: 0,
             provider_idx: None,
             paused_elapsed: None,
-            save_notification_until: None,
+            notification: None,
+            last_auto_save: None,
+            edits_since_save: 0,
+            assists: stats::Assists::default(),
+            assist_selection: 0,
+            hot_seat: None,
+            download: None,
             scroll_cur: (0, 0),
             scroll_max: (0, 0),
             scroll_bar: (ScrollbarState::default(), ScrollbarState::default()),
@@ -248,6 +419,12 @@ impl Default for GameState {
 }
 
 impl GameState {
+    /// Time spent on the puzzle so far, not counting while it is paused.
+    pub fn elapsed(&self) -> Option<Duration> {
+        self.paused_elapsed
+            .or_else(|| self.start_time.map(|start| start.elapsed()))
+    }
+
     /// Reset the game state for a new game, keeping selection state fresh.
     pub fn reset_for_new_game(&mut self) {
         self.puzzle = None;
@@ -255,8 +432,16 @@ impl GameState {
         self.sel = (0, 0);
         self.active_direction = Direction::Across;
         self.visible_area = (0, 0);
+        self.grid_area = Rect::default();
+        self.auto_compact = false;
+        self.clue_panels = Default::default();
+        self.numbers_peek_until = None;
         self.puzzle_date = None;
+        self.source = None;
         self.start_time = None;
+        self.target_time = None;
+        self.replay = Vec::new();
+        self.player = ReplayPlayer::default();
         self.selection = SelectionState::default();
         self.load_select = LoadSelectState::default();
         self.recent_select = RecentSelectState::default();
@@ -265,13 +450,42 @@ impl GameState {
         self.completed_popup_selection = 0;
         self.provider_idx = None;
         self.paused_elapsed = None;
-        self.save_notification_until = None;
+        self.notification = None;
+        self.last_auto_save = None;
+        self.edits_since_save = 0;
+        self.assists = stats::Assists::default();
+        self.assist_selection = 0;
+        self.hot_seat = None;
+        self.download = None;
         self.scroll_cur = (0, 0);
         self.scroll_max = (0, 0);
         self.scroll_bar = (ScrollbarState::default(), ScrollbarState::default());
     }
 }
 
+/// Width of the status bar fill gauge, in characters.
+const GAUGE_WIDTH: usize = 10;
+
+/// A fill gauge such as `███░░░░░░░ 30%`.
+fn progress_gauge(
+    percentage: u8,
+    chars: &constants::BoxChars,
+    theme: &crate::theme::Theme,
+) -> [Span<'static>; 3] {
+    let full = (percentage as usize * GAUGE_WIDTH / 100).min(GAUGE_WIDTH);
+    [
+        Span::styled(
+            chars.gauge_full.to_string().repeat(full),
+            Style::default().fg(theme.success),
+        ),
+        Span::styled(
+            chars.gauge_empty.to_string().repeat(GAUGE_WIDTH - full),
+            Style::default().fg(theme.dimmed),
+        ),
+        Span::styled(format!(" {}%", percentage), Style::default().fg(theme.text)),
+    ]
+}
+
 impl App {
     pub fn draw_game(&mut self, view: GameView, frame: &mut ratatui::Frame) {
         match view {
@@ -283,6 +497,10 @@ impl App {
             GameView::Loading => self.draw_game_loading(frame),
             GameView::Completed => self.draw_game_completed(frame),
             GameView::Saving => self.draw_game_saving(frame),
+            GameView::Info => self.draw_game_info(frame),
+            GameView::Replay => self.draw_game_replay(frame),
+            GameView::Help => self.draw_game_help(frame),
+            GameView::Assist => self.draw_game_assist(frame),
         }
     }
 
@@ -319,11 +537,95 @@ impl App {
         );
     }
 
+    fn draw_game_info(&mut self, frame: &mut ratatui::Frame) {
+        // Draw the game in the background
+        self.draw_game_playing(frame, false);
+
+        let area = frame.area();
+        let theme = self.state.theme;
+
+        let label_style = Style::default().fg(theme.secondary);
+        let value_style = Style::default().fg(theme.text);
+        let field = |label: &'static str, value: String| {
+            Line::from(vec![
+                Span::styled(format!("{:<10}", label), label_style),
+                Span::styled(value, value_style),
+            ])
+        };
+
+        let mut lines: Vec<Line> = Vec::new();
+        if let Some(puzzle) = self.state.game.puzzle.as_ref() {
+            lines.push(field("Title", puzzle.info.title.clone()));
+            lines.push(field("Author", puzzle.info.author.clone()));
+            lines.push(field("Copyright", puzzle.info.copyright.clone()));
+            if !puzzle.info.notes.is_empty() {
+                lines.push(field("Notes", puzzle.info.notes.clone()));
+            }
+        }
+        lines.push(Line::from(""));
+        match self.state.game.source.as_ref() {
+            Some(source) => {
+                lines.push(field("Source", source.provider.clone()));
+                if let Some(language) = source.language {
+                    lines.push(field("Language", language.name().to_string()));
+                }
+                lines.push(field("URL", source.url.clone()));
+                lines.push(field("Fetched", source.fetched_at.clone()));
+                lines.push(field("License", source.license_note.clone()));
+            }
+            None => lines.push(Line::from(Span::styled(
+                "No source information available.",
+                Style::default().fg(theme.dimmed),
+            ))),
+        }
+
+        let warnings = self
+            .state
+            .game
+            .puzzle
+            .as_ref()
+            .map(cruciverbal_providers::entries::check_enumerations)
+            .unwrap_or_default();
+        if !warnings.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                "Warnings",
+                Style::default().fg(theme.error),
+            )));
+            for warning in warnings {
+                lines.push(Line::from(Span::styled(warning, value_style)));
+            }
+        }
+
+        let popup_width: u16 = 70.min(area.width);
+        let popup_height: u16 = (lines.len() as u16 + 4).min(area.height);
+
+        let [centered_area] = Layout::horizontal([Constraint::Length(popup_width)])
+            .flex(Flex::Center)
+            .areas(area);
+
+        let [centered_area] = Layout::vertical([Constraint::Length(popup_height)])
+            .flex(Flex::Center)
+            .areas(centered_area);
+
+        frame.render_widget(Clear, centered_area);
+
+        let block = Block::default()
+            .title(" Puzzle Info ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.secondary));
+        let inner = block.inner(centered_area);
+        frame.render_widget(block, centered_area);
+
+        frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: true }), inner);
+    }
+
     fn draw_game_load_select(&mut self, frame: &mut ratatui::Frame) {
         // Load saves list if not loaded
         if !self.state.game.load_select.loaded {
             match save::list_saves() {
-                Ok(saves) => {
+                Ok(mut saves) => {
+                    self.state.game.load_select.sort.sort(&mut saves);
                     self.state.game.load_select.saves = saves;
                     self.state.game.load_select.error = None;
                 }
@@ -365,43 +667,115 @@ impl App {
 
         let load_select = &self.state.game.load_select;
 
-        if let Some(ref error) = load_select.error {
+        if let (Some(error), true) = (&load_select.error, load_select.saves.is_empty()) {
             frame.render_widget(
                 Paragraph::new(error.as_str())
                     .style(Style::default().fg(theme.error))
                     .centered(),
                 inner_area,
             );
-        } else if load_select.saves.is_empty() {
-            frame.render_widget(
-                Paragraph::new("No saved games. Use CTRL+S to save.")
-                    .style(Style::default().fg(theme.dimmed))
-                    .centered(),
-                inner_area,
-            );
         } else {
-            // List saves
-            let mut lines: Vec<Line> = Vec::new();
-
-            for (i, save_info) in load_select.saves.iter().enumerate() {
-                let is_selected = i == load_select.selected;
-                let style = if is_selected {
+            // List saves, followed by the bundled samples
+            let item_style = |is_selected: bool| {
+                if is_selected {
                     Style::default()
                         .fg(theme.primary)
                         .add_modifier(Modifier::BOLD)
                 } else {
                     Style::default().fg(theme.text)
-                };
+                }
+            };
+            let prefix = |is_selected: bool| if is_selected { "▸ " } else { "  " };
+            let chars = self.render_options().box_chars();
+            let mut lines: Vec<Line> = Vec::new();
+            let mut selected_line = 0;
 
-                let prefix = if is_selected { "▸ " } else { "  " };
-                let line = format!(
-                    "{}{} - {} ({}%)",
-                    prefix, save_info.date, save_info.provider, save_info.completion_pct
-                );
-                lines.push(Line::from(Span::styled(line, style)));
+            if load_select.saves.is_empty() {
+                lines.push(Line::from(Span::styled(
+                    "No saved games. Use CTRL+S to save.",
+                    Style::default().fg(theme.dimmed),
+                )));
+            }
+            for (i, save_info) in load_select.saves.iter().enumerate() {
+                let is_selected = i == load_select.selected;
+                if is_selected {
+                    selected_line = lines.len();
+                }
+                let label: String = save_info.label().chars().take(28).collect();
+                let filled = (save_info.completion_pct as usize).div_ceil(10).min(10);
+                let bar: String = std::iter::repeat_n(chars.filled, filled)
+                    .chain(std::iter::repeat_n(chars.compact_empty, 10 - filled))
+                    .collect();
+                lines.push(Line::from(vec![
+                    Span::styled(
+                        format!("{}{:<28} ", prefix(is_selected), label),
+                        item_style(is_selected),
+                    ),
+                    Span::styled(bar, Style::default().fg(theme.success)),
+                    Span::styled(
+                        format!(" {:>3}%", save_info.completion_pct),
+                        Style::default().fg(theme.dimmed),
+                    ),
+                ]));
+            }
+
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                "Samples",
+                Style::default().fg(theme.secondary),
+            )));
+            for (i, sample) in SAMPLES.iter().enumerate() {
+                let is_selected = load_select.saves.len() + i == load_select.selected;
+                if is_selected {
+                    selected_line = lines.len();
+                }
+                let mut line = format!("{}{}", prefix(is_selected), sample.title);
+                if sample.language != Language::English {
+                    line.push_str(&format!(" · {}", sample.language.name()));
+                }
+                lines.push(Line::from(Span::styled(line, item_style(is_selected))));
             }
 
-            frame.render_widget(Paragraph::new(lines), inner_area);
+            // Delete confirmation or rename input below the list
+            let prompt = if let Some(name) = &load_select.rename {
+                Some(Line::from(vec![
+                    Span::styled("Rename to: ", Style::default().fg(theme.secondary)),
+                    Span::styled(format!("{}▏", name), Style::default().fg(theme.text)),
+                ]))
+            } else if load_select.confirm_delete {
+                let name = load_select
+                    .saves
+                    .get(load_select.selected)
+                    .map(|s| s.label())
+                    .unwrap_or_default();
+                Some(Line::from(Span::styled(
+                    format!("Delete {}? (y/n)", name),
+                    Style::default().fg(theme.error),
+                )))
+            } else {
+                load_select.error.as_ref().map(|error| {
+                    Line::from(Span::styled(
+                        error.as_str(),
+                        Style::default().fg(theme.error),
+                    ))
+                })
+            };
+            let [list_area, prompt_area] = Layout::vertical([
+                Constraint::Min(1),
+                Constraint::Length(if prompt.is_some() { 2 } else { 0 }),
+            ])
+            .areas(inner_area);
+
+            // Scroll to keep the selection in view
+            let visible = list_area.height.max(1) as usize;
+            let offset = (selected_line + 1).saturating_sub(visible);
+            frame.render_widget(Paragraph::new(lines).scroll((offset as u16, 0)), list_area);
+            if let Some(prompt) = prompt {
+                frame.render_widget(
+                    Paragraph::new(vec![Line::from(""), prompt]).centered(),
+                    prompt_area,
+                );
+            }
         }
 
         // Footer with instructions
@@ -412,8 +786,15 @@ impl App {
             Span::styled(" navigate • ", Style::default().fg(theme.dimmed)),
             Span::styled("Enter", Style::default().fg(theme.primary)),
             Span::styled(" load • ", Style::default().fg(theme.dimmed)),
-            Span::styled("Del", Style::default().fg(theme.primary)),
+            Span::styled("d", Style::default().fg(theme.primary)),
             Span::styled(" delete • ", Style::default().fg(theme.dimmed)),
+            Span::styled("r", Style::default().fg(theme.primary)),
+            Span::styled(" rename • ", Style::default().fg(theme.dimmed)),
+            Span::styled("s", Style::default().fg(theme.primary)),
+            Span::styled(
+                format!(" sort: {} • ", self.state.game.load_select.sort.name()),
+                Style::default().fg(theme.dimmed),
+            ),
             Span::styled("ESC", Style::default().fg(theme.primary)),
             Span::styled(" back", Style::default().fg(theme.dimmed)),
         ]);
@@ -443,8 +824,10 @@ impl App {
         if !self.state.game.recent_select.loaded {
             match save::list_autosaves() {
                 Ok(games) => {
-                    self.state.game.recent_select.games = games;
-                    self.state.game.recent_select.error = None;
+                    let recent_select = &mut self.state.game.recent_select;
+                    recent_select.selected =
+                        recent_select.selected.min(games.len().saturating_sub(1));
+                    recent_select.games = games;
                 }
                 Err(e) => {
                     self.state.game.recent_select.error =
@@ -453,6 +836,7 @@ impl App {
             }
             self.state.game.recent_select.loaded = true;
         }
+        self.update_recent_preview();
 
         let area = frame.area();
         let theme = self.state.theme;
@@ -465,12 +849,30 @@ impl App {
         ]);
         let [_, content_area, _] = vertical.areas(area);
 
+        let recent_select = &self.state.game.recent_select;
+        let preview = recent_select.preview.as_ref().map(|(_, lines)| lines);
+        let preview_width = preview
+            .and_then(|lines| lines.iter().map(|l| l.chars().count()).max())
+            .map_or(0, |w| w as u16 + 4);
+
         let horizontal = Layout::horizontal([
-            Constraint::Min(1),     // Left padding
-            Constraint::Length(50), // Form area
-            Constraint::Min(1),     // Right padding
+            Constraint::Min(1),                // Left padding
+            Constraint::Length(50),            // Form area
+            Constraint::Length(preview_width), // Grid preview
+            Constraint::Min(1),                // Right padding
         ]);
-        let [_, form_area, _] = horizontal.areas(content_area);
+        let [_, form_area, preview_area, _] = horizontal.areas(content_area);
+
+        if let Some(lines) = preview {
+            let lines: Vec<Line> = lines
+                .iter()
+                .map(|l| Line::styled(l.as_str(), Style::default().fg(theme.text)))
+                .collect();
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.dimmed));
+            frame.render_widget(Paragraph::new(lines).centered().block(block), preview_area);
+        }
 
         // Draw the main block
         let block = Block::default()
@@ -482,9 +884,7 @@ impl App {
         let inner_area = block.inner(form_area);
         frame.render_widget(block, form_area);
 
-        let recent_select = &self.state.game.recent_select;
-
-        if let Some(ref error) = recent_select.error {
+        if let (Some(error), true) = (&recent_select.error, recent_select.games.is_empty()) {
             frame.render_widget(
                 Paragraph::new(error.as_str())
                     .style(Style::default().fg(theme.error))
@@ -499,10 +899,24 @@ impl App {
                 inner_area,
             );
         } else {
-            // List recent games
+            // List recent games, scrolled to keep the selection in view, with
+            // a load error below them
+            let [list_area, error_area] = Layout::vertical([
+                Constraint::Min(1),
+                Constraint::Length(recent_select.error.is_some() as u16),
+            ])
+            .areas(inner_area);
+            let visible = list_area.height.max(1) as usize;
+            let offset = (recent_select.selected + 1).saturating_sub(visible);
             let mut lines: Vec<Line> = Vec::new();
 
-            for (i, game_info) in recent_select.games.iter().enumerate() {
+            for (i, game_info) in recent_select
+                .games
+                .iter()
+                .enumerate()
+                .skip(offset)
+                .take(visible)
+            {
                 let is_selected = i == recent_select.selected;
                 let style = if is_selected {
                     Style::default()
@@ -517,10 +931,24 @@ impl App {
                     "{}{} - {} ({}%)",
                     prefix, game_info.date, game_info.provider, game_info.completion_pct
                 );
-                lines.push(Line::from(Span::styled(line, style)));
+                lines.push(Line::from(vec![
+                    Span::styled(line, style),
+                    Span::styled(
+                        format!(" · {}", game_info.last_played_label()),
+                        Style::default().fg(theme.dimmed),
+                    ),
+                ]));
             }
 
-            frame.render_widget(Paragraph::new(lines), inner_area);
+            frame.render_widget(Paragraph::new(lines), list_area);
+            if let Some(ref error) = recent_select.error {
+                frame.render_widget(
+                    Paragraph::new(error.as_str())
+                        .style(Style::default().fg(theme.error))
+                        .centered(),
+                    error_area,
+                );
+            }
         }
 
         // Footer with instructions
@@ -537,6 +965,47 @@ impl App {
         frame.render_widget(Paragraph::new(footer).centered(), footer_area);
     }
 
+    /// Build the grid thumbnail of the selected recent game, if it changed.
+    fn update_recent_preview(&mut self) {
+        let chars = self.render_options().box_chars();
+        let recent_select = &mut self.state.game.recent_select;
+        let Some(info) = recent_select.games.get(recent_select.selected) else {
+            recent_select.preview = None;
+            return;
+        };
+        if recent_select
+            .preview
+            .as_ref()
+            .is_some_and(|(path, _)| *path == info.path)
+        {
+            return;
+        }
+
+        let lines = save::load_game(&info.path)
+            .map(|game_save| {
+                let solution = &game_save.puzzle.grid.solution;
+                solution
+                    .iter()
+                    .enumerate()
+                    .map(|(row, line)| {
+                        line.chars()
+                            .enumerate()
+                            .map(|(col, c)| match c {
+                                '.' => chars.filled,
+                                _ => game_save
+                                    .user_letters
+                                    .get(row)
+                                    .and_then(|letters| letters.get(col).copied().flatten())
+                                    .unwrap_or(chars.compact_empty),
+                            })
+                            .collect()
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        recent_select.preview = Some((info.path.clone(), lines));
+    }
+
     fn draw_game_selecting(&mut self, frame: &mut ratatui::Frame) {
         let area = frame.area();
         let theme = self.state.theme;
@@ -544,14 +1013,14 @@ impl App {
         // Create centered layout
         let vertical = Layout::vertical([
             Constraint::Min(1),     // Top padding
-            Constraint::Length(12), // Content area
+            Constraint::Length(15), // Content area
             Constraint::Min(1),     // Bottom padding
         ]);
         let [_, content_area, _] = vertical.areas(area);
 
         let horizontal = Layout::horizontal([
             Constraint::Min(1),     // Left padding
-            Constraint::Length(40), // Form area
+            Constraint::Length(52), // Form area
             Constraint::Min(1),     // Right padding
         ]);
         let [_, form_area, _] = horizontal.areas(content_area);
@@ -570,6 +1039,7 @@ impl App {
         let rows = Layout::vertical([
             Constraint::Length(3), // Date field
             Constraint::Length(3), // Provider field
+            Constraint::Length(3), // Mode field
             Constraint::Length(2), // Start button
             Constraint::Length(2), // Error message
         ])
@@ -585,10 +1055,10 @@ impl App {
         } else {
             Style::default().fg(theme.text)
         };
-        let date_title = if selection.use_latest {
-            "Date"
+        let date_title = if selection.active_field == SelectionField::Date {
+            "Date (Enter: calendar)"
         } else {
-            "Date (YYYY-MM-DD)"
+            "Date"
         };
         let date_block = Block::default()
             .title(Span::styled(date_title, Style::default().dim()))
@@ -599,12 +1069,10 @@ impl App {
         let date_text = if selection.use_latest {
             "< Latest >".to_string()
         } else {
-            let cursor_char = if selection.active_field == SelectionField::Date {
-                "_"
-            } else {
-                ""
-            };
-            format!("< {} >", format!("{}{}", selection.date, cursor_char))
+            let weekday = chrono::NaiveDate::parse_from_str(&selection.date, "%Y-%m-%d")
+                .map(|date| date.format(" (%a)").to_string())
+                .unwrap_or_default();
+            format!("< {}{} >", selection.date, weekday)
         };
         frame.render_widget(
             Paragraph::new(date_text).style(date_style).centered(),
@@ -619,24 +1087,50 @@ impl App {
         } else {
             Style::default().fg(theme.text)
         };
+        let provider = PuzzleProvider::ALL.get(selection.provider_idx);
+        let provider_title = match provider {
+            Some(provider) => format!("Provider · {}", provider.language().name()),
+            None => "Provider".to_string(),
+        };
         let provider_block = Block::default()
-            .title(Span::styled("Provider", Style::default().dim()))
+            .title(Span::styled(provider_title, Style::default().dim()))
             .title_alignment(Alignment::Center);
         let provider_inner = provider_block.inner(rows[1]);
         frame.render_widget(provider_block, rows[1]);
 
-        let provider_name = PuzzleProvider::ALL
-            .get(selection.provider_idx)
-            .map(|p| p.name())
-            .unwrap_or("Unknown");
+        let provider_name = provider.map(|p| p.name()).unwrap_or("Unknown");
         let provider_text = format!("< {} >", provider_name);
+        // schedule, size and difficulty below the name, to pick a suitable date
+        let summary = provider.map(|p| p.summary()).unwrap_or_default();
         frame.render_widget(
-            Paragraph::new(provider_text)
-                .style(provider_style)
-                .centered(),
+            Paragraph::new(vec![
+                Line::from(Span::styled(provider_text, provider_style)),
+                Line::from(Span::styled(summary, Style::default().fg(theme.dimmed))),
+            ])
+            .centered(),
             provider_inner,
         );
 
+        // render mode field
+        let mode_style = if selection.active_field == SelectionField::Mode {
+            Style::default()
+                .fg(theme.primary)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme.text)
+        };
+        let mode_block = Block::default()
+            .title(Span::styled("Mode", Style::default().dim()))
+            .title_alignment(Alignment::Center);
+        let mode_inner = mode_block.inner(rows[2]);
+        frame.render_widget(mode_block, rows[2]);
+        frame.render_widget(
+            Paragraph::new(format!("< {} >", selection.mode.name()))
+                .style(mode_style)
+                .centered(),
+            mode_inner,
+        );
+
         // render start button
         let start_style = if selection.active_field == SelectionField::Start {
             Style::default()
@@ -649,7 +1143,7 @@ impl App {
             Paragraph::new("[ Start Game ]")
                 .style(start_style)
                 .centered(),
-            rows[2],
+            rows[3],
         );
 
         // show error message if any
@@ -658,7 +1152,7 @@ impl App {
                 Paragraph::new(error.as_str())
                     .style(Style::default().fg(theme.error))
                     .centered(),
-                rows[3],
+                rows[4],
             );
         }
 
@@ -676,45 +1170,17 @@ impl App {
             Span::styled(" back", Style::default().fg(theme.dimmed)),
         ]);
         frame.render_widget(Paragraph::new(footer).centered(), footer_area);
-    }
-
-    fn draw_game_loading(&mut self, frame: &mut ratatui::Frame) {
-        let area = frame.area();
-        let theme = self.state.theme;
-
-        let vertical = Layout::vertical([
-            Constraint::Min(1),
-            Constraint::Length(3),
-            Constraint::Min(1),
-        ]);
-        let [_, content_area, _] = vertical.areas(area);
-
-        let provider_name = PuzzleProvider::ALL
-            .get(self.state.game.selection.provider_idx)
-            .map(|p| p.name())
-            .unwrap_or("Unknown");
 
-        let loading_text = if self.state.game.selection.use_latest {
-            format!("Loading latest {} puzzle...", provider_name)
-        } else {
-            format!(
-                "Loading {} puzzle for {}...",
-                provider_name, self.state.game.selection.date
-            )
-        };
-        frame.render_widget(
-            Paragraph::new(loading_text)
-                .style(Style::default().fg(theme.secondary))
-                .centered(),
-            content_area,
-        );
+        // date picker popup, over the form
+        self.draw_calendar(frame);
     }
 
-    fn draw_game_playing(&mut self, frame: &mut ratatui::Frame, is_completed: bool) {
+    pub(crate) fn draw_game_playing(&mut self, frame: &mut ratatui::Frame, is_completed: bool) {
         // initialize grid from puzzle if not already done
         if self.state.game.grid.is_none() {
             if let Some(puzzle) = self.state.game.puzzle.as_ref() {
                 let mut grid = PuzzleGrid::from_solution(&puzzle.grid.solution);
+                grid.set_accept_unaccented(self.state.prefs.accept_unaccented);
 
                 // find and select the first letter cell
                 if let Some((row, col)) = grid.find_first_letter_cell() {
@@ -725,9 +1191,7 @@ impl App {
                 self.state.game.grid = Some(grid);
 
                 // start timer when grid is initialized
-                if self.state.game.start_time.is_none() {
-                    self.state.game.start_time = Some(Instant::now());
-                }
+                self.start_timer();
             }
         }
 
@@ -735,60 +1199,101 @@ impl App {
             return; // nothing to draw
         };
 
-        // Calculate content dimensions
-        // Grid: each cell is 4x4, plus 1 for final border
-        let grid_content_width = (grid.width() as u16 * 4) + 1;
-        let grid_content_height = (grid.height() as u16 * 4) + 1;
+        let full_area = frame.area();
+        let helper_height = self.state.prefs.wordplay_helper as u16;
+
+        // Fall back to the compact grid when the normal one does not fit the terminal
+        let (normal_width, normal_height) =
+            RenderOptions::default().grid_size(grid.width(), grid.height());
+        self.state.game.auto_compact = normal_width > full_area.width
+            || 3 + 1 + normal_height + 1 + 3 + helper_height > full_area.height;
 
-        // Total content: header (3) + padding (1) + grid + padding (1) + footer (3)
-        let total_height = 3 + 1 + grid_content_height + 1 + 3;
+        // Calculate content dimensions
+        // Grid: each cell is 4x4 (8x5 zoomed, 2x1 compact), plus 1 for final border
+        let options = self.render_options();
+        let (grid_content_width, grid_content_height) =
+            options.grid_size(grid.width(), grid.height());
+
+        // Total content: header (3) + padding (1) + grid + padding (1) + footer (3),
+        // plus the wordplay helper line when enabled
+        let total_height = 3 + 1 + grid_content_height + 1 + 3 + helper_height;
         let total_width = grid_content_width.max(40); // minimum width for header/footer text
 
-        let full_area = frame.area();
+        // Show the clue panels to the right of the grid when there is room for them
+        let panels_width = CLUE_PANEL_WIDTH + 2;
+        let show_panels = full_area.width >= total_width + panels_width;
+        let outer_width = if show_panels {
+            total_width + panels_width
+        } else {
+            total_width
+        };
 
         // Center horizontally
-        let [centered_area] = Layout::horizontal([Constraint::Length(total_width)])
+        let [outer_area] = Layout::horizontal([Constraint::Length(outer_width)])
             .flex(Flex::Center)
             .areas(full_area);
 
         // Center vertically
-        let [centered_area] = Layout::vertical([Constraint::Length(total_height)])
+        let [outer_area] = Layout::vertical([Constraint::Length(total_height)])
             .flex(Flex::Center)
-            .areas(centered_area);
+            .areas(outer_area);
+
+        let [centered_area, _, panels_area] = Layout::horizontal([
+            Constraint::Length(total_width),
+            Constraint::Length(2),
+            Constraint::Min(0),
+        ])
+        .areas(outer_area);
 
-        // Split into 5 areas: top bar, padding, grid, padding, bottom bar
+        // Split into 6 areas: top bar, padding, grid, padding, bottom bar, helper
         let layout = Layout::vertical([
-            Constraint::Length(3), // top bar with borders
-            Constraint::Length(1), // padding
-            Constraint::Min(1),    // grid area
-            Constraint::Length(1), // padding
-            Constraint::Length(3), // bottom bar with borders
+            Constraint::Length(3),             // top bar with borders
+            Constraint::Length(1),             // padding
+            Constraint::Min(1),                // grid area
+            Constraint::Length(1),             // padding
+            Constraint::Length(3),             // bottom bar with borders
+            Constraint::Length(helper_height), // wordplay helper
         ])
         .split(centered_area);
 
         let top_area = layout[0];
         let grid_area = layout[2];
         let bottom_area = layout[4];
+        let helper_area = layout[5];
 
         // === TOP BAR ===
         self.draw_top_bar(frame, top_area, is_completed);
 
         // === GRID ===
-        self.state.game.visible_area = (grid_area.width, grid_area.height);
-
-        let mut par = grid.to_par(self.state.theme);
-        let (width, height) = (grid_area.width, grid_area.height);
+        // The grid scrolls inside a viewport when it does not fit; an axis that
+        // overflows gets a scrollbar in a gutter along the right or bottom edge, so
+        // the bars never cover the cells themselves.
+        let (content_width, content_height) = (grid_content_width, grid_content_height);
+        let (mut overflow_v, mut overflow_h) = (false, false);
+        for _ in 0..2 {
+            overflow_v = content_height > grid_area.height.saturating_sub(overflow_h as u16);
+            overflow_h = content_width > grid_area.width.saturating_sub(overflow_v as u16);
+        }
+        let view_area = Rect {
+            width: grid_area.width.saturating_sub(overflow_v as u16),
+            height: grid_area.height.saturating_sub(overflow_h as u16),
+            ..grid_area
+        };
+        let (width, height) = (view_area.width, view_area.height);
 
-        // calculate vertical scroll bounds
-        let content_height = (grid.height() as u16 * 4) + 1;
+        // calculate scroll bounds
         let max_scroll_v = content_height.saturating_sub(height);
-
-        // calculate horizontal scroll bounds
-        let content_width = (grid.width() as u16 * 4) + 1;
         let max_scroll_h = content_width.saturating_sub(width);
-
         self.state.game.scroll_max = (max_scroll_v, max_scroll_h);
 
+        // keep the cursor in view when the viewport changes size (e.g. terminal resize)
+        let is_resized = self.state.game.visible_area != (width, height);
+        self.state.game.visible_area = (width, height);
+        self.state.game.grid_area = view_area;
+        if is_resized {
+            self.ensure_selection_visible();
+        }
+
         // clamp scroll position
         self.state.game.scroll_cur.0 = self
             .state
@@ -803,62 +1308,80 @@ impl App {
             .1
             .min(self.state.game.scroll_max.1);
 
-        par = par.scroll((self.state.game.scroll_cur.0, self.state.game.scroll_cur.1));
-        frame.render_widget(par, grid_area);
+        let Some(grid) = self.state.game.grid.as_ref() else {
+            return;
+        };
+        let par = grid
+            .to_par(self.state.theme, options)
+            .scroll((self.state.game.scroll_cur.0, self.state.game.scroll_cur.1));
+        frame.render_widget(par, view_area);
 
         // render vertical scrollbar
-        self.state.game.scroll_bar.0 = self
-            .state
-            .game
-            .scroll_bar
-            .0
-            .content_length(self.state.game.scroll_max.0 as usize)
-            .position(self.state.game.scroll_cur.0 as usize);
-        frame.render_stateful_widget(
-            Scrollbar::new(ScrollbarOrientation::VerticalRight)
-                .begin_symbol(Some("↑"))
-                .end_symbol(Some("↓")),
-            grid_area,
-            &mut self.state.game.scroll_bar.0,
-        );
+        if overflow_v {
+            self.state.game.scroll_bar.0 = self
+                .state
+                .game
+                .scroll_bar
+                .0
+                .content_length(self.state.game.scroll_max.0 as usize)
+                .position(self.state.game.scroll_cur.0 as usize);
+            frame.render_stateful_widget(
+                Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                    .begin_symbol(Some("↑"))
+                    .end_symbol(Some("↓")),
+                Rect {
+                    height: view_area.height,
+                    ..grid_area
+                },
+                &mut self.state.game.scroll_bar.0,
+            );
+        }
 
         // render horizontal scrollbar
-        self.state.game.scroll_bar.1 = self
-            .state
-            .game
-            .scroll_bar
-            .1
-            .content_length(self.state.game.scroll_max.1 as usize)
-            .position(self.state.game.scroll_cur.1 as usize);
-        frame.render_stateful_widget(
-            Scrollbar::new(ScrollbarOrientation::HorizontalBottom)
-                .begin_symbol(Some("←"))
-                .end_symbol(Some("→")),
-            grid_area,
-            &mut self.state.game.scroll_bar.1,
-        );
+        if overflow_h {
+            self.state.game.scroll_bar.1 = self
+                .state
+                .game
+                .scroll_bar
+                .1
+                .content_length(self.state.game.scroll_max.1 as usize)
+                .position(self.state.game.scroll_cur.1 as usize);
+            frame.render_stateful_widget(
+                Scrollbar::new(ScrollbarOrientation::HorizontalBottom)
+                    .begin_symbol(Some("←"))
+                    .end_symbol(Some("→")),
+                Rect {
+                    width: view_area.width,
+                    ..grid_area
+                },
+                &mut self.state.game.scroll_bar.1,
+            );
+        }
 
         // === BOTTOM BAR (CLUE) ===
         self.draw_clue_bar(frame, bottom_area);
+        if self.state.prefs.wordplay_helper {
+            self.draw_wordplay_helper(frame, helper_area);
+        }
+
+        // === CLUE PANELS ===
+        if show_panels {
+            self.draw_clue_panels(frame, panels_area);
+        } else {
+            self.state.game.clue_panels = Default::default();
+        }
 
-        // === FOOTER HINT (at very bottom of screen) ===
+        // === STATUS BAR (at very bottom of screen) ===
         let theme = self.state.theme;
         let footer_area =
             Layout::vertical([Constraint::Min(1), Constraint::Length(1)]).split(full_area)[1];
-        let footer = Line::from(vec![
-            Span::styled("CTRL+H", Style::default().fg(theme.primary)),
-            Span::styled(" help • ", Style::default().fg(theme.dimmed)),
-            Span::styled("CTRL+S", Style::default().fg(theme.primary)),
-            Span::styled(" save • ", Style::default().fg(theme.dimmed)),
-            Span::styled("ESC", Style::default().fg(theme.primary)),
-            Span::styled(" menu", Style::default().fg(theme.dimmed)),
-        ]);
-        frame.render_widget(Paragraph::new(footer).centered(), footer_area);
+        self.draw_status_bar(frame, footer_area);
 
-        // === SAVE NOTIFICATION (top-right corner) ===
-        if let Some(until) = self.state.game.save_notification_until {
-            if Instant::now() < until {
-                let notif_width: u16 = 12;
+        // === NOTIFICATION (top-right corner) ===
+        if let Some((message, until)) = &self.state.game.notification {
+            if Instant::now() < *until {
+                let text = format!(" {} ", message);
+                let notif_width = text.chars().count() as u16;
                 let notif_area = Rect {
                     x: full_area.width.saturating_sub(notif_width + 1),
                     y: 0,
@@ -866,21 +1389,101 @@ impl App {
                     height: 1,
                 };
                 frame.render_widget(
-                    Paragraph::new(" ✓ Saved ")
-                        .style(Style::default().fg(Color::Black).bg(theme.success)),
+                    Paragraph::new(text).style(Style::default().fg(Color::Black).bg(theme.success)),
                     notif_area,
                 );
             }
         }
     }
 
+    /// Draw the status bar: provider, setter and fill gauge (left), key hints (right).
+    ///
+    /// The puzzle details are left out when the terminal is too narrow for both.
+    fn draw_status_bar(&self, frame: &mut ratatui::Frame, area: Rect) {
+        let theme = self.state.theme;
+        let hints = Line::from(vec![
+            Span::styled("CTRL+H", Style::default().fg(theme.primary)),
+            Span::styled(" help • ", Style::default().fg(theme.dimmed)),
+            Span::styled("CTRL+S", Style::default().fg(theme.primary)),
+            Span::styled(" save • ", Style::default().fg(theme.dimmed)),
+            Span::styled("ESC", Style::default().fg(theme.primary)),
+            Span::styled(" menu", Style::default().fg(theme.dimmed)),
+        ]);
+
+        let mut details = vec![Span::styled(
+            self.current_provider_name(),
+            Style::default().fg(theme.secondary),
+        )];
+        let author = self
+            .state
+            .game
+            .puzzle
+            .as_ref()
+            .map(|p| p.info.author.trim())
+            .filter(|author| !author.is_empty());
+        if let Some(author) = author {
+            details.push(Span::styled(" • by ", Style::default().fg(theme.dimmed)));
+            details.push(Span::styled(
+                author.to_string(),
+                Style::default().fg(theme.text),
+            ));
+        }
+        if let Some(grid) = self.state.game.grid.as_ref() {
+            details.push(Span::raw("  "));
+            details.extend(progress_gauge(
+                grid.progress().percentage(),
+                self.render_options().box_chars(),
+                theme,
+            ));
+        }
+        let details = Line::from(details);
+
+        // one space of padding on either side
+        if details.width() + hints.width() + 4 > area.width as usize {
+            frame.render_widget(Paragraph::new(hints).centered(), area);
+            return;
+        }
+        let area = area.inner(ratatui::layout::Margin::new(1, 0));
+        frame.render_widget(Paragraph::new(details), area);
+        frame.render_widget(Paragraph::new(hints).right_aligned(), area);
+    }
+
     /// Draw the top bar: date (left), title (center), completion% + timer (right).
     fn draw_top_bar(&self, frame: &mut ratatui::Frame, area: Rect, is_completed: bool) {
         // Use the full area without borders
         let inner = area;
         let theme = self.state.theme;
 
-        let date_str = self.state.game.puzzle_date.as_deref().unwrap_or("No date");
+        // In hot-seat mode the left side shows whose turn it is instead of the date
+        let date_str = match self.state.game.hot_seat.as_ref() {
+            Some(hot_seat) => {
+                let marker = |player: usize| {
+                    if hot_seat.current == player {
+                        "▸"
+                    } else {
+                        " "
+                    }
+                };
+                let remaining = hot_seat
+                    .turn_remaining()
+                    .map(|d| format!(" {}s", d.as_secs()))
+                    .unwrap_or_default();
+                format!(
+                    "{}P1 {} {}P2 {}{}",
+                    marker(0),
+                    hot_seat.scores[0],
+                    marker(1),
+                    hot_seat.scores[1],
+                    remaining
+                )
+            }
+            None => self
+                .state
+                .game
+                .puzzle_date
+                .clone()
+                .unwrap_or_else(|| "No date".to_string()),
+        };
 
         let title_str = self
             .state
@@ -894,9 +1497,14 @@ impl App {
         let timer_duration = if is_completed {
             self.state.game.completion_time
         } else {
-            self.state.game.start_time.map(|start| start.elapsed())
+            self.state.game.elapsed()
+        };
+        // The timer can be hidden while playing, but is always shown once completed
+        let timer_str = if is_completed || self.state.prefs.show_timer {
+            format_duration(timer_duration)
+        } else {
+            String::new()
         };
-        let timer_str = format_duration(timer_duration);
 
         // Completion percentage
         let (completion_str, completion_style) = if let Some(grid) = self.state.game.grid.as_ref() {
@@ -915,14 +1523,39 @@ impl App {
             ("0%".to_string(), Style::default().fg(theme.text))
         };
 
-        // Right side: "XX% MM:SS"
-        let right_str = format!("{} {}", completion_str, timer_str);
-        let right_len = right_str.len();
-
-        // Calculate spacing for centering the title
+        // Target: time left while under it, overtime once past it
+        let (target_str, target_over) = match (self.state.game.target_time, timer_duration) {
+            (Some(target), Some(elapsed)) if !timer_str.is_empty() => {
+                if elapsed <= target {
+                    (
+                        format!("-{}", format_duration(Some(target - elapsed))),
+                        false,
+                    )
+                } else {
+                    (
+                        format!("+{}", format_duration(Some(elapsed - target))),
+                        true,
+                    )
+                }
+            }
+            _ => (String::new(), false),
+        };
+
+        // Right side: "XX% MM:SS -MM:SS" (or just "XX%" with the timer hidden)
+        let mut right_str = if timer_str.is_empty() {
+            completion_str.clone()
+        } else {
+            format!("{} {}", completion_str, timer_str)
+        };
+        if !target_str.is_empty() {
+            right_str = format!("{} {}", right_str, target_str);
+        }
+        let right_len = Span::raw(right_str.as_str()).width();
+
+        // Calculate spacing for centering the title, in terminal columns
         let total_width = inner.width as usize;
-        let date_len = date_str.len();
-        let title_len = title_str.len();
+        let date_len = Span::raw(date_str.as_str()).width();
+        let title_len = Span::raw(title_str).width();
 
         // Try to center the title
         let left_space = (total_width.saturating_sub(title_len)) / 2;
@@ -952,8 +1585,19 @@ impl App {
         }
 
         spans.push(Span::styled(completion_str, completion_style));
-        spans.push(Span::raw(" "));
-        spans.push(Span::styled(timer_str, timer_style));
+        if !timer_str.is_empty() {
+            spans.push(Span::raw(" "));
+            spans.push(Span::styled(timer_str, timer_style));
+        }
+        if !target_str.is_empty() {
+            let target_style = if target_over {
+                Style::default().fg(theme.error)
+            } else {
+                dim_style
+            };
+            spans.push(Span::raw(" "));
+            spans.push(Span::styled(target_str, target_style));
+        }
 
         let line = Line::from(spans);
         frame.render_widget(Paragraph::new(line), inner);
@@ -976,7 +1620,19 @@ impl App {
 
         // Popup dimensions
         let popup_width: u16 = 40;
-        let popup_height: u16 = 9;
+        let hot_seat_height: u16 = if self.state.game.hot_seat.is_some() {
+            2
+        } else {
+            0
+        };
+        let target_height: u16 = if self.state.game.target_time.is_some() {
+            1
+        } else {
+            0
+        };
+        let assists = self.state.game.assists;
+        let assists_height = !assists.is_none() as u16;
+        let popup_height: u16 = 10 + hot_seat_height + target_height + assists_height;
 
         // Center the popup
         let [centered_area] = Layout::horizontal([Constraint::Length(popup_width)])
@@ -1008,7 +1664,7 @@ impl App {
             .add_modifier(Modifier::BOLD);
         let normal_style = Style::default().fg(theme.text);
 
-        let options = ["Continue Playing", "Back to Menu"];
+        let options = ["Continue Playing", "Watch Replay", "Back to Menu"];
         let option_lines = options.iter().enumerate().map(|(i, opt)| {
             let (prefix, style) = if i == selected {
                 ("> ", selected_style)
@@ -1018,6 +1674,44 @@ impl App {
             Line::from(Span::styled(format!("{}{}", prefix, opt), style))
         });
 
+        // How the solve went against the target time
+        let target_line = match (self.state.game.target_time, self.state.game.completion_time) {
+            (Some(target), Some(time)) if time <= target => Some(Line::from(Span::styled(
+                format!("{} under target", format_duration(Some(target - time))),
+                Style::default().fg(theme.success),
+            ))),
+            (Some(target), Some(time)) => Some(Line::from(Span::styled(
+                format!("{} over target", format_duration(Some(time - target))),
+                Style::default().fg(theme.error),
+            ))),
+            _ => None,
+        };
+
+        // Help used along the way
+        let plural =
+            |n: u32, what: &str| format!("{} {}{}", n, what, if n == 1 { "" } else { "s" });
+        let assists_line = (!assists.is_none()).then(|| {
+            Line::from(Span::styled(
+                format!(
+                    "with {} revealed and {}",
+                    plural(assists.revealed, "letter"),
+                    plural(assists.checks, "check")
+                ),
+                Style::default().fg(theme.dimmed),
+            ))
+        });
+
+        // Per-player scores for hot-seat sessions
+        let score_lines = self.state.game.hot_seat.as_ref().map(|hot_seat| {
+            [
+                Line::from(Span::styled(
+                    hot_seat.result(),
+                    Style::default().fg(theme.secondary),
+                )),
+                Line::from(""),
+            ]
+        });
+
         let lines: Vec<Line> = [
             Line::from(""),
             Line::from(Span::styled(
@@ -1026,9 +1720,12 @@ impl App {
                     .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             )),
-            Line::from(""),
         ]
         .into_iter()
+        .chain(assists_line)
+        .chain(target_line)
+        .chain([Line::from("")])
+        .chain(score_lines.into_iter().flatten())
         .chain(option_lines)
         .collect();
 
@@ -1080,12 +1777,41 @@ impl App {
             ),
         };
 
-        Some(Line::from(vec![
+        let mut spans = vec![
             Span::styled(clue_no.to_string(), Style::default().fg(theme.primary)),
             Span::styled(dir_char.to_string(), Style::default().fg(theme.secondary)),
             Span::styled(": ", Style::default().fg(theme.dimmed)),
             Span::styled(clue_text.to_string(), Style::default().fg(theme.text)),
-        ]))
+        ];
+        // Clues without an enumeration get the length of the word in the grid
+        if !cruciverbal_providers::entries::has_enumeration(clue_text) {
+            let length = grid.word_cells(clue_no, effective_dir).len();
+            spans.push(Span::styled(
+                format!(" ({})", length),
+                Style::default().fg(theme.dimmed),
+            ));
+        }
+
+        // Once solved, show the answer with its word breaks ("OUT OF SORTS")
+        let is_completed = matches!(
+            self.view,
+            crate::AppView::Game(GameView::Completed | GameView::CompletedPlaying)
+        );
+        if is_completed && unchecked_reason(puzzle).is_none() {
+            let answer = cruciverbal_providers::entries::entries(puzzle)
+                .into_iter()
+                .find(|entry| {
+                    entry.number as usize == clue_no
+                        && Direction::from(entry.direction) == effective_dir
+                })
+                .map(|entry| entry.display_answer());
+            if let Some(answer) = answer {
+                spans.push(Span::styled(" → ", Style::default().fg(theme.dimmed)));
+                spans.push(Span::styled(answer, Style::default().fg(theme.success)));
+            }
+        }
+
+        Some(Line::from(spans))
     }
 
     pub fn handle_game_input(&mut self, view: GameView, key: KeyEvent) {
@@ -1093,23 +1819,58 @@ impl App {
             GameView::Selecting => self.handle_selecting_input(key),
             GameView::LoadSelect => self.handle_load_select_input(key),
             GameView::RecentSelect => self.handle_recent_select_input(key),
-            GameView::Loading => {
-                // ESC cancels loading and goes back to menu
-                if key.code == KeyCode::Esc {
-                    use crate::AppView;
-                    self.view = AppView::Menu;
+            GameView::Loading => self.handle_loading_input(key),
+            GameView::Playing | GameView::Assist => {
+                let before = self.state.game.grid.as_ref().map(replay::grid_letters);
+                let recorded = self.state.game.replay.len();
+                if view == GameView::Assist {
+                    self.handle_assist_input(key);
+                } else {
+                    self.handle_playing_input(key);
                 }
+                if let Some(before) = before {
+                    self.record_replay_changes(&before);
+                }
+                let edits = self.state.game.replay.len().saturating_sub(recorded);
+                self.state.game.edits_since_save += edits as u32;
             }
-            GameView::Playing => self.handle_playing_input(key),
             GameView::Completed => self.handle_completed_input(key),
             GameView::CompletedPlaying => self.handle_completed_playing_input(key),
+            GameView::Replay => self.handle_replay_input(key),
             GameView::Saving => {}
+            GameView::Info => {
+                // any key closes the info popup
+                self.view = crate::AppView::Game(GameView::Playing);
+            }
+            GameView::Help => self.handle_game_help_input(key),
         }
     }
 
     fn handle_load_select_input(&mut self, key: KeyEvent) {
         use crate::AppView;
 
+        let load_select = &mut self.state.game.load_select;
+        if let Some(name) = load_select.rename.as_mut() {
+            match key.code {
+                KeyCode::Esc => load_select.rename = None,
+                KeyCode::Enter => self.rename_selected_save(),
+                KeyCode::Backspace => {
+                    name.pop();
+                }
+                KeyCode::Char(c) => name.push(c),
+                _ => {}
+            }
+            return;
+        }
+        if load_select.confirm_delete {
+            load_select.confirm_delete = false;
+            if matches!(key.code, KeyCode::Char('y') | KeyCode::Char('Y')) {
+                self.delete_selected_save();
+            }
+            return;
+        }
+        load_select.error = None;
+
         match key.code {
             KeyCode::Esc => {
                 self.view = AppView::Menu;
@@ -1120,24 +1881,159 @@ impl App {
                 }
             }
             KeyCode::Down => {
-                let len = self.state.game.load_select.saves.len();
-                if len > 0 && self.state.game.load_select.selected < len - 1 {
+                // saves are followed by the bundled samples
+                let len = self.state.game.load_select.saves.len() + SAMPLES.len();
+                if self.state.game.load_select.selected < len - 1 {
                     self.state.game.load_select.selected += 1;
                 }
             }
             KeyCode::Enter => {
-                self.load_selected_save();
+                let saves_len = self.state.game.load_select.saves.len();
+                match self.state.game.load_select.selected.checked_sub(saves_len) {
+                    Some(sample_idx) => self.start_sample(sample_idx),
+                    None => self.load_selected_save(),
+                }
             }
-            KeyCode::Delete | KeyCode::Backspace => {
-                self.delete_selected_save();
+            KeyCode::Delete | KeyCode::Backspace | KeyCode::Char('d') => {
+                let load_select = &mut self.state.game.load_select;
+                load_select.confirm_delete = load_select.selected < load_select.saves.len();
+            }
+            KeyCode::Char('r') => {
+                let load_select = &mut self.state.game.load_select;
+                if let Some(save_info) = load_select.saves.get(load_select.selected) {
+                    load_select.rename = Some(save_info.name.clone());
+                    load_select.error = None;
+                }
+            }
+            KeyCode::Char('s') => {
+                // re-sort, keeping the same save selected
+                let load_select = &mut self.state.game.load_select;
+                let selected_path = load_select
+                    .saves
+                    .get(load_select.selected)
+                    .map(|s| s.path.clone());
+                load_select.sort = load_select.sort.next();
+                load_select.sort.sort(&mut load_select.saves);
+                if let Some(idx) = selected_path
+                    .and_then(|path| load_select.saves.iter().position(|s| s.path == path))
+                {
+                    load_select.selected = idx;
+                }
             }
             _ => {}
         }
     }
 
-    fn load_selected_save(&mut self) {
+    /// Rename the selected save to the name typed in.
+    fn rename_selected_save(&mut self) {
+        let load_select = &mut self.state.game.load_select;
+        let Some(name) = load_select.rename.take() else {
+            return;
+        };
+        let Some(save_info) = load_select.saves.get_mut(load_select.selected) else {
+            return;
+        };
+        match save::rename_save(&save_info.path, &name) {
+            Ok(path) => {
+                save_info.name = name.trim().to_string();
+                save_info.path = path;
+            }
+            Err(e) => load_select.error = Some(format!("Failed to rename: {}", e)),
+        }
+    }
+
+    /// Restore the game state from a save and continue playing it.
+    pub fn restore_game_save(&mut self, mut game_save: save::GameSave) {
         use crate::AppView;
 
+        // Older or hand-edited saves may not match their puzzle
+        let repairs = game_save.repair(self.state.prefs.accept_unaccented);
+
+        self.state.game.puzzle = Some(game_save.puzzle.clone());
+        self.state.game.puzzle_date = Some(game_save.puzzle_date);
+        self.state.game.source = game_save.source;
+        self.state.game.provider_idx = Some(game_save.provider_idx);
+        self.state.game.sel = game_save.sel;
+        self.state.game.active_direction = game_save.active_direction;
+        self.state.game.completion_state = game_save.completion_state;
+
+        // Build grid from puzzle solution
+        let mut grid = PuzzleGrid::from_solution(&game_save.puzzle.grid.solution);
+        grid.set_accept_unaccented(self.state.prefs.accept_unaccented);
+
+        // Apply user letters
+        for (row_idx, row) in game_save.user_letters.iter().enumerate() {
+            for (col_idx, letter) in row.iter().enumerate() {
+                if let Some(cell) = grid.get_mut(row_idx, col_idx) {
+                    cell.set_user_letter(*letter);
+                }
+            }
+        }
+
+        // Set selection
+        let (row, col) = game_save.sel;
+        grid.set_selection(row, col, game_save.active_direction);
+
+        self.state.game.grid = Some(grid);
+
+        // Restore timer: set start_time to now minus elapsed seconds
+        let elapsed = Duration::from_secs(game_save.elapsed_secs);
+        self.state.game.start_time = Some(Instant::now() - elapsed);
+        self.state.game.target_time = game_save.target_secs.map(Duration::from_secs);
+        self.state.game.replay = game_save.replay;
+        self.state.game.assists = game_save.assists;
+
+        if !repairs.is_empty() {
+            self.state.game.notification = Some((
+                format!("⚠ Save repaired: {}", repairs.join("; ")),
+                Instant::now() + Duration::from_secs(5),
+            ));
+        }
+
+        self.view = AppView::Game(GameView::Playing);
+    }
+
+    /// Move the cursor to a cell, building the grid first if it isn't yet.
+    pub fn focus_cell(&mut self, row: usize, col: usize, direction: Direction) {
+        if let (None, Some(puzzle)) = (
+            self.state.game.grid.as_ref(),
+            self.state.game.puzzle.as_ref(),
+        ) {
+            let mut grid = PuzzleGrid::from_solution(&puzzle.grid.solution);
+            grid.set_accept_unaccented(self.state.prefs.accept_unaccented);
+            self.state.game.grid = Some(grid);
+            self.start_timer();
+        }
+
+        self.state.game.active_direction = direction;
+        let selected = self
+            .state
+            .game
+            .grid
+            .as_mut()
+            .is_some_and(|grid| grid.set_selection(row, col, direction));
+        if selected {
+            self.state.game.sel = (row, col);
+        }
+    }
+
+    /// Start a new game with one of the bundled sample puzzles.
+    fn start_sample(&mut self, sample_idx: usize) {
+        let Some(sample) = SAMPLES.get(sample_idx) else {
+            return;
+        };
+
+        match sample.puzzle() {
+            Ok(puzzle) => {
+                self.start_downloaded_puzzle(puzzle, Some(sample.source()), sample.id.to_string());
+            }
+            Err(e) => {
+                self.state.game.load_select.error = Some(format!("Failed to load sample: {}", e));
+            }
+        }
+    }
+
+    fn load_selected_save(&mut self) {
         let selected = self.state.game.load_select.selected;
         let saves = &self.state.game.load_select.saves;
 
@@ -1149,37 +2045,7 @@ impl App {
 
         match save::load_game(&save_path) {
             Ok(game_save) => {
-                // Restore the game state from save
-                self.state.game.puzzle = Some(game_save.puzzle.clone());
-                self.state.game.puzzle_date = Some(game_save.puzzle_date);
-                self.state.game.provider_idx = Some(game_save.provider_idx);
-                self.state.game.sel = game_save.sel;
-                self.state.game.active_direction = game_save.active_direction;
-                self.state.game.completion_state = game_save.completion_state;
-
-                // Build grid from puzzle solution
-                let mut grid = PuzzleGrid::from_solution(&game_save.puzzle.grid.solution);
-
-                // Apply user letters
-                for (row_idx, row) in game_save.user_letters.iter().enumerate() {
-                    for (col_idx, letter) in row.iter().enumerate() {
-                        if let Some(cell) = grid.get_mut(row_idx, col_idx) {
-                            cell.set_user_letter(*letter);
-                        }
-                    }
-                }
-
-                // Set selection
-                let (row, col) = game_save.sel;
-                grid.set_selection(row, col, game_save.active_direction);
-
-                self.state.game.grid = Some(grid);
-
-                // Restore timer: set start_time to now minus elapsed seconds
-                let elapsed = Duration::from_secs(game_save.elapsed_secs);
-                self.state.game.start_time = Some(Instant::now() - elapsed);
-
-                self.view = AppView::Game(GameView::Playing);
+                self.restore_game_save(game_save);
             }
             Err(e) => {
                 self.state.game.load_select.error = Some(format!("Failed to load: {}", e));
@@ -1197,12 +2063,14 @@ impl App {
             KeyCode::Up => {
                 if self.state.game.recent_select.selected > 0 {
                     self.state.game.recent_select.selected -= 1;
+                    self.state.game.recent_select.error = None;
                 }
             }
             KeyCode::Down => {
                 let len = self.state.game.recent_select.games.len();
                 if len > 0 && self.state.game.recent_select.selected < len - 1 {
                     self.state.game.recent_select.selected += 1;
+                    self.state.game.recent_select.error = None;
                 }
             }
             KeyCode::Enter => {
@@ -1213,8 +2081,6 @@ impl App {
     }
 
     fn load_selected_recent_game(&mut self) {
-        use crate::AppView;
-
         let selected = self.state.game.recent_select.selected;
         let games = &self.state.game.recent_select.games;
 
@@ -1226,37 +2092,7 @@ impl App {
 
         match save::load_game(&save_path) {
             Ok(game_save) => {
-                // Restore the game state from save
-                self.state.game.puzzle = Some(game_save.puzzle.clone());
-                self.state.game.puzzle_date = Some(game_save.puzzle_date);
-                self.state.game.provider_idx = Some(game_save.provider_idx);
-                self.state.game.sel = game_save.sel;
-                self.state.game.active_direction = game_save.active_direction;
-                self.state.game.completion_state = game_save.completion_state;
-
-                // Build grid from puzzle solution
-                let mut grid = PuzzleGrid::from_solution(&game_save.puzzle.grid.solution);
-
-                // Apply user letters
-                for (row_idx, row) in game_save.user_letters.iter().enumerate() {
-                    for (col_idx, letter) in row.iter().enumerate() {
-                        if let Some(cell) = grid.get_mut(row_idx, col_idx) {
-                            cell.set_user_letter(*letter);
-                        }
-                    }
-                }
-
-                // Set selection
-                let (row, col) = game_save.sel;
-                grid.set_selection(row, col, game_save.active_direction);
-
-                self.state.game.grid = Some(grid);
-
-                // Restore timer: set start_time to now minus elapsed seconds
-                let elapsed = Duration::from_secs(game_save.elapsed_secs);
-                self.state.game.start_time = Some(Instant::now() - elapsed);
-
-                self.view = AppView::Game(GameView::Playing);
+                self.restore_game_save(game_save);
             }
             Err(e) => {
                 // Refresh list and show error
@@ -1304,7 +2140,7 @@ impl App {
                 }
             }
             KeyCode::Down => {
-                if self.state.game.completed_popup_selection < 1 {
+                if self.state.game.completed_popup_selection < 2 {
                     self.state.game.completed_popup_selection += 1;
                 }
             }
@@ -1314,7 +2150,8 @@ impl App {
                         // Continue Playing
                         self.view = AppView::Game(GameView::CompletedPlaying);
                     }
-                    1 => {
+                    1 => self.open_replay(),
+                    2 => {
                         // Back to Menu (auto-save first)
                         self.auto_save_current_game();
                         self.view = AppView::Menu;
@@ -1364,9 +2201,44 @@ impl App {
         }
     }
 
+    /// Move to the first (or last) cell of the current word, or of the whole grid
+    /// with `grid_corner`.
+    fn jump_to_word_end(&mut self, last: bool, grid_corner: bool) {
+        let Some(grid) = self.state.game.grid.as_ref() else {
+            return;
+        };
+        let (row, col) = self.state.game.sel;
+        let direction = self.state.game.active_direction;
+
+        let target = if grid_corner {
+            if last {
+                grid.find_last_letter_cell()
+            } else {
+                grid.find_first_letter_cell()
+            }
+        } else {
+            let word = grid
+                .get(row, col)
+                .and_then(|cell| cell.clue_no_for_direction(direction))
+                .map(|clue_no| grid.word_cells(clue_no, direction))
+                .unwrap_or_default();
+            if last { word.last() } else { word.first() }.copied()
+        };
+
+        if let Some((row, col)) = target {
+            self.focus_cell(row, col, direction);
+            self.ensure_selection_visible();
+        }
+    }
+
     fn handle_selecting_input(&mut self, key: KeyEvent) {
         use crate::AppView;
 
+        if self.state.game.selection.calendar.is_some() {
+            self.handle_calendar_input(key);
+            return;
+        }
+
         match key.code {
             KeyCode::Esc => {
                 self.view = AppView::Menu;
@@ -1389,13 +2261,15 @@ impl App {
                         self.state.game.selection.use_latest =
                             !self.state.game.selection.use_latest;
                     }
-                    SelectionField::Provider => {
-                        let len = PuzzleProvider::ALL.len();
-                        if self.state.game.selection.provider_idx == 0 {
-                            self.state.game.selection.provider_idx = len - 1;
-                        } else {
-                            self.state.game.selection.provider_idx -= 1;
-                        }
+                    SelectionField::Provider => self.state.game.selection.step_provider(false),
+                    SelectionField::Mode => {
+                        let modes = PlayMode::ALL;
+                        let idx = modes
+                            .iter()
+                            .position(|m| *m == self.state.game.selection.mode)
+                            .unwrap_or(0);
+                        self.state.game.selection.mode =
+                            modes[(idx + modes.len() - 1) % modes.len()];
                     }
                     _ => {}
                 }
@@ -1408,10 +2282,14 @@ impl App {
                         self.state.game.selection.use_latest =
                             !self.state.game.selection.use_latest;
                     }
-                    SelectionField::Provider => {
-                        let len = PuzzleProvider::ALL.len();
-                        self.state.game.selection.provider_idx =
-                            (self.state.game.selection.provider_idx + 1) % len;
+                    SelectionField::Provider => self.state.game.selection.step_provider(true),
+                    SelectionField::Mode => {
+                        let modes = PlayMode::ALL;
+                        let idx = modes
+                            .iter()
+                            .position(|m| *m == self.state.game.selection.mode)
+                            .unwrap_or(0);
+                        self.state.game.selection.mode = modes[(idx + 1) % modes.len()];
                     }
                     _ => {}
                 }
@@ -1424,6 +2302,8 @@ impl App {
                         self.state.game.selection.error = None;
                         self.view = AppView::Game(GameView::Loading);
                     }
+                } else if self.state.game.selection.active_field == SelectionField::Date {
+                    self.open_calendar();
                 } else {
                     // Move to next field
                     self.state.game.selection.active_field =
@@ -1431,26 +2311,16 @@ impl App {
                 }
             }
 
-            KeyCode::Char(c) => {
-                let is_date_field = self.state.game.selection.active_field == SelectionField::Date;
-                let is_not_latest = !self.state.game.selection.use_latest;
-                let is_valid_char = c.is_ascii_digit() || c == '-';
-                let has_room = self.state.game.selection.date.len() < 10;
-
-                if is_date_field && is_not_latest && is_valid_char && has_room {
-                    self.state.game.selection.date.push(c);
-                    self.state.game.selection.error = None;
-                }
+            // quick dates: today, yesterday
+            KeyCode::Char('t')
+                if self.state.game.selection.active_field == SelectionField::Date =>
+            {
+                self.pick_date(self.state.game.selection.provider().today());
             }
-
-            KeyCode::Backspace => {
-                let is_date_field = self.state.game.selection.active_field == SelectionField::Date;
-                let is_not_latest = !self.state.game.selection.use_latest;
-
-                if is_date_field && is_not_latest {
-                    self.state.game.selection.date.pop();
-                    self.state.game.selection.error = None;
-                }
+            KeyCode::Char('y')
+                if self.state.game.selection.active_field == SelectionField::Date =>
+            {
+                self.pick_date(self.state.game.selection.provider().today() - chrono::Days::new(1));
             }
 
             _ => {}
@@ -1458,12 +2328,31 @@ impl App {
     }
 
     fn validate_date(&mut self) -> bool {
-        if chrono::NaiveDate::parse_from_str(&self.state.game.selection.date, "%Y-%m-%d").is_err() {
-            self.state.game.selection.error = Some("Invalid date format".to_string());
-            false
-        } else {
-            true
+        let selection = &mut self.state.game.selection;
+        let Ok(date) = chrono::NaiveDate::parse_from_str(&selection.date, "%Y-%m-%d") else {
+            selection.error = Some("Invalid date format".to_string());
+            return false;
+        };
+
+        let provider = PuzzleProvider::ALL
+            .get(selection.provider_idx)
+            .copied()
+            .unwrap_or_default();
+        // the latest puzzle would be cached and saved under the picked date
+        if !provider.supports_dates() {
+            selection.error = Some(format!("{} only offers its latest puzzle", provider.name()));
+            return false;
+        }
+        if !provider.schedule().publishes_on(date) {
+            selection.error = Some(format!(
+                "No {} puzzle on {}s: {}",
+                provider.name(),
+                date.format("%A"),
+                provider.schedule().name()
+            ));
+            return false;
         }
+        true
     }
 
     fn handle_playing_input(&mut self, key: KeyEvent) {
@@ -1478,33 +2367,31 @@ impl App {
         let is_ctrl_r_char = key.code == KeyCode::Char('\x12');
 
         if is_ctrl_r {
-            // SHIFT+CTRL+R: reveal current word
-            if key.modifiers.contains(KeyModifiers::SHIFT) {
-                self.reveal_current_word();
-                self.advance_to_next_cell();
-                self.check_completion();
-                return;
-            }
-            // ALT+CTRL+R: reveal entire puzzle
-            else if key.modifiers.contains(KeyModifiers::ALT) {
-                if let Some(grid) = self.state.game.grid.as_mut() {
-                    grid.reveal_all();
-                }
-                self.check_completion();
-                return;
-            }
-            // CTRL+R: reveal current letter
-            else {
-                self.reveal_current_letter();
-                self.advance_to_next_cell();
-                self.check_completion();
-                return;
-            }
+            let scope = if key.modifiers.contains(KeyModifiers::SHIFT) {
+                // SHIFT+CTRL+R: reveal current word
+                AssistScope::Word
+            } else if key.modifiers.contains(KeyModifiers::ALT) {
+                // ALT+CTRL+R: reveal entire puzzle
+                AssistScope::Puzzle
+            } else {
+                // CTRL+R: reveal current letter
+                AssistScope::Letter
+            };
+            self.reveal(scope);
+            return;
         } else if is_ctrl_r_char {
             // CTRL+R as control character: reveal current letter
-            self.reveal_current_letter();
-            self.advance_to_next_cell();
-            self.check_completion();
+            self.reveal(AssistScope::Letter);
+            return;
+        }
+
+        // CTRL+K: check and reveal menu
+        let is_ctrl_k = matches!(key.code, KeyCode::Char('k') | KeyCode::Char('K'))
+            && key.modifiers.contains(KeyModifiers::CONTROL);
+        let is_ctrl_k_char = key.code == KeyCode::Char('\x0b');
+
+        if is_ctrl_k || is_ctrl_k_char {
+            self.open_assist_menu();
             return;
         }
 
@@ -1518,19 +2405,123 @@ impl App {
             return;
         }
 
+        // CTRL+Y: copy the current fill as text, to share it
+        let is_ctrl_y = matches!(key.code, KeyCode::Char('y') | KeyCode::Char('Y'))
+            && key.modifiers.contains(KeyModifiers::CONTROL);
+        let is_ctrl_y_char = key.code == KeyCode::Char('\x19');
+
+        if is_ctrl_y || is_ctrl_y_char {
+            self.share_current_fill();
+            return;
+        }
+
+        // CTRL+E: export the grid and clues for printing
+        let is_ctrl_e = matches!(key.code, KeyCode::Char('e') | KeyCode::Char('E'))
+            && key.modifiers.contains(KeyModifiers::CONTROL);
+        let is_ctrl_e_char = key.code == KeyCode::Char('\x05');
+
+        if is_ctrl_e || is_ctrl_e_char {
+            self.print_current_game();
+            return;
+        }
+
+        // CTRL+X: export unsolved words as patterns for external tools
+        let is_ctrl_x = matches!(key.code, KeyCode::Char('x') | KeyCode::Char('X'))
+            && key.modifiers.contains(KeyModifiers::CONTROL);
+        let is_ctrl_x_char = key.code == KeyCode::Char('\x18');
+
+        if is_ctrl_x || is_ctrl_x_char {
+            self.export_patterns();
+            return;
+        }
+
+        // CTRL+O: import answers from the exported pattern files
+        let is_ctrl_o = matches!(key.code, KeyCode::Char('o') | KeyCode::Char('O'))
+            && key.modifiers.contains(KeyModifiers::CONTROL);
+        let is_ctrl_o_char = key.code == KeyCode::Char('\x0f');
+
+        if is_ctrl_o || is_ctrl_o_char {
+            self.import_answers();
+            return;
+        }
+
+        // CTRL+P: show puzzle info
+        let is_ctrl_p = matches!(key.code, KeyCode::Char('p') | KeyCode::Char('P'))
+            && key.modifiers.contains(KeyModifiers::CONTROL);
+        let is_ctrl_p_char = key.code == KeyCode::Char('\x10');
+
+        if is_ctrl_p || is_ctrl_p_char {
+            use crate::AppView;
+            self.view = AppView::Game(GameView::Info);
+            return;
+        }
+
+        // CTRL+G: suggest where to work next
+        let is_ctrl_g = matches!(key.code, KeyCode::Char('g') | KeyCode::Char('G'))
+            && key.modifiers.contains(KeyModifiers::CONTROL);
+        let is_ctrl_g_char = key.code == KeyCode::Char('\x07');
+
+        if is_ctrl_g || is_ctrl_g_char {
+            self.suggest_next_word();
+            return;
+        }
+
+        // CTRL+T: change the target time
+        let is_ctrl_t = matches!(key.code, KeyCode::Char('t') | KeyCode::Char('T'))
+            && key.modifiers.contains(KeyModifiers::CONTROL);
+        let is_ctrl_t_char = key.code == KeyCode::Char('\x14');
+
+        if is_ctrl_t || is_ctrl_t_char {
+            self.cycle_target_time();
+            return;
+        }
+
+        // CTRL+N: toggle clue numbers
+        let is_ctrl_n = matches!(key.code, KeyCode::Char('n') | KeyCode::Char('N'))
+            && key.modifiers.contains(KeyModifiers::CONTROL);
+        let is_ctrl_n_char = key.code == KeyCode::Char('\x0e');
+
+        if is_ctrl_n || is_ctrl_n_char {
+            self.state.prefs.show_numbers = !self.state.prefs.show_numbers;
+            let _ = crate::preferences::save_preferences(&self.state.prefs);
+            return;
+        }
+
+        // CTRL+L: toggle large print
+        let is_ctrl_l = matches!(key.code, KeyCode::Char('l') | KeyCode::Char('L'))
+            && key.modifiers.contains(KeyModifiers::CONTROL);
+        let is_ctrl_l_char = key.code == KeyCode::Char('\x0c');
+
+        if is_ctrl_l || is_ctrl_l_char {
+            self.state.prefs.large_print = !self.state.prefs.large_print;
+            let _ = crate::preferences::save_preferences(&self.state.prefs);
+
+            // Rescale the scroll bounds so the selection stays in view at the new size
+            if let Some(grid) = self.state.game.grid.as_ref() {
+                let (content_w, content_h) =
+                    self.render_options().grid_size(grid.width(), grid.height());
+                let (visible_w, visible_h) = self.state.game.visible_area;
+                self.state.game.scroll_max = (
+                    content_h.saturating_sub(visible_h),
+                    content_w.saturating_sub(visible_w),
+                );
+            }
+            self.ensure_selection_visible();
+            return;
+        }
+
+        // F2: peek at hidden clue numbers for a moment
+        if key.code == KeyCode::F(2) {
+            self.state.game.numbers_peek_until = Some(Instant::now() + Duration::from_secs(2));
+            return;
+        }
+
         // CTRL+H: show help (note: some terminals send backspace as CTRL+H, so we only check explicit modifier)
         let is_ctrl_h = matches!(key.code, KeyCode::Char('h') | KeyCode::Char('H'))
             && key.modifiers.contains(KeyModifiers::CONTROL);
 
         if is_ctrl_h {
-            use crate::AppView;
-            // Store current view to return to
-            self.previous_view = Some(self.view.clone());
-            // Pause timer by storing elapsed time
-            if let Some(start) = self.state.game.start_time {
-                self.state.game.paused_elapsed = Some(start.elapsed());
-            }
-            self.view = AppView::Help;
+            self.open_game_help();
             return;
         }
 
@@ -1552,17 +2543,40 @@ impl App {
                 self.handle_arrow_navigation(key);
             }
 
-            // letter input: A-Z (and lowercase a-z)
-            KeyCode::Char(c) if c.is_ascii_alphabetic() => {
-                let letter = c.to_ascii_uppercase();
+            // HOME/END: first/last cell of the current word, CTRL for the grid corners
+            KeyCode::Home | KeyCode::End => {
+                self.jump_to_word_end(
+                    key.code == KeyCode::End,
+                    key.modifiers.contains(KeyModifiers::CONTROL),
+                );
+            }
+
+            // PAGEUP/PAGEDOWN: page through the clues of the active direction
+            KeyCode::PageUp => self.page_clue_panel(false),
+            KeyCode::PageDown => self.page_clue_panel(true),
+
+            // TAB/SHIFT+TAB (and the configured extra keys): next/previous clue
+            KeyCode::Tab => self.select_next_clue(true),
+            KeyCode::BackTab => self.select_next_clue(false),
+            KeyCode::Enter if self.state.prefs.clue_keys == ClueKeys::Enter => {
+                self.select_next_clue(true);
+            }
+            KeyCode::Char(c @ (']' | '[')) if self.state.prefs.clue_keys == ClueKeys::Brackets => {
+                self.select_next_clue(c == ']');
+            }
+
+            // letter input: any letter, accented ones included, uppercased
+            KeyCode::Char(c) if c.is_alphabetic() => {
+                let letter = cruciverbal_providers::entries::uppercase_letter(c);
                 let (row, col) = self.state.game.sel;
                 if let Some(grid) = self.state.game.grid.as_mut() {
                     if let Some(cell) = grid.get_mut(row, col) {
                         cell.set_user_letter(Some(letter));
                     }
                 }
-                // auto-advance to next cell in active direction
-                self.advance_to_next_cell();
+                self.settle_hot_seat(true);
+                // auto-advance according to the solver preferences
+                self.advance_after_typing();
                 // check completion after entering a letter
                 self.check_completion();
             }
@@ -1598,6 +2612,8 @@ impl App {
                 self.state.game.completion_time = Some(start.elapsed());
             }
 
+            self.record_solve();
+
             // Reset popup selection
             self.state.game.completed_popup_selection = 0;
 
@@ -1606,8 +2622,147 @@ impl App {
         }
     }
 
+    /// Score the words crossing the selected cell in a hot-seat session.
+    ///
+    /// Pass `award = false` for revealed letters so nobody scores them.
+    fn settle_hot_seat(&mut self, award: bool) {
+        let sel = self.state.game.sel;
+        let direction = self.state.game.active_direction;
+        if let (Some(hot_seat), Some(grid)) = (
+            self.state.game.hot_seat.as_mut(),
+            self.state.game.grid.as_ref(),
+        ) {
+            hot_seat.settle_cell(grid, sel, direction, award);
+        }
+    }
+
+    /// Provider name of the current puzzle, as recorded in its source if it has one
+    /// (bundled samples have no provider of their own).
+    fn current_provider_name(&self) -> String {
+        if let Some(source) = &self.state.game.source {
+            return source.provider.clone();
+        }
+
+        let provider_idx = self
+            .state
+            .game
+            .provider_idx
+            .unwrap_or(self.state.game.selection.provider_idx);
+        PuzzleProvider::ALL
+            .get(provider_idx)
+            .map(|p| p.name())
+            .unwrap_or("Unknown")
+            .to_string()
+    }
+
+    /// Record the solved puzzle in the stats history and refresh the streak reminder.
+    fn record_solve(&mut self) {
+        let provider_name = self.current_provider_name();
+
+        let record = stats::SolveRecord {
+            provider_name,
+            puzzle_date: self
+                .state
+                .game
+                .puzzle_date
+                .clone()
+                .unwrap_or_else(|| "Unknown".to_string()),
+            solved_on: chrono::Local::now().format("%Y-%m-%d").to_string(),
+            elapsed_secs: self
+                .state
+                .game
+                .completion_time
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
+            target_secs: self.state.game.target_time.map(|d| d.as_secs()),
+            assists: self.state.game.assists,
+        };
+        let _ = stats::record_solve(record);
+        self.state.reminder = stats::check_reminder();
+    }
+
+    /// Move to the unfinished word with the most help from its crossings (CTRL+G).
+    ///
+    /// Unlike the reveal keys this gives nothing away, it only points at a place
+    /// where the letters already in the grid should make the answer easier.
+    fn suggest_next_word(&mut self) {
+        let Some(grid) = self.state.game.grid.as_ref() else {
+            return;
+        };
+        let message = match grid.suggested_word() {
+            Some((clue_no, direction)) => {
+                let cells = grid.word_cells(clue_no, direction);
+                let filled = cells
+                    .iter()
+                    .filter(|&&(row, col)| grid.get(row, col).is_some_and(|cell| !cell.is_empty()))
+                    .count();
+                self.select_clue(clue_no, direction);
+                format!(
+                    "Try {} {} ({}/{} letters in)",
+                    clue_no,
+                    direction.name(),
+                    filled,
+                    cells.len()
+                )
+            }
+            None => "Every word is filled in".to_string(),
+        };
+        self.state.game.notification = Some((message, Instant::now() + Duration::from_secs(3)));
+    }
+
+    /// Start the timer for a newly opened puzzle, setting the default target time.
+    fn start_timer(&mut self) {
+        if self.state.game.start_time.is_none() {
+            self.state.game.start_time = Some(Instant::now());
+            self.state.game.target_time = self.target_duration(self.state.prefs.target_time);
+        }
+    }
+
+    /// Resolve a target time setting for the current puzzle.
+    ///
+    /// The average is only known once a puzzle from the same provider was solved.
+    fn target_duration(&self, target: TargetTime) -> Option<Duration> {
+        match target {
+            TargetTime::Off => None,
+            TargetTime::Average => stats::load_stats()
+                .average_secs(&self.current_provider_name())
+                .map(Duration::from_secs),
+            TargetTime::Minutes(minutes) => Some(Duration::from_secs(minutes * 60)),
+        }
+    }
+
+    /// Switch the current puzzle to the next target time (CTRL+T).
+    fn cycle_target_time(&mut self) {
+        let options: Vec<(TargetTime, Option<Duration>)> = TargetTime::ALL
+            .iter()
+            .map(|&target| (target, self.target_duration(target)))
+            // skip the average until there is one
+            .filter(|(target, duration)| *target == TargetTime::Off || duration.is_some())
+            .collect();
+        let idx = options
+            .iter()
+            .position(|(_, duration)| *duration == self.state.game.target_time);
+        let (target, duration) = match idx {
+            Some(i) => options[(i + 1) % options.len()],
+            None => options[0],
+        };
+
+        self.state.game.target_time = duration;
+        let message = match (target, duration) {
+            (TargetTime::Average, Some(d)) => {
+                format!("Target: {} (your average)", format_duration(Some(d)))
+            }
+            (_, Some(d)) => format!("Target: {}", format_duration(Some(d))),
+            (_, None) => "Target off".to_string(),
+        };
+        self.state.game.notification = Some((message, Instant::now() + Duration::from_secs(3)));
+    }
+
     /// Update completion state based on current grid fill.
+    ///
+    /// Without a solution to check against, a full grid counts as correct.
     fn update_completion_state(&mut self) {
+        let unchecked = !self.can_check();
         let Some(grid) = self.state.game.grid.as_ref() else {
             return;
         };
@@ -1616,7 +2771,7 @@ impl App {
 
         if percentage < 100 {
             self.state.game.completion_state = CompletionState::InProgress;
-        } else if grid.is_fully_correct() {
+        } else if unchecked || grid.is_fully_correct() {
             self.state.game.completion_state = CompletionState::Correct;
         } else {
             self.state.game.completion_state = CompletionState::IncorrectFill;
@@ -1635,34 +2790,6 @@ impl App {
         }
     }
 
-    /// Reveal the current cell's letter.
-    fn reveal_current_letter(&mut self) {
-        let (row, col) = self.state.game.sel;
-        if let Some(grid) = self.state.game.grid.as_mut() {
-            if let Some(cell) = grid.get_mut(row, col) {
-                cell.reveal();
-            }
-        }
-    }
-
-    /// Reveal all letters in the currently selected word.
-    fn reveal_current_word(&mut self) {
-        let (row, col) = self.state.game.sel;
-        let direction = self.state.game.active_direction;
-
-        let clue_no = self
-            .state
-            .game
-            .grid
-            .as_ref()
-            .and_then(|grid| grid.get(row, col))
-            .and_then(|cell| cell.clue_no_for_direction(direction));
-
-        if let (Some(clue_no), Some(grid)) = (clue_no, self.state.game.grid.as_mut()) {
-            grid.reveal_word(clue_no, direction);
-        }
-    }
-
     /// Move selection by the given delta, skipping filled cells.
     fn move_selection(&mut self, row_delta: i32, col_delta: i32) {
         let Some(grid) = self.state.game.grid.as_mut() else {
@@ -1803,6 +2930,101 @@ impl App {
         }
     }
 
+    /// Move the cursor after typing a letter, honoring the solver preferences.
+    ///
+    /// Within a word the cursor moves to the next cell (or the next empty cell when
+    /// `skip_filled` is set). At the end of the word, `end_of_word` decides whether
+    /// to keep moving through the grid, stop, jump to the next clue or wrap around.
+    fn advance_after_typing(&mut self) {
+        let skip_filled = self.state.prefs.skip_filled;
+        let end_of_word = self.state.prefs.end_of_word;
+        if !skip_filled && end_of_word == EndOfWord::Continue {
+            self.advance_to_next_cell();
+            return;
+        }
+
+        let Some(grid) = self.state.game.grid.as_ref() else {
+            return;
+        };
+        let direction = self.state.game.active_direction;
+        let sel = self.state.game.sel;
+        let Some(clue_no) = grid
+            .get(sel.0, sel.1)
+            .and_then(|cell| cell.clue_no_for_direction(direction))
+        else {
+            self.advance_to_next_cell();
+            return;
+        };
+
+        let word = grid.word_cells(clue_no, direction);
+        let pos = word.iter().position(|&p| p == sel).unwrap_or(0);
+        let is_candidate = |&(row, col): &(usize, usize)| {
+            !skip_filled || grid.get(row, col).is_some_and(|cell| cell.is_empty())
+        };
+
+        let target = match word[pos + 1..].iter().find(|p| is_candidate(p)) {
+            Some(&next) => Some(next),
+            None => match end_of_word {
+                EndOfWord::Continue => {
+                    self.advance_to_next_cell();
+                    return;
+                }
+                EndOfWord::Stop => None,
+                EndOfWord::NextClue => {
+                    self.select_next_clue(true);
+                    return;
+                }
+                EndOfWord::Wrap => word[..pos].iter().find(|p| is_candidate(p)).copied(),
+            },
+        };
+
+        if let Some((row, col)) = target {
+            let selected = self
+                .state
+                .game
+                .grid
+                .as_mut()
+                .is_some_and(|grid| grid.set_selection(row, col, direction));
+            if selected {
+                self.state.game.sel = (row, col);
+            }
+            self.ensure_selection_visible();
+        }
+    }
+
+    /// Select the next (or previous) clue, moving to its first empty cell.
+    ///
+    /// Clues are ordered across first, then down; stepping past the last clue
+    /// of one direction continues with the other direction.
+    fn select_next_clue(&mut self, forward: bool) {
+        let Some(grid) = self.state.game.grid.as_ref() else {
+            return;
+        };
+        let direction = self.state.game.active_direction;
+        let (row, col) = self.state.game.sel;
+
+        let mut clues: Vec<(Direction, usize)> = Vec::new();
+        for dir in [Direction::Across, Direction::Down] {
+            clues.extend(grid.clue_numbers(dir).into_iter().map(|no| (dir, no)));
+        }
+        if clues.is_empty() {
+            return;
+        }
+
+        let current = grid
+            .get(row, col)
+            .and_then(|cell| cell.clue_no_for_direction(direction))
+            .and_then(|no| clues.iter().position(|&clue| clue == (direction, no)));
+        let len = clues.len();
+        let next_idx = match (current, forward) {
+            (None, _) => 0,
+            (Some(i), true) => (i + 1) % len,
+            (Some(i), false) => (i + len - 1) % len,
+        };
+        let (next_dir, next_no) = clues[next_idx];
+        self.select_clue(next_no, next_dir);
+    }
+
     /// Advance to the next empty cell in the active direction after entering a letter.
     ///
     /// - If direction is `Across`: move right, wrap to next row if at end
@@ -1933,46 +3155,76 @@ impl App {
         self.ensure_selection_visible();
     }
 
+    /// Whether the fill can be checked against the puzzle's solution.
+    fn can_check(&self) -> bool {
+        self.state
+            .game
+            .puzzle
+            .as_ref()
+            .is_none_or(|puzzle| unchecked_reason(puzzle).is_none())
+    }
+
+    /// Grid render options from the preferences and the current numbers peek.
+    fn render_options(&self) -> RenderOptions {
+        // Numbers can be hidden, but a peek shows them for a moment
+        let is_peeking = self
+            .state
+            .game
+            .numbers_peek_until
+            .is_some_and(|until| Instant::now() < until);
+        RenderOptions {
+            auto_check: self.state.prefs.auto_check && self.can_check(),
+            show_numbers: self.state.prefs.show_numbers || is_peeking,
+            zoom: self.state.prefs.large_print,
+            compact: self.state.prefs.compact_grid || self.state.game.auto_compact,
+            ascii: !self.state.terminal.box_drawing,
+        }
+    }
+
     /// Adjust scroll position to ensure the selected cell is visible.
     fn ensure_selection_visible(&mut self) {
         let (sel_row, sel_col) = self.state.game.sel;
         let (visible_w, visible_h) = self.state.game.visible_area;
 
         // Cell rendering dimensions:
-        // - Each cell is 4 lines tall (top border shared, content)
-        // - Each cell is 4 chars wide (left border shared, content)
+        // - Each cell is 4 lines tall (top border shared, content), 5 when zoomed
+        // - Each cell is 4 chars wide (left border shared, content), 8 when zoomed
         // - Plus 1 for bottom/right border on last row/col
+        // - Compact cells are 2 chars wide and 1 line tall, without borders
+        let options = self.render_options();
+        let (cell_width, cell_height) = options.cell_size();
+        let border = options.border_size();
 
         // Calculate the pixel position of the selected cell
-        let cell_top = (sel_row as u16) * 4; // top of cell in content coordinates
-        let cell_bottom = cell_top + 4; // bottom of cell
-        let cell_left = (sel_col as u16) * 4; // left of cell
-        let cell_right = cell_left + 4; // right of cell
+        let cell_top = (sel_row as u16) * cell_height; // top of cell in content coordinates
+        let cell_bottom = cell_top + cell_height + border - 1; // bottom of cell
+        let cell_left = (sel_col as u16) * cell_width; // left of cell
+        let cell_right = cell_left + cell_width + border - 1; // right of cell
 
         let (scroll_v, scroll_h) = self.state.game.scroll_cur;
 
         // vertical scrolling
         let visible_top = scroll_v;
-        let visible_bottom = scroll_v + visible_h.saturating_sub(2); // account for scrollbar
+        let visible_bottom = scroll_v + visible_h.saturating_sub(1); // last visible line
         let new_scroll_v = if cell_top < visible_top {
             // cell is above visible area
             cell_top
         } else if cell_bottom > visible_bottom {
             // cell is below visible area
-            cell_bottom.saturating_sub(visible_h.saturating_sub(2)) // account for scrollbar
+            cell_bottom.saturating_sub(visible_h.saturating_sub(1)) // show its bottom border
         } else {
             scroll_v
         };
 
         // horizontal scrolling
         let visible_left = scroll_h;
-        let visible_right = scroll_h + visible_w.saturating_sub(2); // account for scrollbar
+        let visible_right = scroll_h + visible_w.saturating_sub(1); // last visible column
         let new_scroll_h = if cell_left < visible_left {
             // cell is to the left of visible area
             cell_left
         } else if cell_right > visible_right {
             // cell is to the right of visible area
-            cell_right.saturating_sub(visible_w.saturating_sub(2)) // account for scrollbar
+            cell_right.saturating_sub(visible_w.saturating_sub(1)) // show its right border
         } else {
             scroll_h
         };
@@ -1983,33 +3235,20 @@ impl App {
         );
     }
 
-    /// Save the current game to disk.
-    /// Save the current game. If `is_auto_save` is true, marks as auto-save.
-    fn save_current_game_inner(&mut self, is_auto_save: bool) {
-        let Some(puzzle) = self.state.game.puzzle.as_ref() else {
-            return;
-        };
-
-        let Some(grid) = self.state.game.grid.as_ref() else {
-            return;
-        };
+    /// Snapshot the current game for saving, if there is one. If `is_auto_save`
+    /// is true, marks it as an auto-save.
+    pub(crate) fn game_save(&self, is_auto_save: bool) -> Option<save::GameSave> {
+        let puzzle = self.state.game.puzzle.as_ref()?;
+        let grid = self.state.game.grid.as_ref()?;
 
-        // Build user_letters grid from the puzzle grid
-        let mut user_letters: Vec<Vec<Option<char>>> = Vec::new();
-        for row in grid.cells() {
-            let mut letter_row = Vec::new();
-            for cell in row {
-                letter_row.push(cell.get_user_letter());
-            }
-            user_letters.push(letter_row);
-        }
+        let user_letters = replay::grid_letters(grid);
 
         // Get elapsed time
         let elapsed_secs = self
             .state
             .game
-            .start_time
-            .map(|start| start.elapsed().as_secs())
+            .elapsed()
+            .map(|elapsed| elapsed.as_secs())
             .unwrap_or(0);
 
         // Get provider info
@@ -2018,19 +3257,15 @@ impl App {
             .game
             .provider_idx
             .unwrap_or(self.state.game.selection.provider_idx);
-        let provider_name = PuzzleProvider::ALL
-            .get(provider_idx)
-            .map(|p| p.name())
-            .unwrap_or("Unknown")
-            .to_string();
+        let provider_name = self.current_provider_name();
 
         let now = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .map(|d| d.as_secs())
             .unwrap_or(0);
 
-        let game_save = save::GameSave {
-            version: 1,
+        Some(save::GameSave {
+            version: save::SAVE_VERSION,
             puzzle_date: self
                 .state
                 .game
@@ -2047,25 +3282,250 @@ impl App {
             completion_state: self.state.game.completion_state,
             is_auto_save,
             saved_at: now,
+            source: self.state.game.source.clone(),
+            target_secs: self.state.game.target_time.map(|d| d.as_secs()),
+            replay: self.state.game.replay.clone(),
+            assists: self.state.game.assists,
+        })
+    }
+
+    /// Save the current game. If `is_auto_save` is true, marks as auto-save.
+    fn save_current_game_inner(&mut self, is_auto_save: bool) {
+        let Some(game_save) = self.game_save(is_auto_save) else {
+            return;
         };
 
         // Save to disk and show notification on success
         if save::save_game(&game_save).is_ok() {
+            self.state.game.edits_since_save = 0;
             // Show notification for 2 seconds (only for explicit saves)
             if !is_auto_save {
-                self.state.game.save_notification_until =
-                    Some(Instant::now() + Duration::from_secs(2));
+                self.state.game.notification = Some((
+                    "✓ Saved".to_string(),
+                    Instant::now() + Duration::from_secs(2),
+                ));
             }
         }
     }
 
+    /// Copy the current fill to the clipboard and write it to a file (CTRL+Y).
+    fn share_current_fill(&mut self) {
+        let Some(grid) = self.state.game.grid.as_ref() else {
+            return;
+        };
+        let text = grid.to_fill_text();
+        let puzzle_date = self
+            .state
+            .game
+            .puzzle_date
+            .clone()
+            .unwrap_or_else(|| "Unknown".to_string());
+
+        // the clipboard escape sequence is not supported by every terminal, so keep a file too
+        let _ = crate::share::copy_to_clipboard(&text);
+        let message =
+            match crate::share::write_fill(&puzzle_date, &self.current_provider_name(), &text) {
+                Ok(path) => format!("✓ Copied fill, also in {}", path.display()),
+                Err(e) => format!("✓ Copied fill (file not written: {})", e),
+            };
+        self.state.game.notification = Some((message, Instant::now() + Duration::from_secs(3)));
+    }
+
+    /// Export the current game, with the letters entered so far, for printing (CTRL+E).
+    fn print_current_game(&mut self) {
+        let (Some(puzzle), Some(grid)) = (
+            self.state.game.puzzle.as_ref(),
+            self.state.game.grid.as_ref(),
+        ) else {
+            return;
+        };
+        let postscript = print::to_postscript(puzzle, grid);
+        let puzzle_date = self
+            .state
+            .game
+            .puzzle_date
+            .clone()
+            .unwrap_or_else(|| "Unknown".to_string());
+
+        let message = match crate::share::write_print(
+            &puzzle_date,
+            &self.current_provider_name(),
+            &postscript,
+        ) {
+            Ok(path) => format!("✓ Exported for printing to {}", path.display()),
+            Err(e) => format!("✗ Export failed: {}", e),
+        };
+        self.state.game.notification = Some((message, Instant::now() + Duration::from_secs(3)));
+    }
+
+    /// Export the unsolved words as patterns with their clues (CTRL+X).
+    fn export_patterns(&mut self) {
+        let (Some(puzzle), Some(grid)) = (
+            self.state.game.puzzle.as_ref(),
+            self.state.game.grid.as_ref(),
+        ) else {
+            return;
+        };
+
+        let mut slots = Vec::new();
+        for direction in [Direction::Across, Direction::Down] {
+            let clues = match direction {
+                Direction::Across => &puzzle.clues.across,
+                Direction::Down => &puzzle.clues.down,
+            };
+            for number in grid.clue_numbers(direction) {
+                if grid.is_word_filled(number, direction) {
+                    continue;
+                }
+                let pattern = grid
+                    .word_cells(number, direction)
+                    .into_iter()
+                    .map(|(row, col)| {
+                        grid.get(row, col)
+                            .and_then(|cell| cell.get_user_letter())
+                            .unwrap_or(crate::patterns::UNKNOWN)
+                    })
+                    .collect();
+                slots.push(crate::patterns::SlotPattern {
+                    number,
+                    direction: direction.name().to_string(),
+                    pattern,
+                    clue: clues.get(&(number as u16)).cloned().unwrap_or_default(),
+                    answer: String::new(),
+                });
+            }
+        }
+        let puzzle_date = self
+            .state
+            .game
+            .puzzle_date
+            .clone()
+            .unwrap_or_else(|| "Unknown".to_string());
+
+        let message = match crate::patterns::export_patterns(
+            &puzzle_date,
+            &self.current_provider_name(),
+            &slots,
+        ) {
+            Ok(path) => format!(
+                "✓ Exported {} patterns to {} (and .csv)",
+                slots.len(),
+                path.display()
+            ),
+            Err(e) => format!("✗ Export failed: {}", e),
+        };
+        self.state.game.notification = Some((message, Instant::now() + Duration::from_secs(3)));
+    }
+
+    /// Apply the answers filled into the exported pattern files (CTRL+O).
+    ///
+    /// Only empty cells are filled: answers of the wrong length, for unknown
+    /// words, or clashing with letters already in the grid are skipped.
+    fn import_answers(&mut self) {
+        let puzzle_date = self
+            .state
+            .game
+            .puzzle_date
+            .clone()
+            .unwrap_or_else(|| "Unknown".to_string());
+        let slots =
+            match crate::patterns::import_patterns(&puzzle_date, &self.current_provider_name()) {
+                Ok((_, slots)) => slots,
+                Err(e) => {
+                    self.state.game.notification = Some((
+                        format!("✗ Import failed: {}", e),
+                        Instant::now() + Duration::from_secs(3),
+                    ));
+                    return;
+                }
+            };
+        let Some(grid) = self.state.game.grid.as_mut() else {
+            return;
+        };
+
+        let (mut applied, mut skipped) = (0, 0);
+        for slot in slots.iter().filter(|slot| !slot.answer.trim().is_empty()) {
+            let direction = match slot.direction.trim().to_ascii_lowercase().as_str() {
+                "across" | "a" => Direction::Across,
+                "down" | "d" => Direction::Down,
+                _ => {
+                    skipped += 1;
+                    continue;
+                }
+            };
+            let letters: Vec<char> = slot
+                .answer
+                .chars()
+                .filter(|c| c.is_alphabetic())
+                .map(cruciverbal_providers::entries::uppercase_letter)
+                .collect();
+            let cells = grid.word_cells(slot.number, direction);
+            let fits = !cells.is_empty()
+                && cells.len() == letters.len()
+                && cells.iter().zip(&letters).all(|(&(row, col), letter)| {
+                    grid.get(row, col)
+                        .and_then(|cell| cell.get_user_letter())
+                        .is_none_or(|entered| entered == *letter)
+                });
+            if !fits {
+                skipped += 1;
+                continue;
+            }
+            for (&(row, col), letter) in cells.iter().zip(&letters) {
+                if let Some(cell) = grid.get_mut(row, col) {
+                    cell.set_user_letter(Some(*letter));
+                }
+            }
+            applied += 1;
+        }
+
+        let message = if skipped > 0 {
+            format!("✓ Applied {} answers ({} skipped)", applied, skipped)
+        } else {
+            format!("✓ Applied {} answers", applied)
+        };
+        self.state.game.notification = Some((message, Instant::now() + Duration::from_secs(3)));
+        self.check_completion();
+    }
+
     /// Explicitly save the current game (CTRL+S).
     fn save_current_game(&mut self) {
         self.save_current_game_inner(false);
     }
 
-    /// Auto-save the current game (when exiting to menu).
-    fn auto_save_current_game(&mut self) {
+    /// Auto-save the current game (when exiting to menu, quitting, or on a timer).
+    pub(crate) fn auto_save_current_game(&mut self) {
         self.save_current_game_inner(true);
     }
+
+    /// Periodic game updates: timed hot-seat turns and autosave.
+    pub fn tick_game(&mut self) {
+        let is_playing = self.view == crate::AppView::Game(GameView::Playing);
+        if let Some(hot_seat) = self.state.game.hot_seat.as_mut().filter(|_| is_playing) {
+            hot_seat.tick();
+        }
+        self.tick_autosave();
+    }
+
+    /// Auto-save the game in progress once the configured autosave interval has
+    /// passed, or after the configured number of letter changes. Nothing is
+    /// saved while there are no changes since the last save.
+    fn tick_autosave(&mut self) {
+        if self.view != crate::AppView::Game(GameView::Playing) || self.state.game.grid.is_none() {
+            return;
+        }
+
+        let interval_secs = self.state.prefs.autosave_interval_secs;
+        let edits = self.state.prefs.autosave_edits;
+        let now = Instant::now();
+        let last = *self.state.game.last_auto_save.get_or_insert(now);
+        let interval_due = interval_secs > 0
+            && self.state.game.edits_since_save > 0
+            && now.duration_since(last) >= Duration::from_secs(interval_secs);
+        let edits_due = edits > 0 && self.state.game.edits_since_save >= edits;
+        if interval_due || edits_due {
+            self.auto_save_current_game();
+            self.state.game.last_auto_save = Some(now);
+        }
+    }
 }
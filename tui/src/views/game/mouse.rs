@@ -0,0 +1,121 @@
+//! Mouse handling for the game grid.
+
+use super::{CluePanel, Direction, GameView};
+use crate::App;
+use crossterm::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::layout::Position;
+
+/// Lines scrolled per mouse wheel step.
+const SCROLL_STEP: u16 = 2;
+
+impl App {
+    pub fn handle_game_mouse(&mut self, view: GameView, mouse: MouseEvent) {
+        if !matches!(view, GameView::Playing | GameView::CompletedPlaying) {
+            return;
+        }
+
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                if let Some((row, col)) = self.grid_cell_at(mouse.column, mouse.row) {
+                    self.click_cell(row, col);
+                } else if let Some((clue_no, direction)) = self.clue_at(mouse.column, mouse.row) {
+                    self.select_clue(clue_no, direction);
+                }
+            }
+            // the wheel scrolls the clue panel under the mouse, or else the grid
+            // (SHIFT+wheel scrolls the grid horizontally)
+            MouseEventKind::ScrollUp | MouseEventKind::ScrollDown => {
+                let up = mouse.kind == MouseEventKind::ScrollUp;
+                if let Some(panel) = self.clue_panel_at(mouse.column, mouse.row) {
+                    let delta = SCROLL_STEP as i32;
+                    panel.scroll_by(if up { -delta } else { delta });
+                } else {
+                    let horizontal = mouse.modifiers.contains(KeyModifiers::SHIFT);
+                    self.scroll_grid(horizontal, up);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Map a terminal position to the grid cell rendered there, if any.
+    fn grid_cell_at(&self, x: u16, y: u16) -> Option<(usize, usize)> {
+        let area = self.state.game.grid_area;
+        if !area.contains(Position::new(x, y)) {
+            return None;
+        }
+
+        // cells are `cell_size` terminal cells apart (borders are shared)
+        let (cell_width, cell_height) = self.render_options().cell_size();
+        let (scroll_v, scroll_h) = self.state.game.scroll_cur;
+        let row = ((y - area.y + scroll_v) / cell_height) as usize;
+        let col = ((x - area.x + scroll_h) / cell_width) as usize;
+
+        let grid = self.state.game.grid.as_ref()?;
+        grid.get(row, col).map(|_| (row, col))
+    }
+
+    /// The clue panel containing a terminal position, if any.
+    fn clue_panel_at(&mut self, x: u16, y: u16) -> Option<&mut CluePanel> {
+        self.state
+            .game
+            .clue_panels
+            .iter_mut()
+            .find(|panel| panel.area.contains(Position::new(x, y)))
+    }
+
+    /// The clue rendered at a terminal position in a clue panel, if any.
+    fn clue_at(&self, x: u16, y: u16) -> Option<(usize, Direction)> {
+        [Direction::Across, Direction::Down]
+            .into_iter()
+            .zip(self.state.game.clue_panels.iter())
+            .find(|(_, panel)| panel.area.contains(Position::new(x, y)))
+            .and_then(|(direction, panel)| panel.clue_at(y).map(|no| (no, direction)))
+    }
+
+    /// Select a clicked cell; clicking the selected cell again toggles the direction.
+    fn click_cell(&mut self, row: usize, col: usize) {
+        if self.state.game.sel == (row, col) {
+            self.toggle_direction();
+            return;
+        }
+
+        let direction = self.state.game.active_direction;
+        let Some(grid) = self.state.game.grid.as_mut() else {
+            return;
+        };
+        // keep the direction if the cell has a word in it, otherwise switch to the one it has
+        let direction = match grid.get(row, col) {
+            Some(cell)
+                if !cell.has_direction(direction) && cell.has_direction(direction.toggle()) =>
+            {
+                direction.toggle()
+            }
+            _ => direction,
+        };
+        if grid.set_selection(row, col, direction) {
+            self.state.game.sel = (row, col);
+            self.state.game.active_direction = direction;
+        }
+    }
+
+    /// Scroll the grid viewport by one wheel step.
+    fn scroll_grid(&mut self, horizontal: bool, up: bool) {
+        let (cur, max) = if horizontal {
+            (
+                &mut self.state.game.scroll_cur.1,
+                self.state.game.scroll_max.1,
+            )
+        } else {
+            (
+                &mut self.state.game.scroll_cur.0,
+                self.state.game.scroll_max.0,
+            )
+        };
+        *cur = if up {
+            cur.saturating_sub(SCROLL_STEP)
+        } else {
+            (*cur + SCROLL_STEP).min(max)
+        };
+    }
+}
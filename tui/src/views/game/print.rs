@@ -0,0 +1,199 @@
+//! Printable export of the puzzle being played.
+//!
+//! Renders the grid with the letters entered so far and both clue lists to a
+//! PostScript file, which can be printed directly or converted with `ps2pdf`.
+
+use super::grid::PuzzleGrid;
+use puz_parse::Puzzle;
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// US Letter, in points.
+const PAGE_WIDTH: f32 = 612.0;
+const PAGE_HEIGHT: f32 = 792.0;
+const MARGIN: f32 = 36.0;
+const MAX_CELL_SIZE: f32 = 28.0;
+const CLUE_FONT_SIZE: f32 = 9.0;
+const CLUE_LINE_HEIGHT: f32 = 11.0;
+const COLUMN_GAP: f32 = 18.0;
+
+/// Document header: Latin-1 encoded Helvetica and a centered `show`.
+const PROLOGUE: &str = "%!PS-Adobe-3.0
+%%Creator: cruciverbal
+%%Pages: (atend)
+%%EndComments
+/latin1 { findfont dup length dict begin
+  { 1 index /FID ne { def } { pop pop } ifelse } forall
+  /Encoding ISOLatin1Encoding def currentdict end definefont pop } def
+/Regular /Helvetica latin1
+/Bold /Helvetica-Bold latin1
+/cshow { dup stringwidth pop 2 div neg 0 rmoveto show } def
+";
+
+/// A PostScript string literal; characters outside Latin-1 are replaced with `?`.
+fn ps_string(text: &str) -> String {
+    let mut out = String::from("(");
+    for c in text.chars() {
+        match c {
+            '(' | ')' | '\\' => {
+                out.push('\\');
+                out.push(c);
+            }
+            ' '..='~' => out.push(c),
+            '\u{a0}'..='\u{ff}' => {
+                let _ = write!(out, "\\{:03o}", c as u32);
+            }
+            _ => out.push('?'),
+        }
+    }
+    out.push(')');
+    out
+}
+
+/// Wrap `text` into lines of at most `width` characters, breaking at spaces.
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        if !line.is_empty() && line.chars().count() + 1 + word.chars().count() > width {
+            lines.push(std::mem::take(&mut line));
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(word);
+    }
+    if !line.is_empty() || lines.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/// Clue list lines as `(bold, text)`, with continuation lines indented.
+fn clue_lines(heading: &str, clues: &HashMap<u16, String>, width: usize) -> Vec<(bool, String)> {
+    let mut numbers: Vec<&u16> = clues.keys().collect();
+    numbers.sort();
+
+    let mut lines = vec![(true, heading.to_string())];
+    for no in numbers {
+        for (i, line) in wrap(&format!("{}. {}", no, clues[no]), width - 4)
+            .into_iter()
+            .enumerate()
+        {
+            let indent = if i == 0 { "" } else { "    " };
+            lines.push((false, format!("{}{}", indent, line)));
+        }
+    }
+    lines
+}
+
+/// Render the puzzle and the current fill as a PostScript document.
+pub(super) fn to_postscript(puzzle: &Puzzle, grid: &PuzzleGrid) -> String {
+    let mut ps = String::from(PROLOGUE);
+    let mut page = 1;
+    let _ = writeln!(ps, "%%Page: {0} {0}", page);
+
+    // Title block
+    let mut top = PAGE_HEIGHT - MARGIN;
+    let title = if puzzle.info.title.trim().is_empty() {
+        "Crossword"
+    } else {
+        puzzle.info.title.trim()
+    };
+    top -= 14.0;
+    let _ = writeln!(
+        ps,
+        "/Bold 14 selectfont {} {} moveto {} show",
+        MARGIN,
+        top,
+        ps_string(title)
+    );
+    if !puzzle.info.author.trim().is_empty() {
+        top -= 14.0;
+        let _ = writeln!(
+            ps,
+            "/Regular 10 selectfont {} {} moveto {} show",
+            MARGIN,
+            top,
+            ps_string(&format!("by {}", puzzle.info.author.trim()))
+        );
+    }
+    top -= 18.0;
+
+    // Grid, centered, taking at most a little over half of the page height
+    let (width, height) = (grid.width() as f32, grid.height() as f32);
+    let cell = MAX_CELL_SIZE
+        .min((PAGE_WIDTH - 2.0 * MARGIN) / width)
+        .min(PAGE_HEIGHT * 0.55 / height);
+    let left = (PAGE_WIDTH - cell * width) / 2.0;
+    let _ = writeln!(ps, "0.5 setlinewidth");
+    for (row, cells) in grid.cells().iter().enumerate() {
+        for (col, cell_value) in cells.iter().enumerate() {
+            let x = left + col as f32 * cell;
+            let y = top - (row + 1) as f32 * cell;
+            if cell_value.is_filled() {
+                let _ = writeln!(ps, "{x:.1} {y:.1} {cell:.1} {cell:.1} rectfill");
+                continue;
+            }
+            let _ = writeln!(ps, "{x:.1} {y:.1} {cell:.1} {cell:.1} rectstroke");
+            if let Some(no) = cell_value.clue_number() {
+                let _ = writeln!(
+                    ps,
+                    "/Regular {:.1} selectfont {:.1} {:.1} moveto ({}) show",
+                    cell * 0.3,
+                    x + 1.5,
+                    y + cell * 0.72,
+                    no
+                );
+            }
+            if let Some(letter) = cell_value.get_user_letter() {
+                let _ = writeln!(
+                    ps,
+                    "/Bold {:.1} selectfont {:.1} {:.1} moveto {} cshow",
+                    cell * 0.6,
+                    x + cell / 2.0,
+                    y + cell * 0.2,
+                    ps_string(&letter.to_string())
+                );
+            }
+        }
+    }
+
+    // Clues in two columns below the grid, continuing on new pages
+    let column_width = (PAGE_WIDTH - 2.0 * MARGIN - COLUMN_GAP) / 2.0;
+    let chars_per_line = (column_width / (CLUE_FONT_SIZE * 0.5)) as usize;
+    let mut lines = clue_lines("ACROSS", &puzzle.clues.across, chars_per_line);
+    lines.push((false, String::new()));
+    lines.extend(clue_lines("DOWN", &puzzle.clues.down, chars_per_line));
+
+    let mut clue_top = top - height * cell - 24.0;
+    let mut column = 0;
+    let mut y = clue_top;
+    for (bold, line) in lines {
+        if y < MARGIN {
+            column += 1;
+            if column == 2 {
+                page += 1;
+                let _ = writeln!(ps, "showpage\n%%Page: {0} {0}", page);
+                column = 0;
+                clue_top = PAGE_HEIGHT - MARGIN - CLUE_LINE_HEIGHT;
+            }
+            y = clue_top;
+        }
+        if !line.is_empty() {
+            let _ = writeln!(
+                ps,
+                "/{} {} selectfont {:.1} {:.1} moveto {} show",
+                if bold { "Bold" } else { "Regular" },
+                CLUE_FONT_SIZE,
+                MARGIN + column as f32 * (column_width + COLUMN_GAP),
+                y,
+                ps_string(&line)
+            );
+        }
+        y -= CLUE_LINE_HEIGHT;
+    }
+
+    let _ = writeln!(ps, "showpage\n%%Trailer\n%%Pages: {}\n%%EOF", page);
+    ps
+}
@@ -0,0 +1,292 @@
+//! Solve recording and the replay viewer.
+//!
+//! Every letter change while playing is recorded with its solve time and saved
+//! with the game. After completing a puzzle, the replay plays the solve back
+//! with the grid filling in, at adjustable speed, with a timeline to scrub
+//! through (for streaming, teaching, or reviewing where the time went).
+
+use super::{Direction, GameView, PuzzleGrid, RenderOptions, format_duration};
+use crate::App;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Flex, Layout},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+
+/// Selectable playback speeds.
+const SPEEDS: [f64; 7] = [0.5, 1.0, 2.0, 4.0, 8.0, 16.0, 32.0];
+
+/// Width of the timeline bar, in characters.
+const TIMELINE_WIDTH: usize = 40;
+
+/// A letter change during a solve.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct ReplayEvent {
+    /// Solve time of the change, in milliseconds.
+    pub at_ms: u64,
+    pub row: usize,
+    pub col: usize,
+    /// The new letter (None = cleared).
+    pub letter: Option<char>,
+}
+
+/// Playback state of the replay viewer.
+#[derive(Debug, Clone)]
+pub struct ReplayPlayer {
+    /// Current playback position in solve time.
+    pub position: Duration,
+    /// Index into [`SPEEDS`].
+    pub speed_idx: usize,
+    /// Whether playback is running.
+    pub playing: bool,
+    /// Time of the last position update while playing.
+    pub last_tick: Option<Instant>,
+}
+
+impl Default for ReplayPlayer {
+    fn default() -> Self {
+        Self {
+            position: Duration::ZERO,
+            speed_idx: 3,
+            playing: true,
+            last_tick: None,
+        }
+    }
+}
+
+/// Letters currently entered in the grid.
+pub(super) fn grid_letters(grid: &PuzzleGrid) -> Vec<Vec<Option<char>>> {
+    grid.cells()
+        .iter()
+        .map(|row| row.iter().map(|cell| cell.get_user_letter()).collect())
+        .collect()
+}
+
+impl App {
+    /// Record the cells that changed since `before` (from [`grid_letters`]).
+    pub(super) fn record_replay_changes(&mut self, before: &[Vec<Option<char>>]) {
+        let (Some(grid), Some(start)) = (self.state.game.grid.as_ref(), self.state.game.start_time)
+        else {
+            return;
+        };
+        let at_ms = start.elapsed().as_millis() as u64;
+
+        for (row, cells) in grid.cells().iter().enumerate() {
+            for (col, cell) in cells.iter().enumerate() {
+                let letter = cell.get_user_letter();
+                let previous = before.get(row).and_then(|r| r.get(col)).copied().flatten();
+                if letter != previous {
+                    self.state.game.replay.push(ReplayEvent {
+                        at_ms,
+                        row,
+                        col,
+                        letter,
+                    });
+                }
+            }
+        }
+    }
+
+    /// Length of the recorded solve.
+    fn replay_length(&self) -> Duration {
+        let last_event = self
+            .state
+            .game
+            .replay
+            .last()
+            .map(|e| Duration::from_millis(e.at_ms))
+            .unwrap_or_default();
+        self.state
+            .game
+            .completion_time
+            .unwrap_or_default()
+            .max(last_event)
+    }
+
+    /// Open the replay viewer from the start.
+    pub(super) fn open_replay(&mut self) {
+        self.state.game.player = ReplayPlayer::default();
+        self.view = crate::AppView::Game(GameView::Replay);
+    }
+
+    pub(super) fn draw_game_replay(&mut self, frame: &mut ratatui::Frame) {
+        let theme = self.state.theme;
+        let length = self.replay_length();
+
+        // Advance playback
+        let player = &mut self.state.game.player;
+        let now = Instant::now();
+        if player.playing {
+            if let Some(last_tick) = player.last_tick {
+                player.position += now
+                    .duration_since(last_tick)
+                    .mul_f64(SPEEDS[player.speed_idx]);
+            }
+            if player.position >= length {
+                player.position = length;
+                player.playing = false;
+            }
+        }
+        player.last_tick = player.playing.then_some(now);
+        let player = player.clone();
+
+        let Some(puzzle) = self.state.game.puzzle.as_ref() else {
+            return;
+        };
+
+        // Grid as of the playback position, with the latest change selected
+        let mut grid = PuzzleGrid::from_solution(&puzzle.grid.solution);
+        let position_ms = player.position.as_millis() as u64;
+        let mut latest = None;
+        for event in self
+            .state
+            .game
+            .replay
+            .iter()
+            .take_while(|e| e.at_ms <= position_ms)
+        {
+            if let Some(cell) = grid.get_mut(event.row, event.col) {
+                cell.set_user_letter(event.letter);
+                latest = Some((event.row, event.col));
+            }
+        }
+        if let Some((row, col)) = latest {
+            grid.set_selection(row, col, Direction::Across);
+        }
+
+        let options = RenderOptions {
+            auto_check: false,
+            show_numbers: self.state.prefs.show_numbers,
+            zoom: false,
+            compact: self.state.prefs.compact_grid || self.state.game.auto_compact,
+            ascii: !self.state.terminal.box_drawing,
+        };
+        let (grid_width, grid_height) = options.grid_size(grid.width(), grid.height());
+
+        let area = frame.area();
+        let [area] = Layout::horizontal([Constraint::Length(
+            grid_width.max(TIMELINE_WIDTH as u16 + 4),
+        )])
+        .flex(Flex::Center)
+        .areas(area);
+        let [area] = Layout::vertical([Constraint::Length(2 + grid_height + 4)])
+            .flex(Flex::Center)
+            .areas(area);
+        let [title_area, _, grid_area, _, timeline_area, footer_area] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(grid_height),
+            Constraint::Length(1),
+            Constraint::Length(2),
+            Constraint::Length(1),
+        ])
+        .areas(area);
+
+        let title = puzzle.info.title.trim();
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                format!(
+                    "━━━ Replay: {} ━━━",
+                    if title.is_empty() { "Puzzle" } else { title }
+                ),
+                Style::default()
+                    .fg(theme.secondary)
+                    .add_modifier(Modifier::BOLD),
+            )))
+            .centered(),
+            title_area,
+        );
+
+        let [grid_area] = Layout::horizontal([Constraint::Length(grid_width)])
+            .flex(Flex::Center)
+            .areas(grid_area);
+        frame.render_widget(grid.to_par(theme, options), grid_area);
+
+        // Timeline: played part, playhead, rest
+        let fraction = if length.is_zero() {
+            1.0
+        } else {
+            player.position.as_secs_f64() / length.as_secs_f64()
+        };
+        let played = ((fraction * TIMELINE_WIDTH as f64) as usize).min(TIMELINE_WIDTH - 1);
+        let timeline = Line::from(vec![
+            Span::styled("━".repeat(played), Style::default().fg(theme.primary)),
+            Span::styled("●", Style::default().fg(theme.primary)),
+            Span::styled(
+                "─".repeat(TIMELINE_WIDTH - 1 - played),
+                Style::default().fg(theme.dimmed),
+            ),
+        ]);
+        let status = Line::from(vec![
+            Span::styled(
+                if player.playing { "▶ " } else { "⏸ " },
+                Style::default().fg(theme.primary),
+            ),
+            Span::styled(
+                format!(
+                    "{} / {}",
+                    format_duration(Some(player.position)),
+                    format_duration(Some(length))
+                ),
+                Style::default().fg(theme.text),
+            ),
+            Span::styled(
+                format!("  {}x", SPEEDS[player.speed_idx]),
+                Style::default().fg(theme.dimmed),
+            ),
+        ]);
+        frame.render_widget(
+            Paragraph::new(vec![timeline, status]).centered(),
+            timeline_area,
+        );
+
+        let key = |k: &'static str| Span::styled(k, Style::default().fg(theme.primary));
+        let text = |t: &'static str| Span::styled(t, Style::default().fg(theme.dimmed));
+        let footer = Line::from(vec![
+            key("Space"),
+            text(" play/pause · "),
+            key("←→"),
+            text(" seek · "),
+            key("↑↓"),
+            text(" speed · "),
+            key("ESC"),
+            text(" back"),
+        ]);
+        frame.render_widget(Paragraph::new(footer).centered(), footer_area);
+    }
+
+    pub(super) fn handle_replay_input(&mut self, key: KeyEvent) {
+        let length = self.replay_length();
+        let player = &mut self.state.game.player;
+        // Shift seeks further
+        let step = if key.modifiers.contains(KeyModifiers::SHIFT) {
+            Duration::from_secs(30)
+        } else {
+            Duration::from_secs(5)
+        };
+
+        match key.code {
+            KeyCode::Esc => {
+                self.view = crate::AppView::Game(GameView::Completed);
+            }
+            KeyCode::Char(' ') => {
+                // play again from the start once the end is reached
+                if !player.playing && player.position >= length {
+                    player.position = Duration::ZERO;
+                }
+                player.playing = !player.playing;
+            }
+            KeyCode::Left => player.position = player.position.saturating_sub(step),
+            KeyCode::Right => player.position = (player.position + step).min(length),
+            KeyCode::Home => player.position = Duration::ZERO,
+            KeyCode::End => player.position = length,
+            KeyCode::Up if player.speed_idx + 1 < SPEEDS.len() => player.speed_idx += 1,
+            KeyCode::Down if player.speed_idx > 0 => player.speed_idx -= 1,
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,85 @@
+//! Wordplay helper line for cryptic solvers, shown under the clue bar.
+//!
+//! Lists letter sequences hidden in the current clue and runs of clue words
+//! that could be anagram fodder, both matching the letters already entered.
+//! Nothing is revealed: most candidates are wrong, and the solver picks.
+
+use super::Direction;
+use crate::App;
+use cruciverbal_providers::assist::{anagram_fodder, hidden_words};
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+
+/// Most candidates of each kind listed.
+const MAX_CANDIDATES: usize = 4;
+
+impl App {
+    /// Current clue text and the entered letters of its word (None = empty cell).
+    fn current_word_pattern(&self) -> Option<(String, Vec<Option<char>>)> {
+        let grid = self.state.game.grid.as_ref()?;
+        let puzzle = self.state.game.puzzle.as_ref()?;
+        let (row, col) = self.state.game.sel;
+        let cell = grid.get(row, col)?;
+
+        let direction = self.state.game.active_direction;
+        let (clue_no, direction) = cell
+            .clue_no_for_direction(direction)
+            .map(|no| (no, direction))
+            .or_else(|| {
+                let other = direction.toggle();
+                cell.clue_no_for_direction(other).map(|no| (no, other))
+            })?;
+        let clues = match direction {
+            Direction::Across => &puzzle.clues.across,
+            Direction::Down => &puzzle.clues.down,
+        };
+        let clue = clues.get(&(clue_no as u16))?.clone();
+
+        let pattern = grid
+            .word_cells(clue_no, direction)
+            .into_iter()
+            .map(|(row, col)| grid.get(row, col).and_then(|cell| cell.get_user_letter()))
+            .collect();
+        Some((clue, pattern))
+    }
+
+    /// Draw the hidden word and anagram fodder candidates for the current clue.
+    pub(super) fn draw_wordplay_helper(&self, frame: &mut ratatui::Frame, area: Rect) {
+        let theme = self.state.theme;
+        let Some((clue, pattern)) = self.current_word_pattern() else {
+            return;
+        };
+
+        let list = |mut candidates: Vec<String>| {
+            if candidates.is_empty() {
+                return "-".to_string();
+            }
+            let more = candidates.len().saturating_sub(MAX_CANDIDATES);
+            candidates.truncate(MAX_CANDIDATES);
+            let mut text = candidates.join(", ");
+            if more > 0 {
+                text.push_str(&format!(" +{}", more));
+            }
+            text
+        };
+        let hidden = list(hidden_words(&clue, &pattern));
+        let fodder = list(
+            anagram_fodder(&clue, &pattern)
+                .into_iter()
+                .map(|run| format!("\"{}\"", run))
+                .collect(),
+        );
+
+        let line = Line::from(vec![
+            Span::styled("Hidden: ", Style::default().fg(theme.dimmed)),
+            Span::styled(hidden, Style::default().fg(theme.secondary)),
+            Span::styled(" · Anagram: ", Style::default().fg(theme.dimmed)),
+            Span::styled(fodder, Style::default().fg(theme.secondary)),
+        ]);
+        frame.render_widget(Paragraph::new(line), area);
+    }
+}
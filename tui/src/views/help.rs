@@ -1,22 +1,35 @@
-use crate::{App, AppView};
-use crossterm::event::{KeyCode, KeyEvent};
+use crate::{
+    App, AppView,
+    preferences::{ClueKeys, Preferences},
+    theme::Theme,
+    views::game::GameView,
+};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::{
     Frame,
     layout::{Constraint, Flex, Layout},
     style::{Modifier, Style},
     text::{Line, Span},
-    widgets::Paragraph,
+    widgets::{Block, Borders, Clear, Paragraph},
 };
 use std::time::Instant;
 
-/// Help content sections with their keyboard shortcuts.
+/// Help content sections with their keyboard shortcuts, before the keys that
+/// depend on the preferences are added (see [`help_sections`]).
 const HELP_SECTIONS: &[(&str, &[(&str, &str)])] = &[
     (
         "Navigation",
         &[
             ("Arrow keys", "Move between cells"),
             ("Shift + Arrow", "Jump to next word"),
+            ("Home / End", "First/last cell of word"),
+            ("Ctrl+Home / Ctrl+End", "First/last cell of grid"),
+            ("PgUp / PgDn", "Page through clues"),
+            ("Tab / Shift+Tab", "Next/previous clue"),
             ("Space", "Toggle direction (Across/Down)"),
+            ("Click", "Select cell (again to toggle)"),
+            ("Mouse wheel", "Scroll clues or grid"),
+            ("Click clue", "Jump to clue"),
         ],
     ),
     (
@@ -24,17 +37,28 @@ const HELP_SECTIONS: &[(&str, &[(&str, &str)])] = &[
         &[("A-Z", "Enter letter"), ("Backspace/Delete", "Clear cell")],
     ),
     (
-        "Reveal",
+        "Check & Reveal",
         &[
+            ("Ctrl+K", "Check & reveal menu"),
             ("Ctrl+R", "Reveal current letter"),
             ("Shift+Ctrl+R", "Reveal current word"),
             ("Alt+Ctrl+R", "Reveal entire puzzle"),
+            ("Ctrl+G", "Suggest where to work next"),
         ],
     ),
     (
         "General",
         &[
             ("Ctrl+S", "Save game"),
+            ("Ctrl+P", "Puzzle info"),
+            ("Ctrl+Y", "Copy fill to share"),
+            ("Ctrl+E", "Export for printing"),
+            ("Ctrl+X", "Export answer patterns"),
+            ("Ctrl+O", "Import answers"),
+            ("Ctrl+T", "Change target time"),
+            ("Ctrl+N", "Show/hide clue numbers"),
+            ("F2", "Peek at hidden numbers"),
+            ("Ctrl+L", "Toggle large print"),
             ("Ctrl+H", "Show help"),
             ("ESC", "Back to menu"),
             ("Ctrl+C", "Quit application"),
@@ -42,22 +66,84 @@ const HELP_SECTIONS: &[(&str, &[(&str, &str)])] = &[
     ),
 ];
 
+/// Help sections with the keys that are active for the given preferences.
+fn help_sections(prefs: &Preferences) -> Vec<(&'static str, Vec<(&'static str, &'static str)>)> {
+    let extra_clue_keys: &[(&str, &str)] = match prefs.clue_keys {
+        ClueKeys::Tab => &[],
+        ClueKeys::Enter => &[("Enter", "Next clue")],
+        ClueKeys::Brackets => &[("] / [", "Next/previous clue")],
+    };
+
+    HELP_SECTIONS
+        .iter()
+        .map(|(name, items)| {
+            let mut keys = Vec::new();
+            for &(key, description) in *items {
+                keys.push((key, description));
+                if key == "Tab / Shift+Tab" {
+                    keys.extend_from_slice(extra_clue_keys);
+                }
+            }
+            (*name, keys)
+        })
+        .collect()
+}
+
+/// Lines for the given sections: a header per section, its keys and a blank line.
+fn section_lines(
+    sections: &[(&'static str, Vec<(&'static str, &'static str)>)],
+    theme: &Theme,
+) -> Vec<Line<'static>> {
+    let mut lines: Vec<Line> = Vec::new();
+    for (section_name, items) in sections {
+        // Section header - more subtle
+        lines.push(Line::from(Span::styled(
+            *section_name,
+            Style::default()
+                .fg(theme.primary)
+                .add_modifier(Modifier::BOLD),
+        )));
+
+        // Items
+        for (key, description) in items {
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {}", key), Style::default().fg(theme.secondary)),
+                Span::styled(
+                    format!("  {}", description),
+                    Style::default().fg(theme.dimmed),
+                ),
+            ]));
+        }
+
+        lines.push(Line::from(""));
+    }
+    lines
+}
+
 impl App {
     pub fn draw_help(&mut self, frame: &mut Frame) {
         let area = frame.area();
         let theme = self.state.theme;
 
-        // Calculate content height: title (1) + blank (1) + sections
-        let mut content_height: u16 = 2; // title + blank line
-        for (_section_name, items) in HELP_SECTIONS {
-            content_height += 1; // section header
-            content_height += items.len() as u16; // items
-            content_height += 1; // blank line after section
-        }
-        content_height += 1; // footer
+        // title + blank, sections, footer
+        let mut lines: Vec<Line> = vec![
+            Line::from(Span::styled(
+                "━━━ Keyboard Controls ━━━",
+                Style::default()
+                    .fg(theme.secondary)
+                    .add_modifier(Modifier::BOLD),
+            )),
+            Line::from(""),
+        ];
+        lines.extend(section_lines(&help_sections(&self.state.prefs), theme));
+        lines.push(Line::from(vec![
+            Span::styled("ESC", Style::default().fg(theme.primary)),
+            Span::styled(" to return", Style::default().fg(theme.dimmed)),
+        ]));
 
         // Calculate content width (widest line)
         let content_width: u16 = 40;
+        let content_height = lines.len() as u16;
 
         // Center the content
         let [centered_area] = Layout::horizontal([Constraint::Length(content_width)])
@@ -68,66 +154,92 @@ impl App {
             .flex(Flex::Center)
             .areas(centered_area);
 
-        // Build help content
-        let mut lines: Vec<Line> = Vec::new();
+        frame.render_widget(Paragraph::new(lines), centered_area);
+    }
 
-        // Title
-        lines.push(Line::from(Span::styled(
-            "━━━ Keyboard Controls ━━━",
+    /// Draw the help over the game: the game is dimmed and the keys are shown in
+    /// two columns on a centered panel, so the grid stays in view.
+    pub(crate) fn draw_game_help(&mut self, frame: &mut Frame) {
+        self.draw_game_playing(frame, false);
+
+        let area = frame.area();
+        let theme = self.state.theme;
+        frame.buffer_mut().set_style(
+            area,
             Style::default()
-                .fg(theme.secondary)
-                .add_modifier(Modifier::BOLD),
-        )));
-        lines.push(Line::from(""));
+                .fg(theme.dimmed)
+                .add_modifier(Modifier::DIM),
+        );
 
-        // Sections
-        for (section_name, items) in HELP_SECTIONS {
-            // Section header - more subtle
-            lines.push(Line::from(Span::styled(
-                section_name.to_string(),
-                Style::default()
-                    .fg(theme.primary)
-                    .add_modifier(Modifier::BOLD),
-            )));
-
-            // Items
-            for (key, description) in *items {
-                lines.push(Line::from(vec![
-                    Span::styled(format!("  {}", key), Style::default().fg(theme.secondary)),
-                    Span::styled(
-                        format!("  {}", description),
-                        Style::default().fg(theme.dimmed),
-                    ),
-                ]));
-            }
+        let sections = help_sections(&self.state.prefs);
+        let (left, right) = sections.split_at(sections.len().div_ceil(2));
+        let left = section_lines(left, theme);
+        let right = section_lines(right, theme);
 
-            lines.push(Line::from(""));
-        }
+        let column_width: u16 = 38;
+        // border (2) + padding (2), two columns, footer (1)
+        let popup_width: u16 = (2 * column_width + 4).min(area.width);
+        let popup_height: u16 = (left.len().max(right.len()) as u16 + 3).min(area.height);
 
-        // Footer
-        lines.push(Line::from(vec![
-            Span::styled("ESC", Style::default().fg(theme.primary)),
-            Span::styled(" to return", Style::default().fg(theme.dimmed)),
-        ]));
+        let [centered_area] = Layout::horizontal([Constraint::Length(popup_width)])
+            .flex(Flex::Center)
+            .areas(area);
 
-        frame.render_widget(Paragraph::new(lines), centered_area);
+        let [centered_area] = Layout::vertical([Constraint::Length(popup_height)])
+            .flex(Flex::Center)
+            .areas(centered_area);
+
+        frame.render_widget(Clear, centered_area);
+
+        let block = Block::default()
+            .title(" Keyboard Controls ")
+            .title_style(Style::default().add_modifier(Modifier::BOLD))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.secondary));
+        let inner = block
+            .inner(centered_area)
+            .inner(ratatui::layout::Margin::new(1, 0));
+        frame.render_widget(block, centered_area);
+
+        let [columns, footer] =
+            Layout::vertical([Constraint::Fill(1), Constraint::Length(1)]).areas(inner);
+        let [left_area, right_area] =
+            Layout::horizontal([Constraint::Length(column_width); 2]).areas(columns);
+        frame.render_widget(Paragraph::new(left), left_area);
+        frame.render_widget(Paragraph::new(right), right_area);
+        frame.render_widget(
+            Paragraph::new(Line::from(vec![
+                Span::styled("ESC", Style::default().fg(theme.primary)),
+                Span::styled(" to return", Style::default().fg(theme.dimmed)),
+            ])),
+            footer,
+        );
     }
 
     pub fn handle_help_input(&mut self, key: KeyEvent) {
         // Any key returns, but ESC is the primary one
         if matches!(key.code, KeyCode::Esc | KeyCode::Enter | KeyCode::Backspace) {
-            // Return to previous view if set, otherwise go to menu
-            if let Some(prev) = self.previous_view.take() {
-                // If returning to game, resume timer
-                if matches!(prev, AppView::Game(_)) {
-                    if let Some(elapsed) = self.state.game.paused_elapsed.take() {
-                        self.state.game.start_time = Some(Instant::now() - elapsed);
-                    }
-                }
-                self.view = prev;
-            } else {
-                self.view = AppView::Menu;
+            self.view = AppView::Menu;
+        }
+    }
+
+    /// Open the help overlay, pausing the timer until it is closed.
+    pub(crate) fn open_game_help(&mut self) {
+        if let Some(start) = self.state.game.start_time {
+            self.state.game.paused_elapsed = Some(start.elapsed());
+        }
+        self.view = AppView::Game(GameView::Help);
+    }
+
+    pub(crate) fn handle_game_help_input(&mut self, key: KeyEvent) {
+        let is_ctrl_h = matches!(key.code, KeyCode::Char('h') | KeyCode::Char('H'))
+            && key.modifiers.contains(KeyModifiers::CONTROL);
+        if is_ctrl_h || matches!(key.code, KeyCode::Esc | KeyCode::Enter | KeyCode::Backspace) {
+            // Resume the timer where it was paused
+            if let Some(elapsed) = self.state.game.paused_elapsed.take() {
+                self.state.game.start_time = Some(Instant::now() - elapsed);
             }
+            self.view = AppView::Game(GameView::Playing);
         }
     }
 }
@@ -20,18 +20,24 @@ pub enum MenuItem {
     NewGame,
     RecentlyPlayed,
     LoadGame,
+    Search,
+    Providers,
     Help,
     Theme,
+    Settings,
     Exit,
 }
 
 impl MenuItem {
-    pub const ALL: [MenuItem; 6] = [
+    pub const ALL: [MenuItem; 9] = [
         MenuItem::NewGame,
         MenuItem::RecentlyPlayed,
         MenuItem::LoadGame,
+        MenuItem::Search,
+        MenuItem::Providers,
         MenuItem::Help,
         MenuItem::Theme,
+        MenuItem::Settings,
         MenuItem::Exit,
     ];
     pub fn fmt(&self) -> String {
@@ -39,8 +45,11 @@ impl MenuItem {
             MenuItem::NewGame => "New Game".to_string(),
             MenuItem::RecentlyPlayed => "Recently Played".to_string(),
             MenuItem::LoadGame => "Load Game".to_string(),
+            MenuItem::Search => "Search Library".to_string(),
+            MenuItem::Providers => "Providers".to_string(),
             MenuItem::Help => "Help".to_string(),
             MenuItem::Theme => "Theme".to_string(),
+            MenuItem::Settings => "Settings".to_string(),
             MenuItem::Exit => "Exit".to_string(),
         }
     }
@@ -52,9 +61,13 @@ impl App {
         let theme = self.state.theme;
 
         // Content dimensions
-        let content_width: u16 = 30;
-        // Title (1) + blank (2) + menu items (6) + blank (2) + footer (1)
-        let content_height: u16 = 1 + 2 + MenuItem::ALL.len() as u16 + 2 + 1;
+        let reminder = self.state.reminder.as_ref().map(|r| r.message());
+        let content_width: u16 = reminder
+            .as_ref()
+            .map_or(30, |msg| (msg.chars().count() as u16).max(30));
+        // Title (1) + blank (2) + menu items + blank (2) + footer (1), plus banner (2)
+        let banner_height: u16 = if reminder.is_some() { 2 } else { 0 };
+        let content_height: u16 = 1 + 2 + MenuItem::ALL.len() as u16 + 2 + 1 + banner_height;
 
         // Center the content
         let [centered_area] = Layout::horizontal([Constraint::Length(content_width)])
@@ -78,6 +91,17 @@ impl App {
         lines.push(Line::from(""));
         lines.push(Line::from(""));
 
+        // Streak reminder banner
+        if let Some(msg) = reminder {
+            lines.push(Line::from(Span::styled(
+                msg,
+                Style::default()
+                    .fg(theme.error)
+                    .add_modifier(Modifier::BOLD),
+            )));
+            lines.push(Line::from(""));
+        }
+
         // Menu items
         for (i, item) in MenuItem::ALL.iter().enumerate() {
             let style = if i == self.state.menu.sel {
@@ -156,18 +180,29 @@ impl App {
                 self.state.game.reset_for_new_game();
                 self.view = AppView::Game(GameView::LoadSelect);
             }
+            MenuItem::Search => {
+                self.open_search();
+            }
+            MenuItem::Providers => {
+                self.open_providers();
+            }
             MenuItem::Help => {
                 self.view = AppView::Help;
             }
             MenuItem::Theme => {
                 // Initialize selection to current theme index
-                let current_idx = crate::theme::Theme::ALL
-                    .iter()
+                let current_idx = crate::theme::Theme::available()
+                    .into_iter()
                     .position(|t| t.id == self.state.theme.id)
                     .unwrap_or(0);
                 self.state.theme_select.selected = current_idx;
+                self.state.theme_select.message = None;
                 self.view = AppView::ThemeSelect;
             }
+            MenuItem::Settings => {
+                self.state.settings.selected = 0;
+                self.view = AppView::Settings;
+            }
             MenuItem::Exit => {
                 self.quit();
             }
@@ -1,4 +1,7 @@
 pub mod game;
 pub mod help;
 pub mod menu;
+pub mod providers;
+pub mod search;
+pub mod settings;
 pub mod theme_select;
@@ -0,0 +1,208 @@
+//! Providers view: publication schedule, archive depth and fetch history per provider.
+
+use crate::fetch_log::{self, FetchLog};
+use crate::{App, AppView};
+use crossterm::event::{KeyCode, KeyEvent};
+use cruciverbal_providers::PuzzleProvider;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Flex, Layout},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Paragraph, Wrap},
+};
+
+/// State for the providers screen.
+#[derive(Debug, Default)]
+pub struct ProvidersState {
+    /// Currently selected provider index in [`PuzzleProvider::enabled`].
+    pub selected: usize,
+    /// Fetch history, loaded when the screen opens.
+    pub fetch_log: FetchLog,
+}
+
+impl App {
+    /// Open the providers screen.
+    pub fn open_providers(&mut self) {
+        self.state.providers.fetch_log = fetch_log::load_fetch_log();
+        self.view = AppView::Providers;
+    }
+
+    pub fn draw_providers(&mut self, frame: &mut Frame) {
+        let area = frame.area();
+        let theme = self.state.theme;
+        let state = &self.state.providers;
+
+        let [centered_area] = Layout::horizontal([Constraint::Length(76)])
+            .flex(Flex::Center)
+            .areas(area);
+        let [centered_area] =
+            Layout::vertical([Constraint::Length(3 + PuzzleProvider::ALL.len() as u16 + 2)])
+                .flex(Flex::Center)
+                .areas(centered_area);
+        let [title_area, body_area, footer_area] = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Min(1),
+            Constraint::Length(1),
+        ])
+        .areas(centered_area);
+        let [list_area, _, details_area] = Layout::horizontal([
+            Constraint::Length(26),
+            Constraint::Length(2),
+            Constraint::Min(1),
+        ])
+        .areas(body_area);
+
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                "━━━ Providers ━━━",
+                Style::default()
+                    .fg(theme.secondary)
+                    .add_modifier(Modifier::BOLD),
+            )))
+            .centered(),
+            title_area,
+        );
+
+        // Provider list
+        let lines: Vec<Line> = PuzzleProvider::enabled()
+            .enumerate()
+            .map(|(i, provider)| {
+                let is_selected = i == state.selected;
+                let style = if is_selected {
+                    Style::default()
+                        .fg(theme.primary)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(theme.text)
+                };
+                let prefix = if is_selected { "▸ " } else { "  " };
+                Line::from(Span::styled(
+                    format!("{}{}", prefix, provider.name()),
+                    style,
+                ))
+            })
+            .collect();
+        frame.render_widget(Paragraph::new(lines), list_area);
+
+        // Details of the selected provider
+        let Some(provider) = PuzzleProvider::enabled().nth(state.selected) else {
+            return;
+        };
+        let last_fetch = state
+            .fetch_log
+            .last_fetch
+            .get(provider.name())
+            .and_then(|at| chrono::DateTime::parse_from_rfc3339(at).ok())
+            .map(|at| at.format("%Y-%m-%d %H:%M").to_string())
+            .unwrap_or_else(|| "Never".to_string());
+        let reliability = state.fetch_log.reliability(provider.name());
+        let fetches = match reliability {
+            Some(r) => format!("{} of {} ok", r.ok, r.total),
+            None => "None yet".to_string(),
+        };
+        let average = reliability
+            .and_then(|r| r.average_ms)
+            .map(|ms| format!("{:.1}s", ms as f64 / 1000.0))
+            .unwrap_or_else(|| "-".to_string());
+        let last_error = state
+            .fetch_log
+            .last_error(provider.name())
+            .and_then(|r| r.error.clone())
+            .unwrap_or_else(|| "-".to_string());
+        let (width, height) = provider.typical_size();
+        let auth = if provider.requires_auth() {
+            "Account required"
+        } else {
+            "Not required"
+        };
+
+        let row = |label: &str, value: String| {
+            Line::from(vec![
+                Span::styled(format!("{:<14}", label), Style::default().fg(theme.dimmed)),
+                Span::styled(value, Style::default().fg(theme.text)),
+            ])
+        };
+        let details = vec![
+            Line::from(Span::styled(
+                provider.name(),
+                Style::default()
+                    .fg(theme.primary)
+                    .add_modifier(Modifier::BOLD),
+            )),
+            Line::from(Span::styled(
+                provider.url(),
+                Style::default().fg(theme.dimmed),
+            )),
+            Line::from(""),
+            row("Schedule", provider.schedule().name()),
+            row("Archive", provider.archive().name().to_string()),
+            row("Grid size", format!("{}x{}", width, height)),
+            row("Difficulty", provider.difficulty().name().to_string()),
+            row(
+                "Solutions",
+                if provider.includes_solutions() {
+                    "Included"
+                } else {
+                    "Published later"
+                }
+                .to_string(),
+            ),
+            row("Sign-in", auth.to_string()),
+            row("Last fetched", last_fetch),
+            row("Downloads", fetches),
+            row("Average time", average),
+            row("Last error", last_error),
+            Line::from(""),
+            Line::from(Span::styled(
+                provider.license_note(),
+                Style::default().fg(theme.dimmed),
+            )),
+        ];
+        frame.render_widget(
+            Paragraph::new(details).wrap(Wrap { trim: true }),
+            details_area,
+        );
+
+        let footer = Line::from(vec![
+            Span::styled("↑↓", Style::default().fg(theme.primary)),
+            Span::styled(" navigate · ", Style::default().fg(theme.dimmed)),
+            Span::styled("Enter", Style::default().fg(theme.primary)),
+            Span::styled(" new game · ", Style::default().fg(theme.dimmed)),
+            Span::styled("ESC", Style::default().fg(theme.primary)),
+            Span::styled(" back", Style::default().fg(theme.dimmed)),
+        ]);
+        frame.render_widget(Paragraph::new(footer).centered(), footer_area);
+    }
+
+    pub fn handle_providers_input(&mut self, key: KeyEvent) {
+        let state = &mut self.state.providers;
+        match key.code {
+            KeyCode::Esc => {
+                self.view = AppView::Menu;
+            }
+            KeyCode::Up if state.selected > 0 => {
+                state.selected -= 1;
+            }
+            KeyCode::Down if state.selected + 1 < PuzzleProvider::enabled().count() => {
+                state.selected += 1;
+            }
+            KeyCode::Enter => {
+                use crate::views::game::GameView;
+
+                // Start a new game with this provider preselected
+                let Some(provider) = PuzzleProvider::enabled().nth(state.selected) else {
+                    return;
+                };
+                let provider_idx = PuzzleProvider::ALL
+                    .iter()
+                    .position(|p| *p == provider)
+                    .unwrap_or(0);
+                self.state.game.reset_for_new_game();
+                self.state.game.selection.provider_idx = provider_idx;
+                self.view = AppView::Game(GameView::Selecting);
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,214 @@
+//! Library search view: find clues and answers across all stored puzzles.
+
+use crate::library::{self, IndexEntry, LibraryIndex, LibraryKind};
+use crate::{App, AppView};
+use crossterm::event::{KeyCode, KeyEvent};
+use cruciverbal_providers::PuzzleProvider;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Flex, Layout},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+
+/// State for the library search screen.
+#[derive(Debug, Default)]
+pub struct SearchState {
+    /// Search query typed by the user.
+    pub query: String,
+    /// Concordance index, loaded when the screen opens.
+    pub index: Option<LibraryIndex>,
+    /// Results for the current query.
+    pub results: Vec<IndexEntry>,
+    /// Currently selected result index.
+    pub selected: usize,
+    /// Error message to display, if any.
+    pub error: Option<String>,
+}
+
+impl SearchState {
+    /// Re-run the query against the index.
+    fn refresh(&mut self) {
+        self.results = self
+            .index
+            .as_ref()
+            .map(|index| index.search(&self.query).into_iter().cloned().collect())
+            .unwrap_or_default();
+        self.selected = 0;
+    }
+}
+
+impl App {
+    /// Open the search screen, loading (or rebuilding) the library index.
+    pub fn open_search(&mut self) {
+        self.state.search = SearchState::default();
+        match library::load_or_build() {
+            Ok(index) => self.state.search.index = Some(index),
+            Err(e) => self.state.search.error = Some(format!("Failed to index library: {}", e)),
+        }
+        self.view = AppView::Search;
+    }
+
+    pub fn draw_search(&mut self, frame: &mut Frame) {
+        let area = frame.area();
+        let theme = self.state.theme;
+        let search = &self.state.search;
+
+        let content_width: u16 = area.width.saturating_sub(4).min(90);
+        let [centered_area] = Layout::horizontal([Constraint::Length(content_width)])
+            .flex(Flex::Center)
+            .areas(area);
+        let [header_area, results_area, footer_area] = Layout::vertical([
+            Constraint::Length(5),
+            Constraint::Min(1),
+            Constraint::Length(1),
+        ])
+        .areas(centered_area);
+
+        // Header: title, query input and result count
+        let entry_count = search.index.as_ref().map_or(0, |i| i.entries.len());
+        let status = match &search.error {
+            Some(error) => Span::styled(error.clone(), Style::default().fg(theme.error)),
+            None if search.query.is_empty() => Span::styled(
+                format!("{} clues indexed", entry_count),
+                Style::default().fg(theme.dimmed),
+            ),
+            None => Span::styled(
+                format!("{} result(s)", search.results.len()),
+                Style::default().fg(theme.dimmed),
+            ),
+        };
+        let header = vec![
+            Line::from(Span::styled(
+                "━━━ Search Library ━━━",
+                Style::default()
+                    .fg(theme.secondary)
+                    .add_modifier(Modifier::BOLD),
+            )),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("Answer or clue: ", Style::default().fg(theme.dimmed)),
+                Span::styled(
+                    format!("{}_", search.query),
+                    Style::default()
+                        .fg(theme.primary)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]),
+            Line::from(status),
+        ];
+        frame.render_widget(Paragraph::new(header).centered(), header_area);
+
+        // Results, scrolled to keep the selection visible
+        let visible = results_area.height as usize;
+        let offset = search.selected.saturating_sub(visible.saturating_sub(1));
+        let lines: Vec<Line> = search
+            .results
+            .iter()
+            .enumerate()
+            .skip(offset)
+            .take(visible)
+            .map(|(i, entry)| {
+                let is_selected = i == search.selected;
+                let answer_style = if is_selected {
+                    Style::default()
+                        .fg(theme.primary)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(theme.text)
+                };
+                let prefix = if is_selected { "▸ " } else { "  " };
+                Line::from(vec![
+                    Span::styled(format!("{}{:<15} ", prefix, entry.answer), answer_style),
+                    Span::styled(
+                        format!(
+                            "{} {} · {} {} · ",
+                            entry.number,
+                            entry.direction.name(),
+                            entry.provider_name,
+                            entry.puzzle_date
+                        ),
+                        Style::default().fg(theme.dimmed),
+                    ),
+                    Span::styled(entry.clue.clone(), Style::default().fg(theme.text)),
+                ])
+            })
+            .collect();
+        frame.render_widget(Paragraph::new(lines), results_area);
+
+        let footer = Line::from(vec![
+            Span::styled("↑↓", Style::default().fg(theme.primary)),
+            Span::styled(" navigate · ", Style::default().fg(theme.dimmed)),
+            Span::styled("Enter", Style::default().fg(theme.primary)),
+            Span::styled(" open puzzle · ", Style::default().fg(theme.dimmed)),
+            Span::styled("ESC", Style::default().fg(theme.primary)),
+            Span::styled(" back", Style::default().fg(theme.dimmed)),
+        ]);
+        frame.render_widget(Paragraph::new(footer).centered(), footer_area);
+    }
+
+    pub fn handle_search_input(&mut self, key: KeyEvent) {
+        let search = &mut self.state.search;
+        match key.code {
+            KeyCode::Esc => {
+                self.view = AppView::Menu;
+            }
+            KeyCode::Up if search.selected > 0 => {
+                search.selected -= 1;
+            }
+            KeyCode::Down if search.selected + 1 < search.results.len() => {
+                search.selected += 1;
+            }
+            KeyCode::Enter => {
+                if let Some(entry) = search.results.get(search.selected).cloned() {
+                    self.open_library_entry(&entry);
+                }
+            }
+            KeyCode::Backspace => {
+                search.query.pop();
+                search.refresh();
+            }
+            KeyCode::Char(c) if !c.is_control() => {
+                search.query.push(c);
+                search.refresh();
+            }
+            _ => {}
+        }
+    }
+
+    /// Open the puzzle a search result came from, with the cursor on the entry.
+    fn open_library_entry(&mut self, entry: &IndexEntry) {
+        self.state.game.reset_for_new_game();
+
+        match entry.kind {
+            LibraryKind::Save => match crate::save::load_game(&entry.path) {
+                Ok(game_save) => self.restore_game_save(game_save),
+                Err(e) => {
+                    self.state.search.error = Some(format!("Failed to load: {}", e));
+                    return;
+                }
+            },
+            LibraryKind::Cache => {
+                let Some(cached) = crate::cache::load_path(&entry.path) else {
+                    self.state.search.error = Some("Failed to load cached puzzle".to_string());
+                    return;
+                };
+                if let Some(idx) = PuzzleProvider::ALL
+                    .iter()
+                    .position(|p| p.name() == entry.provider_name)
+                {
+                    self.state.game.selection.provider_idx = idx;
+                }
+                self.start_downloaded_puzzle(
+                    cached.puzzle,
+                    cached.source,
+                    entry.puzzle_date.clone(),
+                );
+            }
+        }
+
+        let (row, col) = entry.start;
+        self.focus_cell(row, col, entry.direction.into());
+    }
+}
@@ -0,0 +1,290 @@
+//! Settings view for solver behavior preferences.
+
+use crate::{
+    App, AppView,
+    preferences::{self, ClueKeys, EndOfWord, TargetTime, TerminalMode},
+};
+use crossterm::event::{KeyCode, KeyEvent};
+use cruciverbal_providers::PuzzleProvider;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Flex, Layout},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+
+/// State for the settings screen.
+#[derive(Debug, Default)]
+pub struct SettingsState {
+    /// Currently hovered setting index.
+    pub selected: usize,
+}
+
+/// A configurable setting.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SettingsItem {
+    AutoCheck,
+    AcceptUnaccented,
+    SkipFilled,
+    EndOfWord,
+    ClueKeys,
+    ShowNumbers,
+    LargePrint,
+    CompactGrid,
+    ShowTimer,
+    TargetTime,
+    WordplayHelper,
+    Terminal,
+    AutosaveInterval,
+    AutosaveEdits,
+    DailyReminder,
+}
+
+/// Selectable autosave intervals in seconds (0 = disabled).
+const AUTOSAVE_INTERVALS: [u64; 5] = [0, 30, 60, 120, 300];
+
+/// Selectable numbers of letter changes between autosaves (0 = disabled).
+const AUTOSAVE_EDITS: [u32; 4] = [0, 10, 20, 50];
+
+impl SettingsItem {
+    pub const ALL: [SettingsItem; 15] = [
+        SettingsItem::AutoCheck,
+        SettingsItem::AcceptUnaccented,
+        SettingsItem::SkipFilled,
+        SettingsItem::EndOfWord,
+        SettingsItem::ClueKeys,
+        SettingsItem::ShowNumbers,
+        SettingsItem::LargePrint,
+        SettingsItem::CompactGrid,
+        SettingsItem::ShowTimer,
+        SettingsItem::TargetTime,
+        SettingsItem::WordplayHelper,
+        SettingsItem::Terminal,
+        SettingsItem::AutosaveInterval,
+        SettingsItem::AutosaveEdits,
+        SettingsItem::DailyReminder,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SettingsItem::AutoCheck => "Auto-check letters",
+            SettingsItem::AcceptUnaccented => "Accept E for É",
+            SettingsItem::SkipFilled => "Skip filled cells",
+            SettingsItem::EndOfWord => "At end of word",
+            SettingsItem::ClueKeys => "Next clue keys",
+            SettingsItem::ShowNumbers => "Show clue numbers",
+            SettingsItem::LargePrint => "Large print",
+            SettingsItem::CompactGrid => "Compact grid",
+            SettingsItem::ShowTimer => "Show timer",
+            SettingsItem::TargetTime => "Target time",
+            SettingsItem::WordplayHelper => "Wordplay helper",
+            SettingsItem::Terminal => "Borders & colors",
+            SettingsItem::AutosaveInterval => "Autosave every",
+            SettingsItem::AutosaveEdits => "Autosave after",
+            SettingsItem::DailyReminder => "Streak reminder",
+        }
+    }
+
+    /// Current value of this setting, formatted for display.
+    fn value(&self, prefs: &preferences::Preferences) -> String {
+        match self {
+            SettingsItem::AutoCheck => on_off(prefs.auto_check),
+            SettingsItem::AcceptUnaccented => on_off(prefs.accept_unaccented),
+            SettingsItem::SkipFilled => on_off(prefs.skip_filled),
+            SettingsItem::EndOfWord => prefs.end_of_word.name().to_string(),
+            SettingsItem::ClueKeys => prefs.clue_keys.name().to_string(),
+            SettingsItem::ShowNumbers => on_off(prefs.show_numbers),
+            SettingsItem::LargePrint => on_off(prefs.large_print),
+            SettingsItem::CompactGrid => if prefs.compact_grid {
+                "Always"
+            } else {
+                "When needed"
+            }
+            .to_string(),
+            SettingsItem::ShowTimer => on_off(prefs.show_timer),
+            SettingsItem::TargetTime => prefs.target_time.name(),
+            SettingsItem::WordplayHelper => on_off(prefs.wordplay_helper),
+            SettingsItem::Terminal => prefs.terminal_mode.name().to_string(),
+            SettingsItem::AutosaveInterval => match prefs.autosave_interval_secs {
+                0 => "Off".to_string(),
+                secs if secs % 60 == 0 => format!("{}m", secs / 60),
+                secs => format!("{}s", secs),
+            },
+            SettingsItem::AutosaveEdits => match prefs.autosave_edits {
+                0 => "Off".to_string(),
+                edits => format!("{} edits", edits),
+            },
+            SettingsItem::DailyReminder => prefs
+                .daily_provider
+                .clone()
+                .unwrap_or_else(|| "Off".to_string()),
+        }
+    }
+
+    /// Cycle this setting to its next (or previous) value.
+    fn cycle(&self, prefs: &mut preferences::Preferences, forward: bool) {
+        match self {
+            SettingsItem::AutoCheck => prefs.auto_check = !prefs.auto_check,
+            SettingsItem::AcceptUnaccented => prefs.accept_unaccented = !prefs.accept_unaccented,
+            SettingsItem::SkipFilled => prefs.skip_filled = !prefs.skip_filled,
+            SettingsItem::EndOfWord => {
+                prefs.end_of_word = cycle_value(&EndOfWord::ALL, prefs.end_of_word, forward);
+            }
+            SettingsItem::ClueKeys => {
+                prefs.clue_keys = cycle_value(&ClueKeys::ALL, prefs.clue_keys, forward);
+            }
+            SettingsItem::ShowNumbers => prefs.show_numbers = !prefs.show_numbers,
+            SettingsItem::LargePrint => prefs.large_print = !prefs.large_print,
+            SettingsItem::CompactGrid => prefs.compact_grid = !prefs.compact_grid,
+            SettingsItem::ShowTimer => prefs.show_timer = !prefs.show_timer,
+            SettingsItem::TargetTime => {
+                prefs.target_time = cycle_value(&TargetTime::ALL, prefs.target_time, forward);
+            }
+            SettingsItem::WordplayHelper => prefs.wordplay_helper = !prefs.wordplay_helper,
+            SettingsItem::Terminal => {
+                prefs.terminal_mode = cycle_value(&TerminalMode::ALL, prefs.terminal_mode, forward);
+            }
+            SettingsItem::AutosaveInterval => {
+                prefs.autosave_interval_secs =
+                    cycle_value(&AUTOSAVE_INTERVALS, prefs.autosave_interval_secs, forward);
+            }
+            SettingsItem::AutosaveEdits => {
+                prefs.autosave_edits = cycle_value(&AUTOSAVE_EDITS, prefs.autosave_edits, forward);
+            }
+            SettingsItem::DailyReminder => {
+                let options: Vec<Option<&str>> = std::iter::once(None)
+                    .chain(PuzzleProvider::enabled().map(|p| Some(p.name())))
+                    .collect();
+                prefs.daily_provider =
+                    cycle_value(&options, prefs.daily_provider.as_deref(), forward)
+                        .map(str::to_string);
+            }
+        }
+    }
+}
+
+fn on_off(value: bool) -> String {
+    if value { "On" } else { "Off" }.to_string()
+}
+
+/// Step to the next (or previous) value in `values`, wrapping around.
+///
+/// Unknown current values start from the first entry.
+fn cycle_value<T: Copy + PartialEq>(values: &[T], current: T, forward: bool) -> T {
+    let len = values.len();
+    let idx = values.iter().position(|v| *v == current);
+    let next = match (idx, forward) {
+        (None, _) => 0,
+        (Some(i), true) => (i + 1) % len,
+        (Some(i), false) => (i + len - 1) % len,
+    };
+    values[next]
+}
+
+impl App {
+    pub fn draw_settings(&mut self, frame: &mut Frame) {
+        let area = frame.area();
+        let theme = self.state.theme;
+
+        // Content dimensions
+        let content_width: u16 = 50;
+        // Title (1) + blank (2) + items + blank (2) + footer (1)
+        let content_height: u16 = 1 + 2 + SettingsItem::ALL.len() as u16 + 2 + 1;
+
+        // Center the content
+        let [centered_area] = Layout::horizontal([Constraint::Length(content_width)])
+            .flex(Flex::Center)
+            .areas(area);
+
+        let [centered_area] = Layout::vertical([Constraint::Length(content_height)])
+            .flex(Flex::Center)
+            .areas(centered_area);
+
+        let mut lines: Vec<Line> = Vec::new();
+
+        lines.push(Line::from(Span::styled(
+            "━━━ Settings ━━━",
+            Style::default()
+                .fg(theme.secondary)
+                .add_modifier(Modifier::BOLD),
+        )));
+        lines.push(Line::from(""));
+        lines.push(Line::from(""));
+
+        for (i, item) in SettingsItem::ALL.iter().enumerate() {
+            let is_hovered = i == self.state.settings.selected;
+            let style = if is_hovered {
+                Style::default()
+                    .fg(theme.primary)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.dimmed)
+            };
+
+            let prefix = if is_hovered { "▸ " } else { "  " };
+            lines.push(Line::from(vec![
+                Span::styled(format!("{}{:<24}", prefix, item.label()), style),
+                Span::styled(
+                    format!("< {} >", item.value(&self.state.prefs)),
+                    Style::default().fg(theme.text),
+                ),
+            ]));
+        }
+
+        lines.push(Line::from(""));
+        lines.push(Line::from(""));
+
+        lines.push(Line::from(vec![
+            Span::styled("↑↓", Style::default().fg(theme.primary)),
+            Span::styled(" navigate · ", Style::default().fg(theme.dimmed)),
+            Span::styled("←→", Style::default().fg(theme.primary)),
+            Span::styled(" change · ", Style::default().fg(theme.dimmed)),
+            Span::styled("ESC", Style::default().fg(theme.primary)),
+            Span::styled(" back", Style::default().fg(theme.dimmed)),
+        ]));
+
+        frame.render_widget(Paragraph::new(lines), centered_area);
+    }
+
+    pub fn handle_settings_input(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc => {
+                self.view = AppView::Menu;
+            }
+            KeyCode::Up if self.state.settings.selected > 0 => {
+                self.state.settings.selected -= 1;
+            }
+            KeyCode::Down if self.state.settings.selected < SettingsItem::ALL.len() - 1 => {
+                self.state.settings.selected += 1;
+            }
+            KeyCode::Left | KeyCode::Right | KeyCode::Enter | KeyCode::Char(' ') => {
+                if let Some(item) = SettingsItem::ALL.get(self.state.settings.selected) {
+                    item.cycle(&mut self.state.prefs, key.code != KeyCode::Left);
+                    let _ = preferences::save_preferences(&self.state.prefs);
+                    match item {
+                        SettingsItem::AcceptUnaccented => {
+                            if let Some(grid) = self.state.game.grid.as_mut() {
+                                grid.set_accept_unaccented(self.state.prefs.accept_unaccented);
+                            }
+                        }
+                        SettingsItem::DailyReminder => {
+                            self.state.reminder = crate::stats::check_reminder();
+                        }
+                        SettingsItem::Terminal => {
+                            let terminal =
+                                crate::terminal::capabilities(self.state.prefs.terminal_mode);
+                            self.state.terminal = terminal;
+                            self.state.theme = crate::theme::Theme::for_terminal(
+                                &self.state.prefs.theme_id,
+                                terminal.rgb_colors,
+                            );
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
@@ -1,6 +1,9 @@
 //! Theme selection view.
 
-use crate::{App, AppView, preferences, theme::Theme};
+use crate::{
+    App, AppView, preferences,
+    theme::{self, Theme},
+};
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
     Frame,
@@ -15,6 +18,8 @@ use ratatui::{
 pub struct ThemeSelectState {
     /// Currently hovered theme index.
     pub selected: usize,
+    /// Result of the last theme reload.
+    pub message: Option<String>,
 }
 
 impl App {
@@ -22,14 +27,16 @@ impl App {
         let area = frame.area();
 
         // Use the hovered theme for live preview
-        let preview_theme = Theme::ALL
+        let themes = Theme::available();
+        let preview_theme = themes
             .get(self.state.theme_select.selected)
-            .unwrap_or(&crate::theme::DEFAULT);
+            .copied()
+            .unwrap_or(&theme::DEFAULT);
 
         // Content dimensions
-        let content_width: u16 = 30;
-        // Title (1) + blank (2) + theme items (5) + blank (2) + footer (1)
-        let content_height: u16 = 1 + 2 + Theme::ALL.len() as u16 + 2 + 1;
+        let content_width: u16 = 44;
+        // Title (1) + blank (2) + theme items + blank (2) + footer (1) + message (2)
+        let content_height: u16 = 1 + 2 + themes.len() as u16 + 2 + 1 + 2;
 
         // Center the content
         let [centered_area] = Layout::horizontal([Constraint::Length(content_width)])
@@ -54,7 +61,7 @@ impl App {
         lines.push(Line::from(""));
 
         // Theme items
-        for (i, theme) in Theme::ALL.iter().enumerate() {
+        for (i, theme) in themes.iter().enumerate() {
             let is_hovered = i == self.state.theme_select.selected;
             let is_current = theme.id == self.state.theme.id;
 
@@ -83,10 +90,20 @@ impl App {
             Span::styled(" navigate · ", Style::default().fg(preview_theme.dimmed)),
             Span::styled("Enter", Style::default().fg(preview_theme.primary)),
             Span::styled(" select · ", Style::default().fg(preview_theme.dimmed)),
+            Span::styled("r", Style::default().fg(preview_theme.primary)),
+            Span::styled(" reload · ", Style::default().fg(preview_theme.dimmed)),
             Span::styled("ESC", Style::default().fg(preview_theme.primary)),
             Span::styled(" back", Style::default().fg(preview_theme.dimmed)),
         ]));
 
+        if let Some(message) = &self.state.theme_select.message {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                message.as_str(),
+                Style::default().fg(preview_theme.dimmed),
+            )));
+        }
+
         frame.render_widget(Paragraph::new(lines), centered_area);
     }
 
@@ -102,21 +119,21 @@ impl App {
                 }
             }
             KeyCode::Down => {
-                let theme_count = Theme::ALL.len();
+                let theme_count = Theme::available().len();
                 if self.state.theme_select.selected < theme_count - 1 {
                     self.state.theme_select.selected += 1;
                 }
             }
+            KeyCode::Char('r') => self.reload_themes(),
             KeyCode::Enter => {
                 // Apply the selected theme
-                if let Some(theme) = Theme::ALL.get(self.state.theme_select.selected) {
-                    self.state.theme = theme;
+                if let Some(theme) = Theme::available().get(self.state.theme_select.selected) {
+                    self.state.theme =
+                        Theme::for_terminal(theme.id, self.state.terminal.rgb_colors);
 
                     // Save preference
-                    let prefs = preferences::Preferences {
-                        theme_id: theme.id.to_string(),
-                    };
-                    let _ = preferences::save_preferences(&prefs);
+                    self.state.prefs.theme_id = theme.id.to_string();
+                    let _ = preferences::save_preferences(&self.state.prefs);
                 }
 
                 // Return to menu
@@ -125,4 +142,30 @@ impl App {
             _ => {}
         }
     }
+
+    /// Reload the user theme files, keeping the hovered and current themes
+    /// selected by ID.
+    fn reload_themes(&mut self) {
+        let hovered = Theme::available()
+            .get(self.state.theme_select.selected)
+            .map(|t| t.id);
+        let (count, errors) = theme::load_user_themes();
+
+        let themes = Theme::available();
+        self.state.theme_select.selected = themes
+            .iter()
+            .position(|t| Some(t.id) == hovered)
+            .unwrap_or(0);
+        // pick up changes to the current theme's file
+        self.state.theme =
+            Theme::for_terminal(&self.state.prefs.theme_id, self.state.terminal.rgb_colors);
+
+        self.state.theme_select.message = Some(match errors.first() {
+            Some(error) if errors.len() > 1 => {
+                format!("{} (and {} more errors)", error, errors.len() - 1)
+            }
+            Some(error) => error.clone(),
+            None => format!("Loaded {} user theme(s)", count),
+        });
+    }
 }
@@ -0,0 +1,106 @@
+//! End-to-end smoke test: get a puzzle, play it, save it, load it and finish it.
+//!
+//! Runs against a bundled sample by default; set `CRUCIVERBAL_NETWORK_TESTS` to
+//! download a live puzzle instead.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use cruciverbal::game::{CompletionState, Direction, GameView};
+use cruciverbal::{App, AppView};
+use cruciverbal_providers::{PuzzleProvider, samples::SAMPLES};
+use std::time::{Duration, Instant};
+
+/// Set to run the smoke test against a live download instead of a sample.
+const NETWORK_ENV: &str = "CRUCIVERBAL_NETWORK_TESTS";
+
+/// Type `letter` into the cell at `(row, col)`.
+fn type_letter(app: &mut App, (row, col): (usize, usize), letter: char) {
+    app.focus_cell(row, col, Direction::Across);
+    let key = KeyEvent::new(KeyCode::Char(letter), KeyModifiers::NONE);
+    app.handle_game_input(GameView::Playing, key);
+}
+
+/// Get a puzzle into play: downloaded through the loading screen when
+/// [`NETWORK_ENV`] is set, or a bundled sample otherwise.
+async fn start_puzzle(app: &mut App) {
+    if std::env::var_os(NETWORK_ENV).is_none() {
+        let sample = SAMPLES[1];
+        let puzzle = sample.puzzle().unwrap();
+        app.start_downloaded_puzzle(puzzle, Some(sample.source()), sample.id.to_string());
+        return;
+    }
+
+    app.state.game.selection.provider_idx = PuzzleProvider::ALL
+        .iter()
+        .position(|p| *p == PuzzleProvider::GuardianQuick)
+        .unwrap();
+    app.state.game.selection.use_latest = true;
+    app.view = AppView::Game(GameView::Loading);
+    let deadline = Instant::now() + Duration::from_secs(30);
+    while app.view == AppView::Game(GameView::Loading) {
+        assert!(
+            Instant::now() < deadline,
+            "download did not finish: {:?}",
+            app.state.game.download
+        );
+        app.poll_download().await;
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+}
+
+/// Play a puzzle from start to finish across a save and reload.
+#[tokio::test]
+async fn test_smoke() {
+    // keep saves, stats and the cache out of the real data directory; this test
+    // binary has no other tests, so nothing else reads the environment meanwhile
+    let home = std::env::temp_dir().join(format!("cruciverbal-smoke-{}", std::process::id()));
+    std::fs::create_dir_all(&home).unwrap();
+    unsafe { std::env::set_var(cruciverbal::paths::HOME_ENV, &home) };
+
+    let mut app = App::new();
+    start_puzzle(&mut app).await;
+    assert_eq!(app.view, AppView::Game(GameView::Playing));
+
+    let solution = app
+        .state
+        .game
+        .puzzle
+        .as_ref()
+        .unwrap()
+        .grid
+        .solution
+        .clone();
+    let mut cells = Vec::new();
+    for (row, line) in solution.iter().enumerate() {
+        for (col, letter) in line.chars().enumerate() {
+            if letter != '.' {
+                cells.push(((row, col), letter));
+            }
+        }
+    }
+    let (last, rest) = cells.split_last().unwrap();
+
+    // fill all but the last letter, then save
+    for &(cell, letter) in rest {
+        type_letter(&mut app, cell, letter);
+    }
+    assert_eq!(app.view, AppView::Game(GameView::Playing));
+    let ctrl_s = KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL);
+    app.handle_game_input(GameView::Playing, ctrl_s);
+
+    // load the save into a fresh app and finish the puzzle there
+    let saves = cruciverbal::save::list_saves().unwrap();
+    assert_eq!(saves.len(), 1);
+    let save = cruciverbal::save::load_game(&saves[0].path).unwrap();
+    assert_eq!(save.puzzle.grid.solution, solution);
+
+    drop(app);
+    let mut app = App::new();
+    app.restore_game_save(save);
+    assert_eq!(app.view, AppView::Game(GameView::Playing));
+    type_letter(&mut app, last.0, last.1);
+    assert_eq!(app.view, AppView::Game(GameView::Completed));
+    assert_eq!(app.state.game.completion_state, CompletionState::Correct);
+
+    drop(app);
+    std::fs::remove_dir_all(&home).unwrap();
+}